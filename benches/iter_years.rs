@@ -0,0 +1,39 @@
+//! 逐年概要的吞吐量：cargo bench --bench iter_years [-- 起始年 末年]
+//!
+//! 迭代器每次只计算一年，内存占用与年数无关；本程序只累加各年天数，不保存任何一年的结果。
+
+use std::env;
+use std::time::Instant;
+
+use sxtwl_rs::statistics::{YearSummary, iter_years};
+
+fn main() {
+    let args: Vec<isize> = env::args().skip(1).filter_map(|a| a.parse().ok()).collect();
+    let (start, end) = match args[..] {
+        [start, end] => (start, end),
+        _ => (2, 9998),
+    };
+    let begin: Instant = Instant::now();
+    let mut years: usize = 0;
+    let mut days: usize = 0;
+    let mut leap_years: usize = 0;
+    for s in iter_years(start..=end).unwrap() {
+        let s: YearSummary = s;
+        years += 1;
+        days += s.get_day_count();
+        if s.get_leap_month() > 0 {
+            leap_years += 1;
+        }
+    }
+    let seconds: f64 = begin.elapsed().as_secs_f64();
+    println!(
+        "{}..={}: {} years, {} days, {} leap years, {:.3} s, {:.0} years/s",
+        start,
+        end,
+        years,
+        days,
+        leap_years,
+        seconds,
+        years as f64 / seconds
+    );
+}
@@ -0,0 +1,53 @@
+//! 批量八字的并行扩展性：cargo bench --features parallel --bench par_batch [-- 起始年 末年]
+//!
+//! 每隔约1小时取一个时刻，分别以1、2、4……个线程（至多为CPU数）并行计算，每次紧接着顺序计算一遍作为基准，输出耗时与加速比。
+
+use std::env;
+use std::time::Instant;
+
+use sxtwl_rs::eightchar::EightChar;
+use sxtwl_rs::solar::SolarTime;
+use sxtwl_rs::types::Tyme;
+
+fn main() {
+    let args: Vec<isize> = env::args().skip(1).filter_map(|a| a.parse().ok()).collect();
+    let (start, end) = match args[..] {
+        [start, end] => (start, end),
+        _ => (1950, 2049),
+    };
+    let mut times: Vec<SolarTime> = Vec::new();
+    let mut t: SolarTime = SolarTime::from_ymd_hms(start, 1, 1, 0, 0, 0);
+    let stop: SolarTime = SolarTime::from_ymd_hms(end + 1, 1, 1, 0, 0, 0);
+    while t.is_before(stop) {
+        times.push(t);
+        t = t.next(3607);
+    }
+    println!("{}..={}: {} times", start, end, times.len());
+
+    let max: usize = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let mut threads: usize = 1;
+    loop {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .unwrap();
+        let begin: Instant = Instant::now();
+        let l: Vec<EightChar> = pool.install(|| EightChar::par_batch(&times));
+        let seconds: f64 = begin.elapsed().as_secs_f64();
+        let begin: Instant = Instant::now();
+        let expected: Vec<EightChar> = EightChar::batch(&times);
+        let sequential: f64 = begin.elapsed().as_secs_f64();
+        assert!(l == expected);
+        println!(
+            "{:>3} threads: {:.3} s, sequential {:.3} s, {:.2}x",
+            threads,
+            seconds,
+            sequential,
+            sequential / seconds
+        );
+        if threads >= max {
+            break;
+        }
+        threads = (threads * 2).min(max);
+    }
+}
@@ -0,0 +1,55 @@
+//! 生成文件的输入哈希：输入数据未变化时跳过重新生成
+
+use std::env;
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+/// 设置后总是重新生成（忽略哈希）
+pub const FORCE_ENV: &str = "SXTWL_BUILDER_FORCE";
+
+/// 生成文件路径：写入OUT_DIR，由src中的include!引入，不改动源码目录（作为git依赖或位于只读registry时也能构建）
+pub fn output_path(file_name: &str) -> Result<PathBuf> {
+    let out_dir = env::var_os("OUT_DIR").context("OUT_DIR is not set")?;
+    Ok(PathBuf::from(out_dir).join(file_name))
+}
+
+/// 生成文件首行的哈希标记
+const HASH_PREFIX: &str = "// 输入哈希: ";
+
+/// 输入内容的哈希（FNV-1a 64位，各输入之间以长度分隔）
+pub fn content_hash(inputs: &[&str]) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for input in inputs {
+        for byte in (input.len() as u64)
+            .to_le_bytes()
+            .iter()
+            .chain(input.as_bytes())
+        {
+            hash ^= *byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+    }
+    format!("{:016x}", hash)
+}
+
+/// 生成文件是否已是最新（首行哈希一致且未强制重新生成）
+pub fn is_up_to_date(dest_path: &Path, hash: &str) -> bool {
+    if env::var_os(FORCE_ENV).is_some() {
+        return false;
+    }
+    match fs::read_to_string(dest_path) {
+        Ok(content) => content.lines().next() == Some(&format!("{}{}", HASH_PREFIX, hash)),
+        Err(_) => false,
+    }
+}
+
+/// 写入生成文件，首行记录输入哈希
+pub fn write_generated(dest_path: &Path, hash: &str, content: &str) -> Result<()> {
+    let mut f = File::create(dest_path)?;
+    writeln!(f, "{}{}", HASH_PREFIX, hash)?;
+    writeln!(f, "{}", content)?;
+    Ok(())
+}
@@ -1,5 +1,6 @@
 //! 资源构建主模块
 
+mod generated;
 mod modules;
 mod progress;
 
@@ -17,10 +18,21 @@ pub fn run() -> Result<()> {
     modules::qishuo::generate_qishuo_data()?;
     progress.complete_stage();
 
+    progress.start_stage("处理 星历系数表");
+    modules::ephemeris::generate_ephemeris_tables()?;
+    progress.complete_stage();
+
     progress.start_stage("处理 闰月 数据");
     modules::leap_month::generate_leap_year_data()?;
     progress.complete_stage();
 
+    #[cfg(feature = "trig-lut")]
+    {
+        progress.start_stage("处理 正弦查找表");
+        modules::sin_table::generate_sin_table()?;
+        progress.complete_stage();
+    }
+
     #[cfg(feature = "holiday")]
     {
         progress.start_stage("处理 法定节假日 数据");
@@ -42,6 +54,20 @@ pub fn run() -> Result<()> {
         progress.complete_stage();
     }
 
+    #[cfg(feature = "era")]
+    {
+        progress.start_stage("处理 年号 数据");
+        modules::era::generate_era_data()?;
+        progress.complete_stage();
+    }
+
+    #[cfg(feature = "farming")]
+    {
+        progress.start_stage("处理 节气农事 数据");
+        modules::farming::generate_farming_data()?;
+        progress.complete_stage();
+    }
+
     progress.finish_build();
     Ok(())
 }
@@ -50,4 +76,5 @@ pub fn run() -> Result<()> {
 fn configure_incremental_build() {
     println!("cargo::rerun-if-changed=build.rs");
     println!("cargo::rerun-if-changed=builder/");
+    println!("cargo::rerun-if-env-changed={}", generated::FORCE_ENV);
 }
@@ -1,76 +1,109 @@
-use std::fs::File;
-use std::io::Write;
-use std::path::Path;
+use anyhow::{Result, anyhow, bail};
 
-use anyhow::Result;
+use crate::builder::generated::{content_hash, is_up_to_date, output_path, write_generated};
 
-pub const DAY_GOD_HEADER: &str = r#"//! 此文件由 build.rs 自动生成，不要手动修改。
+pub const DAY_GOD_HEADER: &str = r#"// 此文件由 build.rs 自动生成，不要手动修改。
 "#;
 
 mod original_strings;
 use original_strings::DAY_GODS;
 
 pub fn generate_day_god_data() -> Result<()> {
-    // 生成 Rust 代码
+    let dest_path = output_path("generated_day_god_data.rs")?;
+    let hash: String = content_hash(&[include_str!("mod.rs"), include_str!("original_strings.rs")]);
+    if is_up_to_date(&dest_path, &hash) {
+        return Ok(());
+    }
+
+    // 生成 Rust 代码：每月按干支日序号逐格写出，保证下标即干支日序号
     let mut content = format!("{}\n", DAY_GOD_HEADER);
     content.push_str("// 自动生成的 Day Gods 数据\n");
     content.push_str("#[rustfmt::skip]\n");
     content.push_str("pub static DAY_GODS_TABLE: [[Option<&[u8]>; 60]; 12] = [\n");
 
-    for month_data in DAY_GODS.iter() {
+    for (month_index, month_data) in DAY_GODS.iter().enumerate() {
+        let day_entries: Vec<Option<Vec<u8>>> = parse_month(month_data)
+            .map_err(|e| anyhow!("illegal day gods data of month {}: {}", month_index, e))?;
         content.push_str("    [\n");
-
-        let mut day_entries: [Option<&'static [u8]>; 60] = [None; 60];
-
-        for segment in month_data.split(';') {
-            if segment.len() >= 2 {
-                if let Ok(day_index) = u8::from_str_radix(&segment[0..2], 16) {
-                    if day_index < 60 {
-                        let data_str = &segment[2..];
-                        // 生成静态数组
-                        let data_vec: Vec<u8> = data_str
-                            .as_bytes()
-                            .chunks(2)
-                            .filter_map(|chunk| {
-                                if chunk.len() == 2 {
-                                    std::str::from_utf8(chunk)
-                                        .ok()
-                                        .and_then(|s| u8::from_str_radix(s, 16).ok())
-                                } else {
-                                    None
-                                }
-                            })
-                            .collect();
-
-                        if !data_vec.is_empty() {
-                            content.push_str(&format!("        Some(&{:?}),\n", data_vec));
-                            day_entries[day_index as usize] =
-                                Some(Box::leak(data_vec.into_boxed_slice()));
-                        }
-                    }
+        for (day_index, entry) in day_entries.iter().enumerate() {
+            match entry {
+                Some(data) => {
+                    content.push_str(&format!("        Some(&{:?}), // 天 {}\n", data, day_index))
                 }
+                None => content.push_str(&format!("        None, // 天 {}\n", day_index)),
             }
         }
-
-        // 对于没有数据的天，写入 None
-        for day_idx in 0..60 {
-            if day_entries[day_idx].is_none() {
-                content.push_str(&format!("        None, // 天 {}\n", day_idx));
-            }
-        }
-
         content.push_str("    ],\n");
     }
 
     content.push_str("];\n");
 
-    let dest_path = Path::new("src")
-        .join("culture")
-        .join("generated_day_god_data.rs");
+    // 写入文件（首行记录输入哈希）
+    write_generated(&dest_path, &hash, &content)?;
+
+    Ok(())
+}
 
-    // 写入文件
-    let mut f = File::create(&dest_path).unwrap();
-    writeln!(f, "{}", content)?;
+/// 解析一个月的数据（以;分隔，每段为2位十六进制干支日序号及其后的神煞序号），按干支日序号排成60格，无数据的格为None。
+/// 序号越界、重复或数据不是完整的十六进制字节时报错
+fn parse_month(month_data: &str) -> Result<Vec<Option<Vec<u8>>>> {
+    let mut day_entries: Vec<Option<Vec<u8>>> = vec![None; 60];
+    for segment in month_data.split(';').filter(|s| !s.is_empty()) {
+        let bytes: Vec<u8> = parse_hex(segment)?;
+        let day_index: usize = bytes[0] as usize;
+        if day_index >= 60 {
+            bail!("day index out of range: {}", segment);
+        }
+        if day_entries[day_index].is_some() {
+            bail!("duplicate day index: {}", segment);
+        }
+        if bytes.len() > 1 {
+            day_entries[day_index] = Some(bytes[1..].to_vec());
+        }
+    }
+    verify_month(month_data, &day_entries)?;
+    Ok(day_entries)
+}
 
+/// 校验排好的60格：以原始字符串为准，每个非空段都须在其前2位所示的格中重新编码得到（按十六进制文本比较，不经parse_hex），
+/// 且有数据的格数与非空段数相同
+fn verify_month(month_data: &str, day_entries: &[Option<Vec<u8>>]) -> Result<()> {
+    let segments: Vec<String> = month_data
+        .split(';')
+        .filter(|s| s.len() > 2)
+        .map(|s| s.to_ascii_uppercase())
+        .collect();
+    let mut emitted: usize = 0;
+    for (day_index, entry) in day_entries.iter().enumerate() {
+        if let Some(data) = entry {
+            let encoded: String = data
+                .iter()
+                .fold(format!("{:02X}", day_index), |mut acc, b| {
+                    acc.push_str(&format!("{:02X}", b));
+                    acc
+                });
+            if !segments.contains(&encoded) {
+                bail!("day {} misaligned: {}", day_index, encoded);
+            }
+            emitted += 1;
+        }
+    }
+    if emitted != segments.len() {
+        bail!("{} segments, {} days emitted", segments.len(), emitted);
+    }
     Ok(())
 }
+
+/// 解析十六进制字节串
+fn parse_hex(segment: &str) -> Result<Vec<u8>> {
+    if !segment.len().is_multiple_of(2) || !segment.is_ascii() {
+        bail!("illegal segment: {}", segment);
+    }
+    (0..segment.len())
+        .step_by(2)
+        .map(|i| {
+            u8::from_str_radix(&segment[i..i + 2], 16)
+                .map_err(|_| anyhow!("illegal segment: {}", segment))
+        })
+        .collect()
+}
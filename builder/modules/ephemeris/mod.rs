@@ -0,0 +1,148 @@
+use anyhow::{Result, bail};
+
+use crate::builder::generated::{content_hash, is_up_to_date, output_path, write_generated};
+
+#[path = "../../../src/sxtwl/coefficients.rs"]
+#[allow(clippy::all)]
+mod coefficients;
+
+use coefficients::{XL0_0, XL1_0, XL1_1, XL1_2};
+
+pub const EPHEMERIS_HEADER: &str = r#"// 此文件由 build.rs 自动生成，不要手动修改。
+// 星历系数表（XL0、XL1），按ephemeris-*特性截断各级数"#;
+
+/// XL0、XL1各级数保留项数的千分比：ephemeris-full优先于ephemeris-medium，ephemeris-medium优先于ephemeris-small
+#[cfg(any(
+    feature = "ephemeris-full",
+    not(any(feature = "ephemeris-medium", feature = "ephemeris-small"))
+))]
+const KEEP_PERMILLE: usize = 1000;
+
+#[cfg(all(not(feature = "ephemeris-full"), feature = "ephemeris-medium"))]
+const KEEP_PERMILLE: usize = 500;
+
+#[cfg(all(
+    not(feature = "ephemeris-full"),
+    not(feature = "ephemeris-medium"),
+    feature = "ephemeris-small"
+))]
+const KEEP_PERMILLE: usize = 250;
+
+/// 启用external-ephemeris时，未加载外部系数表前所用的编入系数表的保留千分比（同ephemeris-small）
+#[cfg(feature = "external-ephemeris")]
+const FALLBACK_KEEP_PERMILLE: usize = 250;
+
+/// 级数截断后保留的项数（向上取整，至少保留1项）
+fn kept(terms: usize, permille: usize) -> usize {
+    (terms * permille).div_ceil(1000)
+}
+
+/// 截断XL1类系数表（每项6个系数）
+fn truncate_xl1(table: &[f64], permille: usize) -> Result<Vec<f64>> {
+    if !table.len().is_multiple_of(6) {
+        bail!("XL1 table length {} is not a multiple of 6", table.len());
+    }
+    Ok(table[..kept(table.len() / 6, permille) * 6].to_vec())
+}
+
+/// 截断XL0系数表（倍率、位置索引表、各级数每项3个系数）的各级数并重建位置索引表
+fn truncate_xl0(table: &[f64], permille: usize) -> Result<Vec<f64>> {
+    let start: usize = table[1] as usize;
+    if start < 2 || start > table.len() {
+        bail!("bad XL0 index table start {}", start);
+    }
+    let mut r: Vec<f64> = table[..start].to_vec();
+    for i in 1..start - 1 {
+        let from: usize = table[i] as usize;
+        let to: usize = table[i + 1] as usize;
+        if from > to || to > table.len() || !(to - from).is_multiple_of(3) {
+            bail!("bad XL0 series {}: {}..{}", i, from, to);
+        }
+        let n: usize = kept((to - from) / 3, permille) * 3;
+        r.extend_from_slice(&table[from..from + n]);
+        r[i + 1] = r.len() as f64;
+    }
+    Ok(r)
+}
+
+fn push_table(content: &mut String, doc: &str, name: &str, table: &[f64]) {
+    content.push_str(&format!("/// {}\n", doc));
+    content.push_str("#[rustfmt::skip]\n");
+    content.push_str(&format!(
+        "pub static {}: [f64; {}] = {:?};\n\n",
+        name,
+        table.len(),
+        table
+    ));
+}
+
+/// 按千分比截断并写出XL0、XL1各表，表名加前缀prefix
+fn push_tables(content: &mut String, prefix: &str, permille: usize) -> Result<()> {
+    push_table(
+        content,
+        "XL0太阳（地球）黄经系数表",
+        &format!("{}XL0", prefix),
+        &truncate_xl0(&XL0_0, permille)?,
+    );
+    for (i, table) in XL1_0.iter().enumerate() {
+        push_table(
+            content,
+            &format!("XL1月球黄经系数表第{}组", i),
+            &format!("{}XL1_0_{}", prefix, i),
+            &truncate_xl1(table, permille)?,
+        );
+    }
+    for (i, table) in XL1_1.iter().enumerate() {
+        push_table(
+            content,
+            &format!("XL1月球黄纬系数表第{}组", i),
+            &format!("{}XL1_1_{}", prefix, i),
+            &truncate_xl1(table, permille)?,
+        );
+    }
+    for (i, table) in XL1_2.iter().enumerate() {
+        push_table(
+            content,
+            &format!("XL1月地距离系数表第{}组", i),
+            &format!("{}XL1_2_{}", prefix, i),
+            &truncate_xl1(table, permille)?,
+        );
+    }
+    Ok(())
+}
+
+pub fn generate_ephemeris_tables() -> Result<()> {
+    let dest_path = output_path("generated_ephemeris_tables.rs")?;
+    let hash: String = content_hash(&[
+        include_str!("mod.rs"),
+        include_str!("../../../src/sxtwl/coefficients.rs"),
+        &KEEP_PERMILLE.to_string(),
+        &cfg!(feature = "external-ephemeris").to_string(),
+    ]);
+    if is_up_to_date(&dest_path, &hash) {
+        return Ok(());
+    }
+
+    // 生成 Rust 代码
+    let mut content = format!("{}\n\n", EPHEMERIS_HEADER);
+    content.push_str("/// XL0、XL1各级数保留项数的千分比\n");
+    content.push_str(&format!(
+        "pub const KEEP_PERMILLE: usize = {};\n\n",
+        KEEP_PERMILLE
+    ));
+    push_tables(&mut content, "", KEEP_PERMILLE)?;
+    #[cfg(feature = "external-ephemeris")]
+    {
+        content.push_str("/// 未加载外部系数表前所用系数表的保留千分比\n");
+        content.push_str(&format!(
+            "pub const FALLBACK_KEEP_PERMILLE: usize = {};\n\n",
+            FALLBACK_KEEP_PERMILLE
+        ));
+        push_tables(&mut content, "FALLBACK_", FALLBACK_KEEP_PERMILLE)?;
+    }
+
+    // 写入文件（首行记录输入哈希）
+    write_generated(&dest_path, &hash, &content)?;
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use anyhow::{Result, anyhow};
+
+use crate::builder::generated::{content_hash, is_up_to_date, output_path, write_generated};
+
+mod original_era_strings;
+use original_era_strings::ERA_DATA;
+
+pub const ERA_HEADER: &str = r#"// 自动生成的年号数据
+
+/// 年号条目
+#[derive(Debug, Clone, Copy)]
+pub struct EraEntry {
+    pub dynasty: &'static str,
+    pub name: &'static str,
+    pub start_year: i16,
+    pub year_count: u8,
+}"#;
+
+pub fn generate_era_data() -> Result<()> {
+    let dest_path = output_path("generated_era_data.rs")?;
+    let hash: String = content_hash(&[
+        include_str!("mod.rs"),
+        include_str!("original_era_strings.rs"),
+    ]);
+    if is_up_to_date(&dest_path, &hash) {
+        return Ok(());
+    }
+
+    let records: Vec<&str> = ERA_DATA.split(';').filter(|s| !s.is_empty()).collect();
+
+    let mut content = format!("{}\n\n", ERA_HEADER);
+    content.push_str(&format!(
+        "pub static ERA_TABLE: [EraEntry; {}] = [\n",
+        records.len()
+    ));
+
+    for record in records {
+        let fields: Vec<&str> = record.split(',').collect();
+        if fields.len() != 4 {
+            return Err(anyhow!("illegal era record: {}", record));
+        }
+        let start_year = fields[2].parse::<i16>()?;
+        let year_count = fields[3].parse::<u8>()?;
+        content.push_str(&format!(
+            "    EraEntry {{ dynasty: \"{}\", name: \"{}\", start_year: {}, year_count: {} }},\n",
+            fields[0], fields[1], start_year, year_count
+        ));
+    }
+
+    content.push_str("];\n");
+
+    // 写入文件（首行记录输入哈希）
+    write_generated(&dest_path, &hash, &content)?;
+
+    Ok(())
+}
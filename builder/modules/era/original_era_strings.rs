@@ -0,0 +1,3 @@
+// 年号字符串：朝代,年号,元年（天文纪年，公元前1年为0）,年数;
+#[rustfmt::skip]
+pub const ERA_DATA: &str = "西汉,建元,-139,6;西汉,元光,-133,6;西汉,元朔,-127,6;西汉,元狩,-121,6;西汉,元鼎,-115,6;西汉,元封,-109,6;西汉,太初,-103,4;西汉,天汉,-99,4;西汉,太始,-95,4;西汉,征和,-91,4;西汉,后元,-87,2;西汉,始元,-85,7;西汉,元凤,-79,6;西汉,元平,-73,1;西汉,本始,-72,4;西汉,地节,-68,4;西汉,元康,-64,5;西汉,神爵,-60,4;西汉,五凤,-56,4;西汉,甘露,-52,4;西汉,黄龙,-48,1;西汉,初元,-47,5;西汉,永光,-42,5;西汉,建昭,-37,5;西汉,竟宁,-32,1;西汉,建始,-31,4;西汉,河平,-27,4;西汉,阳朔,-23,4;西汉,鸿嘉,-19,4;西汉,永始,-15,4;西汉,元延,-11,4;西汉,绥和,-7,2;西汉,建平,-5,4;西汉,元寿,-1,2;西汉,元始,1,5;西汉,居摄,6,3;西汉,初始,8,1;新,始建国,9,5;新,天凤,14,6;新,地皇,20,4;玄汉,更始,23,3;东汉,建武,25,32;东汉,建武中元,56,2;东汉,永平,58,18;东汉,建初,76,9;东汉,元和,84,4;东汉,章和,87,2;东汉,永元,89,17;东汉,元兴,105,1;东汉,延平,106,1;东汉,永初,107,7;东汉,元初,114,7;东汉,永宁,120,2;东汉,建光,121,2;东汉,延光,122,4;东汉,永建,126,7;东汉,阳嘉,132,4;东汉,永和,136,6;东汉,汉安,142,3;东汉,建康,144,1;东汉,永嘉,145,1;东汉,本初,146,1;东汉,建和,147,3;东汉,和平,150,1;东汉,元嘉,151,3;东汉,永兴,153,2;东汉,永寿,155,4;东汉,延熹,158,10;东汉,永康,167,1;东汉,建宁,168,5;东汉,熹平,172,7;东汉,光和,178,7;东汉,中平,184,6;东汉,初平,190,4;东汉,兴平,194,2;东汉,建安,196,25;东汉,延康,220,1;曹魏,黄初,220,7;曹魏,太和,227,7;曹魏,青龙,233,5;曹魏,景初,237,3;曹魏,正始,240,10;曹魏,嘉平,249,6;曹魏,正元,254,3;曹魏,甘露,256,5;曹魏,景元,260,5;曹魏,咸熙,264,2;蜀汉,章武,221,3;蜀汉,建兴,223,15;蜀汉,延熙,238,20;蜀汉,景耀,258,6;蜀汉,炎兴,263,1;孙吴,黄武,222,8;孙吴,黄龙,229,3;孙吴,嘉禾,232,7;孙吴,赤乌,238,14;孙吴,太元,251,2;孙吴,神凤,252,1;孙吴,建兴,252,2;孙吴,五凤,254,3;孙吴,太平,256,3;孙吴,永安,258,7;孙吴,元兴,264,2;孙吴,甘露,265,2;孙吴,宝鼎,266,4;孙吴,建衡,269,3;孙吴,凤凰,272,3;孙吴,天册,275,2;孙吴,天玺,276,1;孙吴,天纪,277,4;西晋,泰始,265,10;西晋,咸宁,275,6;西晋,太康,280,10;西晋,太熙,290,1;西晋,永熙,290,1;西晋,永平,291,1;西晋,元康,291,9;西晋,永康,300,2;西晋,永宁,301,2;西晋,太安,302,2;西晋,永安,304,1;西晋,建武,304,1;西晋,永兴,304,3;西晋,光熙,306,1;西晋,永嘉,307,7;西晋,建兴,313,5;东晋,建武,317,2;东晋,大兴,318,4;东晋,永昌,322,2;东晋,太宁,323,4;东晋,咸和,326,9;东晋,咸康,335,8;东晋,建元,343,2;东晋,永和,345,12;东晋,升平,357,5;东晋,隆和,362,2;东晋,兴宁,363,3;东晋,太和,366,6;东晋,咸安,371,2;东晋,宁康,373,3;东晋,太元,376,21;东晋,隆安,397,5;东晋,元兴,402,3;东晋,义熙,405,14;东晋,元熙,419,2;刘宋,永初,420,3;刘宋,景平,423,2;刘宋,元嘉,424,30;刘宋,孝建,454,3;刘宋,大明,457,8;刘宋,永光,465,1;刘宋,景和,465,1;刘宋,泰始,465,7;刘宋,泰豫,472,1;刘宋,元徽,473,5;刘宋,昇明,477,3;南齐,建元,479,4;南齐,永明,483,11;南齐,隆昌,494,1;南齐,延兴,494,1;南齐,建武,494,5;南齐,永泰,498,1;南齐,永元,499,3;南齐,中兴,501,2;南梁,天监,502,18;南梁,普通,520,8;南梁,大通,527,3;南梁,中大通,529,6;南梁,大同,535,12;南梁,中大同,546,2;南梁,太清,547,3;南梁,大宝,550,2;南梁,承圣,552,4;南梁,天成,555,1;南梁,绍泰,555,2;南梁,太平,556,2;南陈,永定,557,3;南陈,天嘉,560,7;南陈,天康,566,1;南陈,光大,567,2;南陈,太建,569,14;南陈,至德,583,4;南陈,祯明,587,3;隋,开皇,581,20;隋,仁寿,601,4;隋,大业,605,14;隋,义宁,617,2;唐,武德,618,9;唐,贞观,627,23;唐,永徽,650,6;唐,显庆,656,6;唐,龙朔,661,3;唐,麟德,664,2;唐,乾封,666,3;唐,总章,668,3;唐,咸亨,670,5;唐,上元,674,3;唐,仪凤,676,4;唐,调露,679,2;唐,永隆,680,2;唐,开耀,681,2;唐,永淳,682,2;唐,弘道,683,1;唐,嗣圣,684,1;唐,文明,684,1;唐,光宅,684,1;唐,垂拱,685,4;唐,永昌,689,1;唐,载初,690,1;武周,天授,690,3;武周,如意,692,1;武周,长寿,692,3;武周,延载,694,1;武周,证圣,695,1;武周,天册万岁,695,2;武周,万岁登封,696,1;武周,万岁通天,696,2;武周,神功,697,1;武周,圣历,698,3;武周,久视,700,1;武周,大足,701,1;武周,长安,701,4;唐,神龙,705,3;唐,景龙,707,4;唐,唐隆,710,1;唐,景云,710,2;唐,太极,712,1;唐,延和,712,1;唐,先天,712,2;唐,开元,713,29;唐,天宝,742,15;唐,至德,756,3;唐,乾元,758,3;唐,上元,760,2;唐,宝应,762,2;唐,广德,763,2;唐,永泰,765,2;唐,大历,766,14;唐,建中,780,4;唐,兴元,784,1;唐,贞元,785,21;唐,永贞,805,1;唐,元和,806,15;唐,长庆,821,4;唐,宝历,825,3;唐,大和,827,9;唐,开成,836,5;唐,会昌,841,6;唐,大中,847,14;唐,咸通,860,15;唐,乾符,874,6;唐,广明,880,2;唐,中和,881,5;唐,光启,885,4;唐,文德,888,1;唐,龙纪,889,1;唐,大顺,890,2;唐,景福,892,2;唐,乾宁,894,5;唐,光化,898,4;唐,天复,901,4;唐,天祐,904,4;后梁,开平,907,5;后梁,乾化,911,5;后梁,贞明,915,7;后梁,龙德,921,3;后唐,同光,923,4;后唐,天成,926,5;后唐,长兴,930,4;后唐,应顺,934,1;后唐,清泰,934,3;后晋,天福,936,9;后晋,开运,944,3;后汉,天福,947,1;后汉,乾祐,948,3;后周,广顺,951,3;后周,显德,954,7;北宋,建隆,960,4;北宋,乾德,963,6;北宋,开宝,968,9;北宋,太平兴国,976,9;北宋,雍熙,984,4;北宋,端拱,988,2;北宋,淳化,990,5;北宋,至道,995,3;北宋,咸平,998,6;北宋,景德,1004,4;北宋,大中祥符,1008,9;北宋,天禧,1017,5;北宋,乾兴,1022,1;北宋,天圣,1023,10;北宋,明道,1032,2;北宋,景祐,1034,5;北宋,宝元,1038,3;北宋,康定,1040,2;北宋,庆历,1041,8;北宋,皇祐,1049,6;北宋,至和,1054,3;北宋,嘉祐,1056,8;北宋,治平,1064,4;北宋,熙宁,1068,10;北宋,元丰,1078,8;北宋,元祐,1086,9;北宋,绍圣,1094,5;北宋,元符,1098,3;北宋,建中靖国,1101,1;北宋,崇宁,1102,5;北宋,大观,1107,4;北宋,政和,1111,8;北宋,重和,1118,2;北宋,宣和,1119,7;北宋,靖康,1126,2;南宋,建炎,1127,4;南宋,绍兴,1131,32;南宋,隆兴,1163,2;南宋,乾道,1165,9;南宋,淳熙,1174,16;南宋,绍熙,1190,5;南宋,庆元,1195,6;南宋,嘉泰,1201,4;南宋,开禧,1205,3;南宋,嘉定,1208,17;南宋,宝庆,1225,3;南宋,绍定,1228,6;南宋,端平,1234,3;南宋,嘉熙,1237,4;南宋,淳祐,1241,12;南宋,宝祐,1253,6;南宋,开庆,1259,1;南宋,景定,1260,5;南宋,咸淳,1265,10;南宋,德祐,1275,2;南宋,景炎,1276,3;南宋,祥兴,1278,2;元,中统,1260,5;元,至元,1264,31;元,元贞,1295,3;元,大德,1297,11;元,至大,1308,4;元,皇庆,1312,2;元,延祐,1314,7;元,至治,1321,3;元,泰定,1324,5;元,致和,1328,1;元,天顺,1328,1;元,天历,1328,3;元,至顺,1330,4;元,元统,1333,3;元,至元,1335,6;元,至正,1341,28;明,洪武,1368,31;明,建文,1399,4;明,永乐,1403,22;明,洪熙,1425,1;明,宣德,1426,10;明,正统,1436,14;明,景泰,1450,8;明,天顺,1457,8;明,成化,1465,23;明,弘治,1488,18;明,正德,1506,16;明,嘉靖,1522,45;明,隆庆,1567,6;明,万历,1573,48;明,泰昌,1620,1;明,天启,1621,7;明,崇祯,1628,17;清,天命,1616,11;清,天聪,1627,10;清,崇德,1636,8;清,顺治,1644,18;清,康熙,1662,61;清,雍正,1723,13;清,乾隆,1736,60;清,嘉庆,1796,25;清,道光,1821,30;清,咸丰,1851,11;清,同治,1862,13;清,光绪,1875,34;清,宣统,1909,3;中华民国,民国,1912,38;";
@@ -0,0 +1,67 @@
+use anyhow::{Result, anyhow};
+
+use crate::builder::generated::{content_hash, is_up_to_date, output_path, write_generated};
+
+mod original_farming_strings;
+use original_farming_strings::FARMING_DATA;
+
+pub const FARMING_HEADER: &str = r#"// 自动生成的节气农事数据
+
+/// 节气农事条目
+#[derive(Debug, Clone, Copy)]
+pub struct FarmingEntry {
+    pub advice: &'static str,
+    pub proverbs: &'static [&'static str],
+}"#;
+
+/// 节气名称（冬至起）
+const SOLAR_TERM_NAMES: [&str; 24] = [
+    "冬至", "小寒", "大寒", "立春", "雨水", "惊蛰", "春分", "清明", "谷雨", "立夏", "小满", "芒种",
+    "夏至", "小暑", "大暑", "立秋", "处暑", "白露", "秋分", "寒露", "霜降", "立冬", "小雪", "大雪",
+];
+
+pub fn generate_farming_data() -> Result<()> {
+    let dest_path = output_path("generated_farming_data.rs")?;
+    let hash: String = content_hash(&[
+        include_str!("mod.rs"),
+        include_str!("original_farming_strings.rs"),
+    ]);
+    if is_up_to_date(&dest_path, &hash) {
+        return Ok(());
+    }
+
+    let records: Vec<&str> = FARMING_DATA.split(';').filter(|s| !s.is_empty()).collect();
+    if records.len() != 24 {
+        return Err(anyhow!("illegal farming record count: {}", records.len()));
+    }
+
+    let mut content = format!("{}\n\n", FARMING_HEADER);
+    content.push_str("pub static FARMING_TABLE: [FarmingEntry; 24] = [\n");
+
+    for (i, record) in records.into_iter().enumerate() {
+        let fields: Vec<&str> = record.split(',').collect();
+        if fields.len() != 3 {
+            return Err(anyhow!("illegal farming record: {}", record));
+        }
+        if fields[0] != SOLAR_TERM_NAMES[i] {
+            return Err(anyhow!("unexpected solar term: {}", fields[0]));
+        }
+        let proverbs: Vec<String> = fields[2]
+            .split('|')
+            .filter(|s| !s.is_empty())
+            .map(|s| format!("\"{}\"", s))
+            .collect();
+        content.push_str(&format!(
+            "    FarmingEntry {{ advice: \"{}\", proverbs: &[{}] }},\n",
+            fields[1],
+            proverbs.join(", ")
+        ));
+    }
+
+    content.push_str("];\n");
+
+    // 写入文件（首行记录输入哈希）
+    write_generated(&dest_path, &hash, &content)?;
+
+    Ok(())
+}
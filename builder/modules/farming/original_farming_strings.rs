@@ -0,0 +1,3 @@
+// 节气农事字符串：节气,农事提示,谚语|谚语;（按冬至起排列）
+#[rustfmt::skip]
+pub const FARMING_DATA: &str = "冬至,做好越冬作物防冻，积肥造肥,冬至不冷，夏至不热|冬至晴，正月雨;小寒,防寒防冻，检修农具,小寒大寒，冷成冰团|小寒不寒，清明泥潭;大寒,做好春耕准备，选种晒种,大寒不寒，人马不安|大寒见三白，农人衣食足;立春,整地施肥，育苗备耕,立春一日，百草回芽|打春阳气转;雨水,小麦追肥，油菜中耕,雨水有雨庄稼好|七九八九雨水节，种田老汉不能歇;惊蛰,春耕开始，防治病虫,惊蛰不耙地，好比蒸馍跑了气|到了惊蛰节，锄头不停歇;春分,春播春种，植树造林,春分麦起身，一刻值千金|春分前后怕春霜;清明,种瓜点豆，植树插柳,清明前后，种瓜点豆|植树造林，莫过清明;谷雨,播种移苗，采摘春茶,谷雨前后，栽秧点豆|谷雨下秧，大致不差;立夏,夏收夏种准备，中耕除草,立夏种麻，七股八杈|立夏三天遍地锄;小满,小麦灌浆，防干热风,小满不满，麦有一险|小满麦渐黄，夏至稻花香;芒种,抢收小麦，抢种秋作物,芒种忙，麦上场|芒种不种，再种无用;夏至,夏管除草，防汛抗旱,夏至不锄根边草，如同养下毒蛇咬|夏至东南风，平地把船撑;小暑,田间管理，防治虫害,小暑不算热，大暑正伏天|小暑雨如银，大暑雨如金;大暑,防暑抗旱，追肥灌溉,大暑不暑，五谷不鼓|禾到大暑日夜黄;立秋,秋田管理，播种秋菜,立秋三场雨，秕稻变成米|立秋种芝麻，老死不开花;处暑,抢收早稻，防治秋虫,处暑满田黄，家家修廪仓|处暑不出头，割谷喂老牛;白露,秋收秋种，采收棉花,白露种高山，秋分种平川|白露白迷迷，秋分稻秀齐;秋分,秋收秋耕秋种,秋分种麦正当时|秋分不割，霜打风磨;寒露,播种冬麦，收获晚稻,寒露不摘棉，霜打莫怨天|寒露种小麦，种一碗收一斗;霜降,防霜防冻，收获秋薯,霜降见霜，米谷满仓|霜降不起葱，越长越要空;立冬,冬灌保墒，储藏越冬蔬菜,立冬不砍菜，受冻莫怪天|立冬种麦正当时;小雪,积肥造肥，修建水利,小雪雪满天，来年必丰年|小雪不起菜，就要受冻害;大雪,越冬作物覆盖防冻,大雪兆丰年，无雪要遭殃|冬雪是麦被，春雪是麦鬼;";
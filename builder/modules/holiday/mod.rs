@@ -1,11 +1,11 @@
-use std::fs::File;
-use std::io::Write;
-use std::path::Path;
+use std::collections::HashMap;
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+
+use crate::builder::generated::{content_hash, is_up_to_date, output_path, write_generated};
 
 mod original_holiday_strings;
-use original_holiday_strings::LEGAL_HOLIDAY_DATA;
+use original_holiday_strings::{LEGAL_HOLIDAY_DATA, LEGAL_HOLIDAY_SNAPSHOT};
 
 pub const HOLIDAYS_HEADER: &str = r#"// 自动生成的法定节假日数据
 
@@ -17,18 +17,45 @@ pub struct LegalHolidayEntry {
     pub day: u8,
     pub work: bool,
     pub index: u8,
+    /// 假期编号（连续休息日及其调休上班日相同）
+    pub period: u16,
+    /// 假期第几天（从1开始，调休上班为0）
+    pub day_index: u8,
+    /// 假期共几天
+    pub day_count: u8,
 }"#;
 
+/// 公历日期转为日序数（公元1年1月1日为1）
+fn to_day_number(year: i64, month: i64, day: i64) -> i64 {
+    let (y, m) = if month <= 2 {
+        (year - 1, month + 12)
+    } else {
+        (year, month)
+    };
+    365 * y + y / 4 - y / 100 + y / 400 + (153 * (m - 3) + 2) / 5 + day - 306
+}
+
 pub fn generate_holidays_data() -> Result<()> {
+    let dest_path = output_path("generated_holidays_data.rs")?;
+    let hash: String = content_hash(&[
+        include_str!("mod.rs"),
+        include_str!("original_holiday_strings.rs"),
+    ]);
+    if is_up_to_date(&dest_path, &hash) {
+        return Ok(());
+    }
+
     // 生成 Rust 代码
     let mut content = format!("{}\n\n", HOLIDAYS_HEADER);
 
     let record_count = LEGAL_HOLIDAY_DATA.len() / 13;
     content.push_str(&format!(
-        "pub const LEGAL_HOLIDAY_TABLE: [LegalHolidayEntry; {}] = [\n",
+        "pub static LEGAL_HOLIDAY_TABLE: [LegalHolidayEntry; {}] = [\n",
         record_count
     ));
 
+    // 解析记录
+    let mut records = Vec::with_capacity(record_count);
     for i in 0..record_count {
         let start = i * 13;
         let record = &LEGAL_HOLIDAY_DATA[start..start + 13];
@@ -38,23 +65,108 @@ pub fn generate_holidays_data() -> Result<()> {
         let day = &record[6..8];
         let work_char = &record[8..9];
         let index_char = &record[9..10];
+        let offset = record[10..13].parse::<i64>()?;
 
         let work = work_char == "0";
         let index = index_char.parse::<u8>().unwrap();
 
+        let day_number = to_day_number(year.parse()?, month.parse()?, day.parse()?);
+        records.push((
+            year,
+            month,
+            day,
+            work,
+            index,
+            day_number,
+            day_number + offset,
+        ));
+    }
+
+    // 按日期排序，运行时按年份下标及二分查找定位条目
+    records.sort_by_key(|record| record.5);
+    for pair in records.windows(2) {
+        if pair[0].5 == pair[1].5 {
+            return Err(anyhow!(
+                "duplicate legal holiday record: {}{}{}",
+                pair[0].0,
+                pair[0].1,
+                pair[0].2
+            ));
+        }
+    }
+
+    // 连续的休息日为一次假期（与中秋相连的国庆等合并为一次）
+    let mut ranges: Vec<(i64, i64)> = Vec::new();
+    for &(_, _, _, work, _, day_number, _) in &records {
+        if work {
+            continue;
+        }
+        match ranges.last_mut() {
+            Some(range) if range.1 + 1 == day_number => range.1 = day_number,
+            _ => ranges.push((day_number, day_number)),
+        }
+    }
+
+    // 调休上班日归入其节日当天所在的假期，假期编号按首次出现的顺序
+    let mut periods: HashMap<i64, u16> = HashMap::new();
+    let years: Vec<u16> = records.iter().map(|r| r.0.parse().unwrap()).collect();
+    for &(year, month, day, work, index, day_number, target) in &records {
+        let key = if work { target } else { day_number };
+        let range = ranges.iter().find(|r| r.0 <= key && key <= r.1).copied();
+        // 无对应休息日的调休记录，自成一次假期，天数记为0
+        let (first, last) = range.unwrap_or((key + 1, key));
+        let next_period = periods.len() as u16;
+        let period = *periods.entry(first).or_insert(next_period);
+        let day_index = if work { 0 } else { day_number - first + 1 };
         content.push_str(&format!(
-            "    LegalHolidayEntry {{ year: {}, month: {}, day: {}, work: {}, index: {} }},\n",
-            year, month, day, work, index
+            "    LegalHolidayEntry {{ year: {}, month: {}, day: {}, work: {}, index: {}, period: {}, day_index: {}, day_count: {} }},\n",
+            year,
+            month,
+            day,
+            work,
+            index,
+            period,
+            day_index,
+            last - first + 1
         ));
     }
 
     content.push_str("];\n");
 
-    let dest_path = Path::new("src").join("generated_holidays_data.rs");
+    // 各年首个条目的下标，末项为条目总数
+    let first_year: u16 = records.first().map_or(0, |r| r.0.parse().unwrap());
+    let last_year: u16 = records.last().map_or(0, |r| r.0.parse().unwrap());
+    let mut offsets: Vec<usize> = Vec::new();
+    for year in first_year..=last_year + 1 {
+        offsets.push(years.iter().position(|y| *y >= year).unwrap_or(years.len()));
+    }
+    content.push_str("\n/// 内置数据的第一年\n");
+    content.push_str(&format!(
+        "pub static LEGAL_HOLIDAY_FIRST_YEAR: u16 = {};\n",
+        first_year
+    ));
+    content.push_str(
+        "\n/// 各年首个条目的下标：第year - LEGAL_HOLIDAY_FIRST_YEAR项，末项为条目总数\n",
+    );
+    content.push_str("#[rustfmt::skip]\n");
+    content.push_str(&format!(
+        "pub static LEGAL_HOLIDAY_YEAR_OFFSETS: [u16; {}] = {:?};\n",
+        offsets.len(),
+        offsets
+    ));
+    content.push_str("\n/// 数据快照日期（所依据节假日安排通知的发布日期）\n");
+    content.push_str(&format!(
+        "pub const LEGAL_HOLIDAY_SNAPSHOT: &str = {:?};\n",
+        LEGAL_HOLIDAY_SNAPSHOT
+    ));
+    content.push_str("\n/// 原始数据字符串的哈希（FNV-1a 64位）\n");
+    content.push_str(&format!(
+        "pub const LEGAL_HOLIDAY_SOURCE_HASH: &str = {:?};\n",
+        content_hash(&[LEGAL_HOLIDAY_DATA])
+    ));
 
-    // 写入文件
-    let mut f = File::create(&dest_path).unwrap();
-    writeln!(f, "{}", content)?;
+    // 写入文件（首行记录输入哈希）
+    write_generated(&dest_path, &hash, &content)?;
 
     Ok(())
 }
@@ -1,16 +1,23 @@
-use std::fs::File;
-use std::io::Write;
-use std::path::Path;
-
 use anyhow::Result;
 
-pub const LEAP_YEAR_HEADER: &str = r#"//! 此文件由 build.rs 自动生成，不要手动修改。
-//! 包含了预计算的闰月查找表数据"#;
+use crate::builder::generated::{content_hash, is_up_to_date, output_path, write_generated};
+
+pub const LEAP_YEAR_HEADER: &str = r#"// 此文件由 build.rs 自动生成，不要手动修改。
+// 包含了预计算的闰月查找表数据"#;
 
 mod original_leap_month_strings;
 use original_leap_month_strings::{CHARS, LEAP_MONTH};
 
 pub fn generate_leap_year_data() -> Result<()> {
+    let dest_path = output_path("generated_leap_year_data.rs")?;
+    let hash: String = content_hash(&[
+        include_str!("mod.rs"),
+        include_str!("original_leap_month_strings.rs"),
+    ]);
+    if is_up_to_date(&dest_path, &hash) {
+        return Ok(());
+    }
+
     let mut leap_month_data = Vec::new();
     let mut max_days_in_month = 0;
 
@@ -54,11 +61,8 @@ pub fn generate_leap_year_data() -> Result<()> {
     }
     content.push_str("];\n");
 
-    let dest_path = Path::new("src").join("generated_leap_year_data.rs");
-
-    // 写入文件
-    let mut f = File::create(&dest_path).unwrap();
-    writeln!(f, "{}", content)?;
+    // 写入文件（首行记录输入哈希）
+    write_generated(&dest_path, &hash, &content)?;
 
     Ok(())
 }
@@ -1,8 +1,15 @@
 #[cfg(feature = "god")]
 pub mod day_god;
+pub mod ephemeris;
+#[cfg(feature = "era")]
+pub mod era;
+#[cfg(feature = "farming")]
+pub mod farming;
 #[cfg(feature = "holiday")]
 pub mod holiday;
 pub mod leap_month;
 pub mod qishuo;
 #[cfg(feature = "rabbyung")]
 pub mod rab_byung_month_days;
+#[cfg(feature = "trig-lut")]
+pub mod sin_table;
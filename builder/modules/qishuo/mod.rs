@@ -1,9 +1,7 @@
-use std::fs::File;
-use std::io::Write;
-use std::path::Path;
-
 use anyhow::Result;
 
+use crate::builder::generated::{content_hash, is_up_to_date, output_path, write_generated};
+
 mod original_qishuo_strings;
 use original_qishuo_strings::{QI_S, SHUO_S};
 
@@ -118,6 +116,15 @@ fn string_to_two_bits(s: &str) -> (Vec<u8>, usize) {
 }
 
 pub fn generate_qishuo_data() -> Result<()> {
+    let dest_path = output_path("generated_compressed_qishuo_correction_data.rs")?;
+    let hash: String = content_hash(&[
+        include_str!("mod.rs"),
+        include_str!("original_qishuo_strings.rs"),
+    ]);
+    if is_up_to_date(&dest_path, &hash) {
+        return Ok(());
+    }
+
     // 处理朔日表
     let shuo_decompressed = jieya(SHUO_S);
     let (shuo_bytes, shuo_len) = string_to_two_bits(&shuo_decompressed);
@@ -127,18 +134,14 @@ pub fn generate_qishuo_data() -> Result<()> {
     let (qi_bytes, qi_len) = string_to_two_bits(&qi_decompressed);
 
     // 生成 Rust 代码
+    let source_hash: String = content_hash(&[SHUO_S, QI_S]);
     let content = format!(
-        "{}\npub const SHUO_BYTES: &[u8] = &{shuo_bytes:?};\npub const SHUO_LEN: usize = {shuo_len};\npub const QI_BYTES: &[u8] = &{qi_bytes:?};\npub const QI_LEN: usize = {qi_len};\n\n{}\n\n{}",
+        "{}\npub static SHUO_BYTES: &[u8] = &{shuo_bytes:?};\npub const SHUO_LEN: usize = {shuo_len};\npub static QI_BYTES: &[u8] = &{qi_bytes:?};\npub const QI_LEN: usize = {qi_len};\n\n/// 原始压缩字符串（SHUO_S、QI_S）的哈希（FNV-1a 64位）\npub const QISHUO_SOURCE_HASH: &str = {source_hash:?};\n\n{}\n\n{}",
         QISHUO_HEADER, GET_SHUO_FUNCTION, GET_QI_FUNCTION
     );
 
-    let dest_path = Path::new("src")
-        .join("sxtwl")
-        .join("generated_compressed_qishuo_correction_data.rs");
-
-    // 写入文件
-    let mut f = File::create(&dest_path).unwrap();
-    writeln!(&mut f, "{}", content)?;
+    // 写入文件（首行记录输入哈希）
+    write_generated(&dest_path, &hash, &content)?;
 
     Ok(())
 }
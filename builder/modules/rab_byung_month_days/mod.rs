@@ -1,33 +1,65 @@
 // build.rs
-use std::fs::File;
-use std::io::Write;
-use std::path::Path;
 
 use anyhow::Result;
 
-pub const RAB_BYUNG_MONTH_DAYS_HEADER: &str = r#"//! 此文件由 build.rs 自动生成，不要手动修改。
-//! 自动生成的 RabByung 数据
+use crate::builder::generated::{content_hash, is_up_to_date, output_path, write_generated};
+
+pub const RAB_BYUNG_MONTH_DAYS_HEADER: &str = r#"// 此文件由 build.rs 自动生成，不要手动修改。
+// 自动生成的 RabByung 数据
 
 #[derive(Debug, Clone)]
 pub struct RabByungMonthData {
     pub year: u16,
-    pub month: u8, 
+    pub month: u8,
     pub days: &'static [isize],
+    /// 自1950年12月初一至该月首日的天数
+    pub offset: u32,
 }
 "#;
 
 pub const RAB_BYUNG_MONTH_DAYS_FUNCTIONS: &str = r#"
-pub fn get_rab_byung_month_days(year: usize, month: usize) -> Option<&'static [isize]> {
+/// 月在表中的位置（表按年、年中索引升序）
+fn find_rab_byung_month_index(year: usize, month: usize) -> Option<usize> {
     RAB_BYUNG_DATA
-        .iter()
-        .find(|data| data.year == year as u16 && data.month == month as u8)
-        .map(|data| data.days)
+        .binary_search_by_key(&(year as u16, month as u8), |data| (data.year, data.month))
+        .ok()
+}
+
+/// 特殊日子（闰日为正，缺日为负）
+pub fn get_rab_byung_month_days(year: usize, month: usize) -> Option<&'static [isize]> {
+    find_rab_byung_month_index(year, month).map(|i| RAB_BYUNG_DATA[i].days)
+}
+
+/// 自1950年12月初一（公历1951年1月8日）至该月首日的天数
+pub fn get_rab_byung_month_offset(year: usize, month: usize) -> Option<usize> {
+    find_rab_byung_month_index(year, month).map(|i| RAB_BYUNG_DATA[i].offset as usize)
+}
+
+/// 自1950年12月初一起第days天（0起）所在的月（年, 年中索引）及该月首日的天数
+pub fn find_rab_byung_month(days: usize) -> Option<(usize, usize, usize)> {
+    let i: usize = RAB_BYUNG_DATA.partition_point(|data| data.offset as usize <= days);
+    if i == 0 {
+        return None;
+    }
+    let data: &RabByungMonthData = &RAB_BYUNG_DATA[i - 1];
+    let count: usize = 30 + data.days.iter().filter(|&&d| d > 0).count()
+        - data.days.iter().filter(|&&d| d < 0).count();
+    if days >= data.offset as usize + count {
+        return None;
+    }
+    Some((data.year as usize, data.month as usize, data.offset as usize))
 }"#;
 
 mod original_strings;
 use original_strings::RAW_DATA;
 
 pub fn generate_rab_byung_data() -> Result<()> {
+    let dest_path = output_path("generated_rab_byung.rs")?;
+    let hash: String = content_hash(&[include_str!("mod.rs"), include_str!("original_strings.rs")]);
+    if is_up_to_date(&dest_path, &hash) {
+        return Ok(());
+    }
+
     // 生成 Rust 代码
     let mut content = format!("{}\n", RAB_BYUNG_MONTH_DAYS_HEADER);
     content.push_str("#[rustfmt::skip]\n");
@@ -36,6 +68,7 @@ pub fn generate_rab_byung_data() -> Result<()> {
     let years: Vec<&str> = RAW_DATA.split(',').collect();
     let mut y: usize = 1950;
     let mut m: usize = 11;
+    let mut offset: usize = 0;
 
     for s in years {
         let mut ys = s;
@@ -54,9 +87,12 @@ pub fn generate_rab_byung_data() -> Result<()> {
 
             // 生成静态数组条目
             content.push_str(&format!(
-                "    RabByungMonthData {{ year: {}, month: {}, days: &{:?} }},\n",
-                y, m, days_array
+                "    RabByungMonthData {{ year: {}, month: {}, days: &{:?}, offset: {} }},\n",
+                y, m, days_array, offset
             ));
+            let leap_count = days_array.iter().filter(|&&d| d > 0).count();
+            let miss_count = days_array.iter().filter(|&&d| d < 0).count();
+            offset += 30 + leap_count - miss_count;
 
             // 更新位置
             m += 1;
@@ -69,11 +105,8 @@ pub fn generate_rab_byung_data() -> Result<()> {
     content.push_str("];\n");
     content.push_str(RAB_BYUNG_MONTH_DAYS_FUNCTIONS);
 
-    let dest_path = Path::new("src").join("generated_rab_byung.rs");
-
-    // 写入文件
-    let mut f = File::create(&dest_path).unwrap();
-    writeln!(f, "{}", content)?;
+    // 写入文件（首行记录输入哈希）
+    write_generated(&dest_path, &hash, &content)?;
 
     Ok(())
 }
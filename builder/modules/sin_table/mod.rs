@@ -0,0 +1,52 @@
+use std::f64::consts::FRAC_PI_2;
+
+use anyhow::Result;
+
+use crate::builder::generated::{content_hash, is_up_to_date, output_path, write_generated};
+
+pub const SIN_TABLE_HEADER: &str = r#"// 此文件由 build.rs 自动生成，不要手动修改。
+// 四分之一周期的正弦定点查找表"#;
+
+/// 四分之一周期的分段数
+const QUARTER_STEPS: usize = 1024;
+
+/// 定点小数位数
+const FRACTION_BITS: u32 = 30;
+
+pub fn generate_sin_table() -> Result<()> {
+    let dest_path = output_path("generated_sin_table.rs")?;
+    let hash: String = content_hash(&[include_str!("mod.rs")]);
+    if is_up_to_date(&dest_path, &hash) {
+        return Ok(());
+    }
+
+    let scale: f64 = (1u64 << FRACTION_BITS) as f64;
+    let values: Vec<i32> = (0..=QUARTER_STEPS)
+        .map(|i| ((i as f64 * FRAC_PI_2 / QUARTER_STEPS as f64).sin() * scale).round() as i32)
+        .collect();
+
+    // 生成 Rust 代码
+    let mut content = format!("{}\n\n", SIN_TABLE_HEADER);
+    content.push_str("/// 四分之一周期的分段数\n");
+    content.push_str(&format!(
+        "pub const QUARTER_STEPS: usize = {};\n\n",
+        QUARTER_STEPS
+    ));
+    content.push_str("/// 定点小数位数\n");
+    content.push_str(&format!(
+        "pub const FRACTION_BITS: u32 = {};\n\n",
+        FRACTION_BITS
+    ));
+    content.push_str("/// sin(i·π/2/QUARTER_STEPS)·2^FRACTION_BITS，i为0至QUARTER_STEPS\n");
+    content.push_str("#[rustfmt::skip]\n");
+    content.push_str(&format!(
+        "pub static SIN_TABLE: [i32; {}] = {:?};\n",
+        QUARTER_STEPS + 1,
+        values
+    ));
+
+    // 写入文件（首行记录输入哈希）
+    write_generated(&dest_path, &hash, &content)?;
+
+    Ok(())
+}
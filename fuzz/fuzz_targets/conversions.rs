@@ -0,0 +1,35 @@
+//! 农历、公历、藏历互转的模糊测试：任意输入只允许返回错误，不允许崩溃
+//!
+//! 运行：cargo +nightly fuzz run conversions
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sxtwl_rs::lunar::LunarDay;
+use sxtwl_rs::rabbyung::RabByungDay;
+use sxtwl_rs::solar::SolarDay;
+
+fuzz_target!(|data: (i16, i8, i8)| {
+    let (year, month, day) = data;
+    let (year, month, day) = (year as isize, month as isize, day as isize);
+
+    if let Ok(lunar_day) = LunarDay::new(year, month, day.unsigned_abs()) {
+        let solar_day: SolarDay = lunar_day.get_solar_day();
+        assert_eq!(lunar_day, solar_day.get_lunar_day());
+    }
+
+    if let Ok(solar_day) = SolarDay::new(year, month.unsigned_abs(), day.unsigned_abs()) {
+        let lunar_day: LunarDay = solar_day.get_lunar_day();
+        assert_eq!(solar_day, lunar_day.get_solar_day());
+        if let Ok(rab_byung_day) = RabByungDay::from_solar_day(solar_day) {
+            assert_eq!(solar_day, rab_byung_day.get_solar_day());
+        }
+    }
+
+    if let Ok(rab_byung_day) = RabByungDay::from_ymd(year, month, day) {
+        assert_eq!(
+            rab_byung_day,
+            RabByungDay::from_solar_day(rab_byung_day.get_solar_day()).unwrap()
+        );
+    }
+});
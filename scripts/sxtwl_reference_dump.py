@@ -0,0 +1,44 @@
+#!/usr/bin/env python3
+"""用C++版寿星万年历的Python绑定（pip install sxtwl）导出逐日参考数据，供 reference 特性对比。
+
+用法：python3 scripts/sxtwl_reference_dump.py 1600 2200 > reference.csv
+"""
+
+import datetime
+import sys
+
+import sxtwl
+
+GAN = "甲乙丙丁戊己庚辛壬癸"
+ZHI = "子丑寅卯辰巳午未申酉戌亥"
+JQMC = [
+    "冬至", "小寒", "大寒", "立春", "雨水", "惊蛰", "春分", "清明", "谷雨", "立夏", "小满", "芒种",
+    "夏至", "小暑", "大暑", "立秋", "处暑", "白露", "秋分", "寒露", "霜降", "立冬", "小雪", "大雪",
+]
+
+
+def gz(v):
+    return GAN[v.tg] + ZHI[v.dz]
+
+
+def main():
+    start = int(sys.argv[1]) if len(sys.argv) > 1 else 1600
+    end = int(sys.argv[2]) if len(sys.argv) > 2 else 2200
+    print("# solar,lunar year,lunar month,lunar day,year,month,day,term")
+    d = datetime.date(start, 1, 1)
+    last = datetime.date(end, 12, 31)
+    while d <= last:
+        day = sxtwl.fromSolar(d.year, d.month, d.day)
+        month = day.getLunarMonth()
+        if day.isLunarLeap():
+            month = -month
+        term = JQMC[day.getJieQi()] if day.hasJieQi() else ""
+        print(
+            f"{d.year}-{d.month:02d}-{d.day:02d},{day.getLunarYear()},{month},{day.getLunarDay()},"
+            f"{gz(day.getYearGZ())},{gz(day.getMonthGZ())},{gz(day.getDayGZ())},{term}"
+        )
+        d += datetime.timedelta(days=1)
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,217 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::context::CalendarContext;
+use crate::culture::PhaseDay;
+use crate::enums::Locale;
+use crate::extension::{CalendarExtRegistry, DayIndex};
+use crate::lunar::LunarDay;
+use crate::moon::MoonTimes;
+use crate::solar::{SolarDay, SolarTermDay, SolarTime, TermDayResolution};
+use crate::sun::{Observer, SunTimes};
+use crate::types::Culture;
+
+/// 黄历（某公历日的常用信息汇总）
+#[derive(Debug, Copy, Clone)]
+pub struct Almanac {
+    /// 公历日
+    day: SolarDay,
+    /// 配置上下文
+    context: CalendarContext,
+    /// 观测者位置，未指定时不计算日出日落、月出月落
+    observer: Option<Observer>,
+}
+
+impl Almanac {
+    pub fn from_solar_day(day: SolarDay) -> Self {
+        Self {
+            day,
+            context: CalendarContext::default(),
+            observer: None,
+        }
+    }
+
+    /// 使用指定配置上下文
+    pub fn with_context(&self, context: CalendarContext) -> Self {
+        Self { context, ..*self }
+    }
+
+    /// 指定观测者位置，启用日出日落、月出月落
+    pub fn with_observer(&self, observer: Observer) -> Self {
+        Self {
+            observer: Some(observer),
+            ..*self
+        }
+    }
+
+    /// 公历日
+    pub fn get_solar_day(&self) -> SolarDay {
+        self.day
+    }
+
+    /// 配置上下文
+    pub fn get_context(&self) -> CalendarContext {
+        self.context
+    }
+
+    /// 观测者位置
+    pub fn get_observer(&self) -> Option<Observer> {
+        self.observer
+    }
+
+    /// 月相第几天
+    pub fn get_phase_day(&self) -> PhaseDay {
+        self.day.get_phase_day()
+    }
+
+    /// 日出日没，未指定观测者时为None
+    pub fn get_sun_times(&self) -> Option<SunTimes> {
+        self.observer
+            .map(|observer| SunTimes::from_observer(self.day, observer))
+    }
+
+    /// 月出月落，未指定观测者时为None
+    pub fn get_moon_times(&self) -> Option<MoonTimes> {
+        self.observer
+            .map(|observer| MoonTimes::from_observer(self.day, observer))
+    }
+
+    /// 日出，未指定观测者或极昼、极夜时为None
+    pub fn get_sunrise(&self) -> Option<SolarTime> {
+        self.get_sun_times().and_then(|t| t.sunrise())
+    }
+
+    /// 日没，未指定观测者或极昼、极夜时为None
+    pub fn get_sunset(&self) -> Option<SolarTime> {
+        self.get_sun_times().and_then(|t| t.sunset())
+    }
+
+    /// 月出，未指定观测者、当天无月出或终日不升不没时为None
+    pub fn get_moonrise(&self) -> Option<SolarTime> {
+        self.get_moon_times().and_then(|t| t.moonrise())
+    }
+
+    /// 月落，未指定观测者、当天无月落或终日不升不没时为None
+    pub fn get_moonset(&self) -> Option<SolarTime> {
+        self.get_moon_times().and_then(|t| t.moonset())
+    }
+
+    /// 已登记的扩展指数（见CalendarExtRegistry），按登记顺序
+    pub fn get_indices(&self) -> Vec<DayIndex> {
+        CalendarExtRegistry::get_indices(self)
+    }
+
+    /// 黄历抬头，如：公历2024年6月15日 星期六 农历甲辰年五月初十 【芒种】第11天
+    pub fn header_string(&self) -> String {
+        let lunar: LunarDay = self.day.get_lunar_day();
+        let term_day: SolarTermDay = self.day.get_term_day();
+        match self.context.get_locale() {
+            Locale::ZH => format!(
+                "公历{} 星期{} {}{} 【{}】第{}天",
+                self.day,
+                self.day.get_week(),
+                lunar.get_lunar_month().get_lunar_year().get_name(),
+                lunar,
+                term_day.get_name(),
+                term_day.get_day_index() + 1
+            ),
+            Locale::EN => format!(
+                "{:04}-{:02}-{:02} {} Lunar {} {} Day {} [{}] Day {}",
+                self.day.get_year(),
+                self.day.get_month(),
+                self.day.get_day(),
+                self.day.get_week().name_en(),
+                lunar
+                    .get_lunar_month()
+                    .get_lunar_year()
+                    .get_sixty_cycle()
+                    .name_en(),
+                lunar.get_lunar_month().name_en(),
+                lunar.get_day(),
+                term_day.get_solar_term().name_en(),
+                term_day.get_day_index() + 1
+            ),
+        }
+    }
+
+    /// 当天交节时的交节日判定，当天不交节返回None
+    pub fn get_term_day_resolution(&self) -> Option<TermDayResolution> {
+        let term_day: SolarTermDay = self.day.get_term_day();
+        if term_day.get_day_index() != 0 {
+            return None;
+        }
+        Some(term_day.get_solar_term().get_day_resolution())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::almanac::Almanac;
+    use crate::context::CalendarContext;
+    use crate::enums::Locale;
+    use crate::solar::SolarDay;
+    use crate::sun::Observer;
+
+    #[test]
+    fn test1() {
+        let almanac: Almanac = Almanac::from_solar_day(SolarDay::from_ymd(2024, 6, 15));
+        assert_eq!(
+            "公历2024年6月15日 星期六 农历甲辰年五月初十 【芒种】第11天",
+            almanac.header_string()
+        );
+        assert_eq!(
+            "2024-06-15 Saturday Lunar Jia-Chen Month 5 Day 10 [Grain in Ear] Day 11",
+            almanac
+                .with_context(CalendarContext::default().with_locale(Locale::EN))
+                .header_string()
+        );
+    }
+
+    #[test]
+    fn test2() {
+        let almanac: Almanac = Almanac::from_solar_day(SolarDay::from_ymd(2023, 4, 1));
+        assert_eq!(
+            "公历2023年4月1日 星期六 农历癸卯年闰二月十一 【春分】第12天",
+            almanac.header_string()
+        );
+    }
+
+    #[test]
+    fn test3() {
+        let almanac: Almanac = Almanac::from_solar_day(SolarDay::from_ymd(2042, 4, 4));
+        assert!(almanac.get_term_day_resolution().unwrap().is_ambiguous());
+        assert!(
+            Almanac::from_solar_day(SolarDay::from_ymd(2042, 4, 5))
+                .get_term_day_resolution()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test4() {
+        let almanac: Almanac = Almanac::from_solar_day(SolarDay::from_ymd(2024, 9, 17));
+        assert!(almanac.get_sunrise().is_none());
+        assert!(almanac.get_moon_times().is_none());
+        assert_eq!("盈凸月第6天", almanac.get_phase_day().to_string());
+
+        let almanac: Almanac = almanac.with_observer(Observer::from_degrees(116.4, 39.9));
+        assert!(almanac.get_sunrise().is_some());
+        assert!(almanac.get_sunset().is_some());
+        assert_eq!(
+            "2024年9月17日 18:02:21",
+            almanac.get_moonrise().unwrap().to_string()
+        );
+        assert_eq!(
+            "2024年9月17日 04:26:55",
+            almanac.get_moonset().unwrap().to_string()
+        );
+
+        let polar: Almanac = Almanac::from_solar_day(SolarDay::from_ymd(2024, 6, 21))
+            .with_observer(Observer::from_degrees(25.0, 70.0));
+        assert!(polar.get_sunrise().is_none());
+        assert!(polar.get_sunset().is_none());
+    }
+}
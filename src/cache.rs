@@ -1,7 +1,152 @@
 #![allow(dead_code)]
 
+#[cfg(not(feature = "thread-safe"))]
+use core::cell::Cell;
+#[cfg(all(not(feature = "thread-safe"), feature = "single-thread"))]
+use core::cell::RefCell;
+#[cfg(feature = "thread-safe")]
 use core::sync::atomic::{AtomicUsize, Ordering};
-use spin::Mutex;
+
+#[cfg(not(any(feature = "thread-safe", feature = "single-thread")))]
+compile_error!(
+    "sxtwl-rs: global state (caches, registries) needs the `thread-safe` or `single-thread` feature"
+);
+
+/// 全局状态（登记表、缓存）的锁：启用thread-safe时为spin::Mutex；仅启用single-thread时为critical-section互斥，
+/// 临界区由目标提供（如单核MCU关中断），不含原子操作。两者必须启用其一
+pub(crate) struct Lock<T> {
+    #[cfg(feature = "thread-safe")]
+    inner: spin::Mutex<T>,
+    #[cfg(all(not(feature = "thread-safe"), feature = "single-thread"))]
+    inner: critical_section::Mutex<RefCell<T>>,
+}
+
+impl<T> Lock<T> {
+    pub const fn new(data: T) -> Self {
+        Self {
+            #[cfg(feature = "thread-safe")]
+            inner: spin::Mutex::new(data),
+            #[cfg(all(not(feature = "thread-safe"), feature = "single-thread"))]
+            inner: critical_section::Mutex::new(RefCell::new(data)),
+        }
+    }
+
+    /// 持锁访问数据，f中不可再访问同一把锁
+    #[cfg(feature = "thread-safe")]
+    pub fn with<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+        f(&mut self.inner.lock())
+    }
+
+    /// 持锁访问数据，f中不可再访问同一把锁
+    #[cfg(all(not(feature = "thread-safe"), feature = "single-thread"))]
+    pub fn with<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+        critical_section::with(|cs| f(&mut self.inner.borrow_ref_mut(cs)))
+    }
+}
+
+/// 一次性初始化（外部星历表）：启用thread-safe时为spin::Once，否则以Lock保存初始化后泄漏的值
+#[cfg(all(feature = "external-ephemeris", feature = "thread-safe"))]
+pub(crate) use spin::Once;
+
+/// 一次性初始化（外部星历表）：启用thread-safe时为spin::Once，否则以Lock保存初始化后泄漏的值
+#[cfg(all(
+    feature = "external-ephemeris",
+    not(feature = "thread-safe"),
+    feature = "single-thread"
+))]
+pub(crate) struct Once<T: 'static> {
+    value: Lock<Option<&'static T>>,
+}
+
+#[cfg(all(
+    feature = "external-ephemeris",
+    not(feature = "thread-safe"),
+    feature = "single-thread"
+))]
+impl<T: 'static> Once<T> {
+    pub const fn new() -> Self {
+        Self {
+            value: Lock::new(None),
+        }
+    }
+
+    /// 未初始化时以f的结果初始化，返回已初始化的值；f在临界区外执行
+    pub fn call_once<F: FnOnce() -> T>(&self, f: F) -> &'static T {
+        if let Some(v) = self.get() {
+            return v;
+        }
+        let created: &'static T = alloc::boxed::Box::leak(alloc::boxed::Box::new(f()));
+        self.value.with(|v| *v.get_or_insert(created))
+    }
+
+    /// 已初始化的值
+    pub fn get(&self) -> Option<&'static T> {
+        self.value.with(|v| *v)
+    }
+}
+
+/// LRU访问计数：thread-safe下为原子计数，否则为普通计数（仅在Lock内使用）
+struct LruCounter {
+    #[cfg(feature = "thread-safe")]
+    count: AtomicUsize,
+    #[cfg(not(feature = "thread-safe"))]
+    count: Cell<usize>,
+}
+
+impl LruCounter {
+    const fn new() -> Self {
+        Self {
+            #[cfg(feature = "thread-safe")]
+            count: AtomicUsize::new(0),
+            #[cfg(not(feature = "thread-safe"))]
+            count: Cell::new(0),
+        }
+    }
+
+    #[cfg(feature = "thread-safe")]
+    fn increment(&self) {
+        self.count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    #[cfg(not(feature = "thread-safe"))]
+    fn increment(&self) {
+        self.count.set(self.count.get() + 1);
+    }
+
+    #[cfg(feature = "thread-safe")]
+    fn load(&self) -> usize {
+        self.count.load(Ordering::Relaxed)
+    }
+
+    #[cfg(not(feature = "thread-safe"))]
+    fn load(&self) -> usize {
+        self.count.get()
+    }
+
+    #[cfg(feature = "thread-safe")]
+    fn reset(&self) {
+        self.count.store(0, Ordering::Relaxed);
+    }
+
+    #[cfg(not(feature = "thread-safe"))]
+    fn reset(&self) {
+        self.count.set(0);
+    }
+}
+
+/// 内存屏障：未启用thread-safe时不需要
+#[inline]
+fn fence_acquire() {
+    #[cfg(feature = "thread-safe")]
+    core::sync::atomic::fence(Ordering::Acquire);
+}
+
+/// 内存屏障：未启用thread-safe时不需要
+#[inline]
+fn fence_release() {
+    #[cfg(feature = "thread-safe")]
+    core::sync::atomic::fence(Ordering::Release);
+}
 
 // 缓存条目
 #[derive(Clone, Copy)]
@@ -22,7 +167,7 @@ where
     V: Copy,
 {
     entries: [Option<CacheEntry<K, V, DATA_SIZE>>; CACHE_SIZE],
-    lru_counter: AtomicUsize,
+    lru_counter: LruCounter,
 }
 
 impl<K, V, const CACHE_SIZE: usize, const DATA_SIZE: usize> FixedCache<K, V, CACHE_SIZE, DATA_SIZE>
@@ -33,19 +178,19 @@ where
     pub const fn new() -> Self {
         Self {
             entries: [None; CACHE_SIZE],
-            lru_counter: AtomicUsize::new(0),
+            lru_counter: LruCounter::new(),
         }
     }
 
     pub fn get(&self, key: K) -> Option<[V; DATA_SIZE]> {
         // 使用原子操作确保内存可见性
-        core::sync::atomic::fence(Ordering::Acquire);
+        fence_acquire();
 
         for entry in &self.entries {
             if let Some(entry) = entry {
                 if entry.key == key {
-                    // 更新访问计数（原子操作）
-                    self.lru_counter.fetch_add(1, Ordering::Relaxed);
+                    // 更新访问计数
+                    self.lru_counter.increment();
                     return Some(entry.data);
                 }
             }
@@ -55,12 +200,12 @@ where
 
     pub fn insert(&mut self, key: K, data: [V; DATA_SIZE]) {
         // 使用原子操作确保内存可见性
-        core::sync::atomic::fence(Ordering::Release);
+        fence_release();
 
         let new_entry = CacheEntry {
             key,
             data,
-            access_count: self.lru_counter.load(Ordering::Relaxed),
+            access_count: self.lru_counter.load(),
         };
 
         // 查找空位或最旧的条目
@@ -89,7 +234,7 @@ where
 
     pub fn clear(&mut self) {
         self.entries = [None; CACHE_SIZE];
-        self.lru_counter.store(0, Ordering::Relaxed);
+        self.lru_counter.reset();
     }
 
     pub fn len(&self) -> usize {
@@ -107,7 +252,7 @@ where
     K: Copy + PartialEq,
     V: Copy,
 {
-    cache: Mutex<FixedCache<K, V, CACHE_SIZE, DATA_SIZE>>,
+    cache: Lock<FixedCache<K, V, CACHE_SIZE, DATA_SIZE>>,
 }
 
 impl<K, V, const CACHE_SIZE: usize, const DATA_SIZE: usize>
@@ -118,18 +263,16 @@ where
 {
     pub const fn new() -> Self {
         Self {
-            cache: Mutex::new(FixedCache::new()),
+            cache: Lock::new(FixedCache::new()),
         }
     }
 
     pub fn get(&self, key: K) -> Option<[V; DATA_SIZE]> {
-        let cache = self.cache.lock();
-        cache.get(key)
+        self.cache.with(|cache| cache.get(key))
     }
 
     pub fn insert(&self, key: K, data: [V; DATA_SIZE]) {
-        let mut cache = self.cache.lock();
-        cache.insert(key, data);
+        self.cache.with(|cache| cache.insert(key, data));
     }
 
     pub fn get_or_compute<F>(&self, key: K, compute_fn: F) -> [V; DATA_SIZE]
@@ -151,18 +294,15 @@ where
     }
 
     pub fn clear(&self) {
-        let mut cache = self.cache.lock();
-        cache.clear();
+        self.cache.with(|cache| cache.clear());
     }
 
     pub fn len(&self) -> usize {
-        let cache = self.cache.lock();
-        cache.len()
+        self.cache.with(|cache| cache.len())
     }
 
     pub fn is_empty(&self) -> bool {
-        let cache = self.cache.lock();
-        cache.is_empty()
+        self.len() == 0
     }
 }
 
@@ -178,3 +318,30 @@ macro_rules! create_cache {
         > = $crate::cache::ThreadSafeCache::new();
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::cache::Lock;
+    use crate::cache::ThreadSafeCache;
+
+    #[test]
+    fn test1() {
+        let cache: ThreadSafeCache<isize, f64, 2, 1> = ThreadSafeCache::new();
+        assert!(cache.is_empty());
+        assert_eq!([1.0], cache.get_or_compute(1, || [1.0]));
+        assert_eq!([1.0], cache.get_or_compute(1, || [9.0]));
+        cache.insert(2, [2.0]);
+        cache.insert(3, [3.0]);
+        assert_eq!(2, cache.len());
+        assert_eq!(Some([3.0]), cache.get(3));
+        cache.clear();
+        assert!(cache.get(3).is_none());
+    }
+
+    #[test]
+    fn test2() {
+        let m: Lock<usize> = Lock::new(1);
+        m.with(|v| *v += 1);
+        assert_eq!(2, m.with(|v| *v));
+    }
+}
@@ -0,0 +1,392 @@
+//! 年历：一次排出全年12个月的日期网格及农历、节气、节日标注，节气与农历月只推算一遍，供印刷年历、桌面日历使用
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::culture::Zodiac;
+#[cfg(feature = "festival")]
+use crate::festival::{LunarFestival, SolarFestival};
+#[cfg(feature = "holiday")]
+use crate::holiday::LegalHoliday;
+use crate::lunar::{LunarDay, LunarMonth, LunarYear};
+use crate::sixtycycle::SixtyCycle;
+use crate::solar::{SolarDay, SolarMonth, SolarTerm};
+use crate::types::{Culture, Tyme};
+
+/// 年历排版参数，默认每周自星期一起、网格中不显示邻月日期
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct YearCalendarOptions {
+    /// 每周起始星期（0日、1一、……、6六）
+    start: usize,
+    /// 网格首尾周是否填入上月、下月的日期
+    fill_adjacent: bool,
+}
+
+impl Default for YearCalendarOptions {
+    fn default() -> Self {
+        Self {
+            start: 1,
+            fill_adjacent: false,
+        }
+    }
+}
+
+impl YearCalendarOptions {
+    pub fn new(start: usize, fill_adjacent: bool) -> Result<Self, String> {
+        if start > 6 {
+            return Err(format!("illegal start: {}", start));
+        }
+        Ok(Self {
+            start,
+            fill_adjacent,
+        })
+    }
+
+    /// 每周起始星期（0日、1一、……、6六）
+    pub fn get_start(&self) -> usize {
+        self.start
+    }
+
+    /// 网格首尾周是否填入上月、下月的日期
+    pub fn is_fill_adjacent(&self) -> bool {
+        self.fill_adjacent
+    }
+}
+
+/// 年历中的一天
+#[derive(Debug, Clone)]
+pub struct CalendarDay {
+    /// 公历日
+    solar_day: SolarDay,
+    /// 农历日
+    lunar_day: LunarDay,
+    /// 当天交节的节气
+    term: Option<SolarTerm>,
+    /// 农历传统节日
+    #[cfg(feature = "festival")]
+    lunar_festival: Option<LunarFestival>,
+    /// 公历现代节日
+    #[cfg(feature = "festival")]
+    solar_festival: Option<SolarFestival>,
+    /// 法定假日
+    #[cfg(feature = "holiday")]
+    legal_holiday: Option<LegalHoliday>,
+}
+
+impl CalendarDay {
+    /// 公历日
+    pub fn get_solar_day(&self) -> SolarDay {
+        self.solar_day
+    }
+
+    /// 农历日
+    pub fn get_lunar_day(&self) -> LunarDay {
+        self.lunar_day.clone()
+    }
+
+    /// 当天交节的节气，当天不交节返回None
+    pub fn get_term(&self) -> Option<SolarTerm> {
+        self.term.clone()
+    }
+
+    /// 农历传统节日
+    #[cfg(feature = "festival")]
+    pub fn get_lunar_festival(&self) -> Option<LunarFestival> {
+        self.lunar_festival.clone()
+    }
+
+    /// 公历现代节日
+    #[cfg(feature = "festival")]
+    pub fn get_solar_festival(&self) -> Option<SolarFestival> {
+        self.solar_festival
+    }
+
+    /// 法定假日
+    #[cfg(feature = "holiday")]
+    pub fn get_legal_holiday(&self) -> Option<LegalHoliday> {
+        self.legal_holiday
+    }
+
+    /// 格内标注：依次取交节的节气、农历节日、公历节日，无则月首为农历月名、其余为农历日名
+    pub fn get_label(&self) -> String {
+        if let Some(term) = &self.term {
+            return term.get_name();
+        }
+        #[cfg(feature = "festival")]
+        {
+            if let Some(f) = &self.lunar_festival {
+                return f.get_name();
+            }
+            if let Some(f) = self.solar_festival {
+                return f.get_name();
+            }
+        }
+        if self.lunar_day.get_day() == 1 {
+            self.lunar_day.get_lunar_month().get_name()
+        } else {
+            self.lunar_day.get_name()
+        }
+    }
+}
+
+/// 年历中的一个月：按周排成网格，每周7格，不属于本月（且未填入邻月日期）的格为None
+#[derive(Debug, Clone)]
+pub struct CalendarMonth {
+    /// 公历月
+    month: SolarMonth,
+    /// 各周
+    weeks: Vec<Vec<Option<CalendarDay>>>,
+}
+
+impl CalendarMonth {
+    /// 公历月
+    pub fn get_solar_month(&self) -> SolarMonth {
+        self.month
+    }
+
+    /// 各周，每周7格
+    pub fn get_weeks(&self) -> Vec<Vec<Option<CalendarDay>>> {
+        self.weeks.clone()
+    }
+
+    /// 本月各日（不含邻月日期）
+    pub fn get_days(&self) -> Vec<CalendarDay> {
+        self.weeks
+            .iter()
+            .flatten()
+            .flatten()
+            .filter(|d| d.solar_day.get_solar_month() == self.month)
+            .cloned()
+            .collect()
+    }
+}
+
+/// 年历：公历一年的12个月网格，及当年干支、生肖、闰月、法定假日
+#[derive(Debug, Clone)]
+pub struct YearCalendar {
+    /// 公历年
+    year: isize,
+    /// 排版参数
+    options: YearCalendarOptions,
+    /// 12个月
+    months: Vec<CalendarMonth>,
+}
+
+impl YearCalendar {
+    /// 排出公历year年的年历（2—9998年，首尾周可能含邻年日期）
+    pub fn build(year: isize, options: YearCalendarOptions) -> Result<Self, String> {
+        if !(2..=9998).contains(&year) {
+            return Err(format!("illegal year calendar year: {}", year));
+        }
+        let start: usize = options.start;
+        let first: SolarDay = SolarMonth::from_ym(year, 1).get_weeks(start)[0].get_first_day();
+        let last: SolarDay = SolarMonth::from_ym(year, 12)
+            .get_weeks(start)
+            .last()
+            .unwrap()
+            .get_first_day()
+            .next(6);
+        let count: usize = (last.subtract(first) + 1) as usize;
+
+        // 农历日：逐月推算，不逐日换算
+        let mut lunar_days: Vec<LunarDay> = Vec::with_capacity(count);
+        let mut month: LunarMonth = first.get_lunar_day().get_lunar_month();
+        while lunar_days.len() < count {
+            for (lunar, solar) in month.get_days_with_solar() {
+                if !solar.is_before(first) && lunar_days.len() < count {
+                    lunar_days.push(lunar);
+                }
+            }
+            month = month.next(1);
+        }
+
+        // 交节日
+        let mut terms: Vec<(SolarDay, SolarTerm)> = Vec::new();
+        let mut term: SolarTerm = first.get_term();
+        let mut term_day: SolarDay = term.get_solar_day();
+        while !term_day.is_after(last) {
+            if !term_day.is_before(first) {
+                terms.push((term_day, term.clone()));
+            }
+            term = term.next(1);
+            term_day = term.get_solar_day();
+        }
+
+        let day_at = |solar_day: SolarDay| -> CalendarDay {
+            let lunar_day: LunarDay = lunar_days[solar_day.subtract(first) as usize].clone();
+            CalendarDay {
+                solar_day,
+                term: terms
+                    .iter()
+                    .find(|(d, _)| *d == solar_day)
+                    .map(|(_, t)| t.clone()),
+                #[cfg(feature = "festival")]
+                lunar_festival: lunar_day.get_festival(),
+                #[cfg(feature = "festival")]
+                solar_festival: solar_day.get_festival(),
+                #[cfg(feature = "holiday")]
+                legal_holiday: solar_day.get_legal_holiday(),
+                lunar_day,
+            }
+        };
+
+        let mut months: Vec<CalendarMonth> = Vec::with_capacity(12);
+        for m in 1..=12 {
+            let month: SolarMonth = SolarMonth::from_ym(year, m);
+            let weeks: Vec<Vec<Option<CalendarDay>>> = month
+                .get_weeks(start)
+                .iter()
+                .map(|week| {
+                    let d: SolarDay = week.get_first_day();
+                    (0..7)
+                        .map(|i| d.next(i))
+                        .map(|d| {
+                            if options.fill_adjacent || d.get_solar_month() == month {
+                                Some(day_at(d))
+                            } else {
+                                None
+                            }
+                        })
+                        .collect()
+                })
+                .collect();
+            months.push(CalendarMonth { month, weeks });
+        }
+        Ok(Self {
+            year,
+            options,
+            months,
+        })
+    }
+
+    /// 公历年
+    pub fn get_year(&self) -> isize {
+        self.year
+    }
+
+    /// 排版参数
+    pub fn get_options(&self) -> YearCalendarOptions {
+        self.options
+    }
+
+    /// 12个月
+    pub fn get_months(&self) -> Vec<CalendarMonth> {
+        self.months.clone()
+    }
+
+    /// 当年（以正月初一起算的同数字农历年）
+    pub fn get_lunar_year(&self) -> LunarYear {
+        LunarYear::from_year(self.year)
+    }
+
+    /// 农历年干支
+    pub fn get_sixty_cycle(&self) -> SixtyCycle {
+        self.get_lunar_year().get_sixty_cycle()
+    }
+
+    /// 生肖
+    pub fn get_zodiac(&self) -> Zodiac {
+        self.get_sixty_cycle().get_earth_branch().get_zodiac()
+    }
+
+    /// 农历年的闰月，无闰月为0
+    pub fn get_leap_month(&self) -> usize {
+        self.get_lunar_year().get_leap_month()
+    }
+
+    /// 当年交节的节气，按时间先后
+    pub fn get_terms(&self) -> Vec<SolarTerm> {
+        self.get_days().into_iter().filter_map(|d| d.term).collect()
+    }
+
+    /// 当年各日
+    pub fn get_days(&self) -> Vec<CalendarDay> {
+        self.months.iter().flat_map(|m| m.get_days()).collect()
+    }
+
+    /// 当年的法定假日（含调休上班日），按日期先后
+    #[cfg(feature = "holiday")]
+    pub fn get_legal_holidays(&self) -> Vec<LegalHoliday> {
+        self.get_days()
+            .into_iter()
+            .filter_map(|d| d.legal_holiday)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "holiday")]
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+
+    use crate::calendar::{CalendarDay, CalendarMonth, YearCalendar, YearCalendarOptions};
+    use crate::solar::SolarDay;
+    use crate::types::{Culture, Tyme};
+
+    #[test]
+    fn test1() {
+        let c: YearCalendar = YearCalendar::build(2023, YearCalendarOptions::default()).unwrap();
+        assert_eq!("癸卯", c.get_sixty_cycle().get_name());
+        assert_eq!("兔", c.get_zodiac().get_name());
+        assert_eq!(2, c.get_leap_month());
+        assert_eq!(365, c.get_days().len());
+        assert_eq!(24, c.get_terms().len());
+        assert_eq!("小寒", c.get_terms()[0].get_name());
+
+        let months: Vec<CalendarMonth> = c.get_months();
+        assert_eq!(12, months.len());
+        // 2023年1月1日为星期日，按星期一起排在首周末格
+        let first: Vec<Option<CalendarDay>> = months[0].get_weeks()[0].clone();
+        assert!(first[0..6].iter().all(|d| d.is_none()));
+        assert_eq!(
+            "初十",
+            first[6].as_ref().unwrap().get_lunar_day().get_name()
+        );
+        for (d, day) in c.get_days().iter().zip(0..) {
+            assert_eq!(SolarDay::from_ymd(2023, 1, 1).next(day), d.get_solar_day());
+            assert_eq!(d.get_solar_day().get_lunar_day(), d.get_lunar_day());
+        }
+        let day: CalendarDay = months[2].get_days()[21].clone();
+        assert_eq!("闰二月", day.get_label());
+        assert_eq!("春分", months[2].get_days()[20].get_label());
+
+        let c: YearCalendar =
+            YearCalendar::build(2023, YearCalendarOptions::new(0, true).unwrap()).unwrap();
+        let first: Vec<Option<CalendarDay>> = c.get_months()[0].get_weeks()[0].clone();
+        assert!(first.iter().all(|d| d.is_some()));
+        let last: Vec<Option<CalendarDay>> = c.get_months()[11].get_weeks().last().unwrap().clone();
+        assert_eq!(
+            SolarDay::from_ymd(2024, 1, 6),
+            last[6].as_ref().unwrap().get_solar_day()
+        );
+        assert!(YearCalendarOptions::new(7, false).is_err());
+        assert!(YearCalendar::build(9999, YearCalendarOptions::default()).is_err());
+    }
+
+    #[cfg(feature = "festival")]
+    #[test]
+    fn test2() {
+        let c: YearCalendar = YearCalendar::build(2024, YearCalendarOptions::default()).unwrap();
+        let days: Vec<CalendarDay> = c.get_days();
+        assert_eq!("春节", days[40].get_label());
+        assert_eq!("中秋节", days[260].get_label());
+        assert_eq!("国庆节", days[274].get_label());
+    }
+
+    #[cfg(feature = "holiday")]
+    #[test]
+    fn test3() {
+        let c: YearCalendar = YearCalendar::build(2024, YearCalendarOptions::default()).unwrap();
+        let l = c.get_legal_holidays();
+        assert_eq!("2024年1月1日 元旦节(休)", l[0].to_string());
+        assert_eq!(
+            l.len(),
+            c.get_days()
+                .iter()
+                .filter(|d| d.get_legal_holiday().is_some())
+                .count()
+        );
+    }
+}
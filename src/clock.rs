@@ -0,0 +1,90 @@
+//! 时钟：由调用方注入当前时刻（系统时间、RTC、测试用固定时刻），不依赖std
+
+use alloc::string::String;
+
+use crate::context::CalendarContext;
+use crate::solar::{SolarDay, SolarTime};
+use crate::types::Tyme;
+
+/// 时钟
+pub trait Clock {
+    /// 当前UTC时刻
+    fn now_utc(&self) -> SolarTime;
+}
+
+/// 固定时钟，总是返回同一UTC时刻，便于测试
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FixedClock {
+    /// UTC时刻
+    time: SolarTime,
+}
+
+impl FixedClock {
+    pub fn new(time: SolarTime) -> Self {
+        Self { time }
+    }
+}
+
+impl Clock for FixedClock {
+    fn now_utc(&self) -> SolarTime {
+        self.time
+    }
+}
+
+/// 指定UTC偏移（秒）的当前时刻
+pub fn now_in(utc_offset: isize, clock: &impl Clock) -> Result<SolarTime, String> {
+    Ok(now_with_context(
+        &CalendarContext::default().with_utc_offset(utc_offset)?,
+        clock,
+    ))
+}
+
+/// 指定UTC偏移（秒）的今天
+pub fn today_in(utc_offset: isize, clock: &impl Clock) -> Result<SolarDay, String> {
+    Ok(now_in(utc_offset, clock)?.get_solar_day())
+}
+
+/// 配置上下文时区的当前时刻
+pub fn now_with_context(context: &CalendarContext, clock: &impl Clock) -> SolarTime {
+    clock.now_utc().next(context.get_utc_offset())
+}
+
+/// 配置上下文时区的今天
+pub fn today_with_context(context: &CalendarContext, clock: &impl Clock) -> SolarDay {
+    now_with_context(context, clock).get_solar_day()
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::clock::{FixedClock, now_in, today_in, today_with_context};
+    use crate::context::CalendarContext;
+    use crate::solar::{SolarDay, SolarTime};
+
+    #[test]
+    fn test1() {
+        let clock: FixedClock = FixedClock::new(SolarTime::from_ymd_hms(2024, 2, 9, 16, 30, 0));
+        assert_eq!(
+            "2024年2月10日 00:30:00",
+            now_in(28800, &clock).unwrap().to_string()
+        );
+        assert_eq!(
+            SolarDay::from_ymd(2024, 2, 10),
+            today_in(28800, &clock).unwrap()
+        );
+        assert_eq!(
+            SolarDay::from_ymd(2024, 2, 9),
+            today_in(25200, &clock).unwrap()
+        );
+        assert_eq!(
+            SolarDay::from_ymd(2024, 2, 9),
+            today_in(-18000, &clock).unwrap()
+        );
+        assert_eq!(
+            SolarDay::from_ymd(2024, 2, 10),
+            today_with_context(&CalendarContext::default(), &clock)
+        );
+        assert!(today_in(60000, &clock).is_err());
+    }
+}
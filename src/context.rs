@@ -0,0 +1,260 @@
+use alloc::format;
+use alloc::string::String;
+
+use crate::enums::{Locale, Precision, SpringSwitch, YearBoundary, ZiShiPolicy};
+use crate::event::EventPriority;
+use crate::solar::SolarTime;
+use crate::types::Tyme;
+
+/// 北京时间的UTC偏移（秒）
+pub static BEIJING_UTC_OFFSET: isize = 28800;
+
+/// 默认晚子时日柱取法，与全局八字服务的提供器一致（启用eight-char-lunar-sect2-provider时算当天）
+#[cfg(not(feature = "eight-char-lunar-sect2-provider"))]
+const DEFAULT_ZI_SHI: ZiShiPolicy = ZiShiPolicy::NEXT;
+
+#[cfg(feature = "eight-char-lunar-sect2-provider")]
+const DEFAULT_ZI_SHI: ZiShiPolicy = ZiShiPolicy::CURRENT;
+
+/// 默认八字年柱分界（启用eight-char-lunar-new-year-boundary时以正月初一为界）
+#[cfg(not(feature = "eight-char-lunar-new-year-boundary"))]
+pub(crate) const DEFAULT_YEAR_BOUNDARY: YearBoundary = YearBoundary::SPRING;
+
+#[cfg(feature = "eight-char-lunar-new-year-boundary")]
+pub(crate) const DEFAULT_YEAR_BOUNDARY: YearBoundary = YearBoundary::LUNAR;
+
+/// 日历配置上下文，汇总时区、晚子时、年柱分界、立春当天换年、精度、语言等选项，按值传递
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct CalendarContext {
+    /// UTC偏移（秒），默认北京时间
+    utc_offset: isize,
+    /// 晚子时日柱取法
+    zi_shi: ZiShiPolicy,
+    /// 八字年柱分界
+    year_boundary: YearBoundary,
+    /// 立春当天交节前的年柱、月柱取法
+    spring_switch: SpringSwitch,
+    /// 节气、朔望计算精度
+    precision: Precision,
+    /// 名称语言
+    locale: Locale,
+    /// 同一天多个事件的排序策略
+    event_priority: EventPriority,
+}
+
+impl Default for CalendarContext {
+    fn default() -> Self {
+        Self {
+            utc_offset: BEIJING_UTC_OFFSET,
+            zi_shi: DEFAULT_ZI_SHI,
+            year_boundary: DEFAULT_YEAR_BOUNDARY,
+            spring_switch: SpringSwitch::INSTANT,
+            precision: Precision::HIGH,
+            locale: Locale::ZH,
+            event_priority: EventPriority::default(),
+        }
+    }
+}
+
+impl CalendarContext {
+    pub fn new(
+        utc_offset: isize,
+        zi_shi: ZiShiPolicy,
+        year_boundary: YearBoundary,
+        precision: Precision,
+        locale: Locale,
+    ) -> Result<Self, String> {
+        if !(-50400..=50400).contains(&utc_offset) {
+            return Err(format!("illegal utc offset: {}", utc_offset));
+        }
+        Ok(Self {
+            utc_offset,
+            zi_shi,
+            year_boundary,
+            spring_switch: SpringSwitch::INSTANT,
+            precision,
+            locale,
+            event_priority: EventPriority::default(),
+        })
+    }
+
+    /// UTC偏移（秒）
+    pub fn get_utc_offset(&self) -> isize {
+        self.utc_offset
+    }
+
+    /// 晚子时日柱取法
+    pub fn get_zi_shi(&self) -> ZiShiPolicy {
+        self.zi_shi
+    }
+
+    /// 八字年柱分界
+    pub fn get_year_boundary(&self) -> YearBoundary {
+        self.year_boundary
+    }
+
+    /// 立春当天交节前的年柱、月柱取法
+    pub fn get_spring_switch(&self) -> SpringSwitch {
+        self.spring_switch
+    }
+
+    /// 节气、朔望计算精度
+    pub fn get_precision(&self) -> Precision {
+        self.precision
+    }
+
+    /// 名称语言
+    pub fn get_locale(&self) -> Locale {
+        self.locale
+    }
+
+    /// 同一天多个事件的排序策略
+    pub fn get_event_priority(&self) -> EventPriority {
+        self.event_priority
+    }
+
+    /// 使用指定UTC偏移（秒）
+    pub fn with_utc_offset(&self, utc_offset: isize) -> Result<Self, String> {
+        Ok(Self {
+            spring_switch: self.spring_switch,
+            event_priority: self.event_priority,
+            ..Self::new(
+                utc_offset,
+                self.zi_shi,
+                self.year_boundary,
+                self.precision,
+                self.locale,
+            )?
+        })
+    }
+
+    /// 使用指定晚子时日柱取法
+    pub fn with_zi_shi(&self, zi_shi: ZiShiPolicy) -> Self {
+        Self { zi_shi, ..*self }
+    }
+
+    /// 使用指定八字年柱分界
+    pub fn with_year_boundary(&self, year_boundary: YearBoundary) -> Self {
+        Self {
+            year_boundary,
+            ..*self
+        }
+    }
+
+    /// 使用指定立春当天换年取法（年柱分界为立春时有效）
+    pub fn with_spring_switch(&self, spring_switch: SpringSwitch) -> Self {
+        Self {
+            spring_switch,
+            ..*self
+        }
+    }
+
+    /// 使用指定计算精度
+    pub fn with_precision(&self, precision: Precision) -> Self {
+        Self { precision, ..*self }
+    }
+
+    /// 使用指定名称语言
+    pub fn with_locale(&self, locale: Locale) -> Self {
+        Self { locale, ..*self }
+    }
+
+    /// 使用指定事件排序策略
+    pub fn with_event_priority(&self, event_priority: EventPriority) -> Self {
+        Self {
+            event_priority,
+            ..*self
+        }
+    }
+
+    /// 八字相关的取法（晚子时、年柱分界、立春当天换年）是否与另一上下文相同
+    #[cfg(feature = "eight-char")]
+    pub(crate) fn is_eight_char_equivalent(&self, other: &Self) -> bool {
+        self.zi_shi == other.zi_shi
+            && self.year_boundary == other.year_boundary
+            && self.spring_switch == other.spring_switch
+    }
+
+    /// 北京时间换算为本上下文的当地时间
+    pub fn to_local(&self, time: SolarTime) -> SolarTime {
+        time.next(self.utc_offset - BEIJING_UTC_OFFSET)
+    }
+
+    /// 本上下文的当地时间换算为北京时间
+    pub fn to_beijing(&self, time: SolarTime) -> SolarTime {
+        time.next(BEIJING_UTC_OFFSET - self.utc_offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::context::CalendarContext;
+    use crate::enums::{Locale, Precision, SpringSwitch, YearBoundary, ZiShiPolicy};
+    use crate::solar::{SolarTerm, SolarTime};
+    use crate::sxtwl::ephemeris::assert_time;
+
+    #[test]
+    fn test1() {
+        let context: CalendarContext = CalendarContext::default();
+        assert_eq!(28800, context.get_utc_offset());
+        #[cfg(not(feature = "eight-char-lunar-sect2-provider"))]
+        assert_eq!(ZiShiPolicy::NEXT, context.get_zi_shi());
+        #[cfg(feature = "eight-char-lunar-sect2-provider")]
+        assert_eq!(ZiShiPolicy::CURRENT, context.get_zi_shi());
+        #[cfg(not(feature = "eight-char-lunar-new-year-boundary"))]
+        assert_eq!(YearBoundary::SPRING, context.get_year_boundary());
+        #[cfg(feature = "eight-char-lunar-new-year-boundary")]
+        assert_eq!(YearBoundary::LUNAR, context.get_year_boundary());
+        assert_eq!(Precision::HIGH, context.get_precision());
+        assert_eq!(Locale::ZH, context.get_locale());
+        assert_eq!(SpringSwitch::INSTANT, context.get_spring_switch());
+        let day: CalendarContext = context.with_spring_switch(SpringSwitch::DAY);
+        assert_eq!(
+            SpringSwitch::DAY,
+            day.with_utc_offset(25200).unwrap().get_spring_switch()
+        );
+
+        let en: CalendarContext = context.with_locale(Locale::EN);
+        assert_eq!(Locale::ZH, context.get_locale());
+        assert_ne!(context, en);
+        assert!(context.with_utc_offset(60000).is_err());
+    }
+
+    #[test]
+    fn test2() {
+        let context: CalendarContext = CalendarContext::default().with_utc_offset(25200).unwrap();
+        let time: SolarTime = SolarTime::from_ymd_hms(2024, 1, 1, 0, 30, 0);
+        assert_eq!(
+            "2023年12月31日 23:30:00",
+            context.to_local(time).to_string()
+        );
+        assert_eq!(time, context.to_beijing(context.to_local(time)));
+    }
+
+    #[test]
+    fn test3() {
+        let term: SolarTerm = SolarTerm::from_name(2024, "立春");
+        let context: CalendarContext = CalendarContext::default();
+        assert_eq!("立春", term.get_name_with_context(&context));
+        assert_eq!(
+            "Start of Spring",
+            term.get_name_with_context(&context.with_locale(Locale::EN))
+        );
+        // 默认精度与get_julian_day一致
+        assert_eq!(
+            term.get_julian_day().get_solar_time(),
+            term.get_solar_time_with_context(&context)
+        );
+        assert_eq!(
+            term.get_julian_day_with_precision(Precision::STANDARD)
+                .get_solar_time(),
+            term.get_solar_time_with_context(&context.with_precision(Precision::STANDARD))
+        );
+        assert_time(
+            SolarTime::from_ymd_hms(2024, 2, 4, 15, 27, 7),
+            term.get_solar_time_with_context(&context.with_utc_offset(25200).unwrap()),
+        );
+    }
+}
@@ -0,0 +1,207 @@
+use core::fmt::{Display, Formatter};
+
+use alloc::string::{String, ToString};
+
+use crate::jd::{J2000, JulianDay};
+use crate::solar::{SolarDay, SolarTerm};
+use crate::sxtwl::Sxtwl;
+use crate::sxtwl::units::{Degrees, Radians};
+use crate::types::{AbstractCulture, AbstractCultureDay, AbstractTyme, Culture, LoopTyme, Tyme};
+
+pub static EARTH_KING_NAMES: [&str; 4] = ["冬土王用事", "春土王用事", "夏土王用事", "秋土王用事"];
+
+/// 土王用事（四立前太阳视黄经差18度起，至四立止）
+#[derive(Debug, Clone)]
+pub struct EarthKing {
+    parent: LoopTyme,
+    /// 年
+    year: isize,
+}
+
+impl Tyme for EarthKing {
+    fn next(&self, n: isize) -> Self {
+        let i: isize = self.get_index() as isize + n;
+        Self::from_index(
+            self.year + i.div_euclid(4),
+            self.parent.index_of_index(i) as isize,
+        )
+    }
+}
+
+impl Culture for EarthKing {
+    fn get_name(&self) -> String {
+        self.parent.get_name()
+    }
+}
+
+impl EarthKing {
+    /// 公历年中的第index个土王用事，0-3分别止于立春、立夏、立秋、立冬
+    pub fn from_index(year: isize, index: isize) -> Self {
+        Self {
+            parent: LoopTyme::from_index(
+                EARTH_KING_NAMES
+                    .to_vec()
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect(),
+                index,
+            ),
+            year: year + index.div_euclid(4),
+        }
+    }
+
+    pub fn get_index(&self) -> usize {
+        self.parent.get_index()
+    }
+
+    pub fn get_size(&self) -> usize {
+        self.parent.get_size()
+    }
+
+    /// 年
+    pub fn get_year(&self) -> isize {
+        self.year
+    }
+
+    /// 结束的四立节气
+    pub fn get_solar_term(&self) -> SolarTerm {
+        SolarTerm::from_index(self.year, 3 + self.get_index() as isize * 6)
+    }
+
+    /// 起始儒略日（精确到秒）
+    pub fn get_julian_day(&self) -> JulianDay {
+        let w: f64 = Sxtwl::qi_lon(self.get_solar_term().get_cursory_julian_day());
+        let offset: Radians = Degrees(18.0).into();
+        JulianDay::from_julian_day(Sxtwl::sun_longitude_time(Radians(w - offset.0)).0 + J2000)
+    }
+
+    /// 起始公历日
+    pub fn get_solar_day(&self) -> SolarDay {
+        self.get_julian_day().get_solar_day()
+    }
+
+    /// 结束公历日（四立当天，不含）
+    pub fn get_end_solar_day(&self) -> SolarDay {
+        self.get_solar_term().get_julian_day().get_solar_day()
+    }
+}
+
+impl Display for EarthKing {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.get_name())
+    }
+}
+
+impl PartialEq for EarthKing {
+    fn eq(&self, other: &Self) -> bool {
+        self.year == other.year && self.get_index() == other.get_index()
+    }
+}
+
+impl Eq for EarthKing {}
+
+impl Into<LoopTyme> for EarthKing {
+    fn into(self) -> LoopTyme {
+        self.parent
+    }
+}
+
+/// 土王用事天
+#[derive(Debug, Clone)]
+pub struct EarthKingDay {
+    parent: AbstractCultureDay,
+    earth_king: EarthKing,
+}
+
+impl Culture for EarthKingDay {
+    fn get_name(&self) -> String {
+        self.earth_king.get_name()
+    }
+}
+
+impl EarthKingDay {
+    pub fn new(earth_king: EarthKing, day_index: usize) -> Self {
+        let loop_tyme: LoopTyme = earth_king.clone().into();
+        let abstract_tyme: AbstractTyme = loop_tyme.into();
+        let culture: AbstractCulture = abstract_tyme.into();
+        Self {
+            parent: AbstractCultureDay::new(culture, day_index),
+            earth_king,
+        }
+    }
+
+    pub fn get_earth_king(&self) -> EarthKing {
+        self.earth_king.clone()
+    }
+
+    pub fn get_day_index(&self) -> usize {
+        self.parent.get_day_index()
+    }
+}
+
+impl Display for EarthKingDay {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{}第{}天",
+            self.get_name(),
+            self.parent.get_day_index() + 1
+        )
+    }
+}
+
+impl PartialEq for EarthKingDay {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
+impl Eq for EarthKingDay {}
+
+impl Into<AbstractCultureDay> for EarthKingDay {
+    fn into(self) -> AbstractCultureDay {
+        self.parent
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::culture::earthking::{EarthKing, EarthKingDay};
+    use crate::solar::{SolarDay, SolarYear};
+    use crate::types::{Culture, Tyme};
+
+    #[test]
+    fn test1() {
+        let l: alloc::vec::Vec<EarthKing> = SolarYear::from_year(2024).get_earth_kings();
+        assert_eq!(4, l.len());
+        assert_eq!("冬土王用事", l[0].get_name());
+        assert_eq!("立春", l[0].get_solar_term().get_name());
+        assert_eq!("2024年1月17日", l[0].get_solar_day().to_string());
+        assert_eq!("2024年4月16日", l[1].get_solar_day().to_string());
+        assert_eq!("2024年7月19日", l[2].get_solar_day().to_string());
+        assert_eq!("2024年10月20日", l[3].get_solar_day().to_string());
+    }
+
+    #[test]
+    fn test2() {
+        let k: EarthKing = EarthKing::from_index(2024, 3);
+        assert_eq!(EarthKing::from_index(2025, 0), k.next(1));
+        assert_eq!(EarthKing::from_index(2023, 3), k.next(-4));
+        assert_eq!(2025, k.next(1).get_year());
+    }
+
+    #[test]
+    fn test3() {
+        assert_eq!(
+            true,
+            SolarDay::from_ymd(2024, 2, 4)
+                .get_earth_king_day()
+                .is_none()
+        );
+        let d: EarthKingDay = SolarDay::from_ymd(2024, 2, 3).get_earth_king_day().unwrap();
+        assert_eq!("冬土王用事", d.get_name());
+        assert_eq!("冬土王用事第18天", d.to_string());
+    }
+}
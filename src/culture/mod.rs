@@ -1,5 +1,7 @@
 #[cfg(feature = "dog")]
 pub mod dog;
+#[cfg(feature = "earthking")]
+pub mod earthking;
 #[cfg(feature = "fetus")]
 pub mod fetus;
 #[cfg(feature = "nine")]
@@ -12,6 +14,8 @@ pub mod phenology;
 pub mod plumrain;
 #[cfg(feature = "miniren")]
 pub mod ren;
+#[cfg(feature = "springox")]
+pub mod springox;
 pub mod star;
 
 use core::fmt::{Display, Formatter};
@@ -21,15 +25,19 @@ use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use libm::floor;
 
+use crate::enums::{Precision, SeasonScheme, YearBoundary};
 use crate::jd::{J2000, JulianDay};
 use crate::lunar::{LunarDay, LunarMonth};
-use crate::sixtycycle::SixtyCycle;
-use crate::solar::{SolarDay, SolarTime};
+use crate::sixtycycle::{EarthBranch, SixtyCycle};
+use crate::solar::{SolarDay, SolarTerm, SolarTime};
+use crate::sxtwl::units::{Days, Radians};
 use crate::sxtwl::{ONE_THIRD, PI_2, Sxtwl};
 use crate::types::{AbstractCulture, AbstractCultureDay, AbstractTyme, Culture, LoopTyme, Tyme};
 
 #[cfg(feature = "god")]
-mod generated_day_god_data;
+pub(crate) mod generated_day_god_data {
+    include!(concat!(env!("OUT_DIR"), "/generated_day_god_data.rs"));
+}
 #[cfg(feature = "god")]
 use crate::culture::generated_day_god_data::DAY_GODS_TABLE;
 
@@ -184,6 +192,21 @@ pub static CONSTELLATION_NAMES: [&str; 12] = [
     "白羊", "金牛", "双子", "巨蟹", "狮子", "处女", "天秤", "天蝎", "射手", "摩羯", "水瓶", "双鱼",
 ];
 
+pub static CONSTELLATION_NAMES_EN: [&str; 12] = [
+    "Aries",
+    "Taurus",
+    "Gemini",
+    "Cancer",
+    "Leo",
+    "Virgo",
+    "Libra",
+    "Scorpio",
+    "Sagittarius",
+    "Capricorn",
+    "Aquarius",
+    "Pisces",
+];
+
 /// 星座
 #[derive(Debug, Clone)]
 pub struct Constellation {
@@ -229,6 +252,11 @@ impl Constellation {
         }
     }
 
+    /// 英文名称
+    pub fn name_en(&self) -> String {
+        CONSTELLATION_NAMES_EN[self.get_index()].to_string()
+    }
+
     pub fn get_index(&self) -> usize {
         self.parent.get_index()
     }
@@ -412,6 +440,8 @@ impl Into<LoopTyme> for Duty {
 
 pub static ELEMENT_NAMES: [&str; 5] = ["木", "火", "土", "金", "水"];
 
+pub static ELEMENT_NAMES_EN: [&str; 5] = ["Wood", "Fire", "Earth", "Metal", "Water"];
+
 /// 五行
 #[derive(Debug, Clone)]
 pub struct Element {
@@ -457,6 +487,11 @@ impl Element {
         }
     }
 
+    /// 英文名称
+    pub fn name_en(&self) -> String {
+        ELEMENT_NAMES_EN[self.get_index()].to_string()
+    }
+
     pub fn get_index(&self) -> usize {
         self.parent.get_index()
     }
@@ -752,6 +787,106 @@ impl Into<LoopTyme> for Luck {
     }
 }
 
+pub static MONTH_GENERAL_NAMES: [&str; 12] = [
+    "神后", "大吉", "功曹", "太冲", "天罡", "太乙", "胜光", "小吉", "传送", "从魁", "河魁", "登明",
+];
+
+/// 月将（太阳所在宫次，每过一个中气换将，冬至后为丑将大吉，逆行十二支）
+#[derive(Debug, Clone)]
+pub struct MonthGeneral {
+    parent: LoopTyme,
+}
+
+impl Tyme for MonthGeneral {
+    fn next(&self, n: isize) -> Self {
+        Self::from_index(self.parent.next_index(n) as isize)
+    }
+}
+
+impl Culture for MonthGeneral {
+    fn get_name(&self) -> String {
+        self.parent.get_name()
+    }
+}
+
+impl MonthGeneral {
+    pub fn from_index(index: isize) -> Self {
+        Self {
+            parent: LoopTyme::from_index(
+                MONTH_GENERAL_NAMES
+                    .to_vec()
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect(),
+                index,
+            ),
+        }
+    }
+
+    pub fn from_name(name: &str) -> Self {
+        Self {
+            parent: LoopTyme::from_name(
+                MONTH_GENERAL_NAMES
+                    .to_vec()
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect(),
+                name,
+            ),
+        }
+    }
+
+    /// 公历时刻的月将，按太阳视黄经计算
+    pub fn from_solar_time(time: SolarTime) -> Self {
+        let t: f64 = time.get_julian_day().get_day() - J2000 - ONE_THIRD;
+        let lon: Radians = Sxtwl::sun_apparent_longitude(Days(t + Sxtwl::dtt(t)));
+        // 自冬至（270度）起经过的中气数
+        let k: f64 = floor((lon.0 - PI_2 * 0.75) / (PI_2 / 12.0));
+        Self::from_index(1 - k as isize)
+    }
+
+    pub fn get_index(&self) -> usize {
+        self.parent.get_index()
+    }
+
+    pub fn get_size(&self) -> usize {
+        self.parent.get_size()
+    }
+
+    /// 地支
+    pub fn get_earth_branch(&self) -> EarthBranch {
+        EarthBranch::from_index(self.get_index() as isize)
+    }
+
+    /// 月将加时：以月将加临时支，返回地盘子至亥对应的天盘地支
+    pub fn get_heaven_plate(&self, hour: EarthBranch) -> Vec<EarthBranch> {
+        let offset: isize = self.get_index() as isize - hour.get_index() as isize;
+        (0..12)
+            .map(|i| EarthBranch::from_index(i + offset))
+            .collect()
+    }
+}
+
+impl Display for MonthGeneral {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.get_name())
+    }
+}
+
+impl PartialEq for MonthGeneral {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
+impl Eq for MonthGeneral {}
+
+impl Into<LoopTyme> for MonthGeneral {
+    fn into(self) -> LoopTyme {
+        self.parent
+    }
+}
+
 #[rustfmt::skip]
 pub static PHASE_NAMES: [&str; 8] = [ "新月", "蛾眉月", "上弦月", "盈凸月", "满月", "亏凸月", "下弦月", "残月",];
 
@@ -822,14 +957,13 @@ impl Phase {
         self.parent.get_size()
     }
 
-    fn get_start_solar_time(&self) -> SolarTime {
+    fn get_start_solar_time(&self, precision: Precision) -> SolarTime {
         let n: isize = floor((self.lunar_year - 2000) as f64 * 365.2422 / 29.53058886) as isize;
         let mut i: isize = 0;
-        let jd: f64 = J2000 + ONE_THIRD;
         let d: SolarDay = LunarDay::from_ymd(self.lunar_year, self.lunar_month, 1).get_solar_day();
         loop {
-            let t: f64 = Sxtwl::m_sa_lon_t((n + i) as f64 * PI_2) * 36525.0;
-            if !JulianDay::from_julian_day(jd + t - Sxtwl::dtt(t))
+            let t: f64 = Sxtwl::shuo_with_precision((n + i) as f64 * PI_2, precision);
+            if !JulianDay::from_julian_day(J2000 + t)
                 .get_solar_day()
                 .is_before(d)
             {
@@ -837,14 +971,20 @@ impl Phase {
             }
             i += 1;
         }
-        let t: f64 = Sxtwl::m_sa_lon_t(
+        let t: f64 = Sxtwl::shuo_with_precision(
             (n as f64 + i as f64 + [0, 90, 180, 270][self.get_index() / 2] as f64 / 360.0) * PI_2,
-        ) * 36525.0;
-        JulianDay::from_julian_day(jd + t - Sxtwl::dtt(t)).get_solar_time()
+            precision,
+        );
+        JulianDay::from_julian_day(J2000 + t).get_solar_time()
     }
 
     pub fn get_solar_time(&self) -> SolarTime {
-        let t: SolarTime = self.get_start_solar_time();
+        self.get_solar_time_with_precision(Precision::HIGH)
+    }
+
+    /// 按指定精度计算的时刻
+    pub fn get_solar_time_with_precision(&self, precision: Precision) -> SolarTime {
+        let t: SolarTime = self.get_start_solar_time(precision);
         if self.get_index() % 2 == 1 {
             t.next(1)
         } else {
@@ -852,8 +992,36 @@ impl Phase {
         }
     }
 
+    /// 月相时刻的力学时（J2000起算）
+    fn get_dynamical_days(&self) -> Days {
+        let jd: f64 = self.get_solar_time().get_julian_day().get_day() - J2000 - ONE_THIRD;
+        Days(jd + Sxtwl::dtt(jd))
+    }
+
+    /// 月相时刻的月亮地心黄纬（度）
+    pub fn get_moon_latitude(&self) -> f64 {
+        Sxtwl::moon_latitude(self.get_dynamical_days())
+            .0
+            .to_degrees()
+    }
+
+    /// 月相时刻的太阳地心黄纬（度）
+    pub fn get_sun_latitude(&self) -> f64 {
+        Sxtwl::sun_latitude(self.get_dynamical_days())
+            .0
+            .to_degrees()
+    }
+
+    /// 是否可能发生日食（新月）或月食（满月），其他月相总为false
+    pub fn is_eclipse_possible(&self) -> bool {
+        match self.get_index() {
+            0 | 4 => Sxtwl::is_eclipse_possible(self.get_dynamical_days()),
+            _ => false,
+        }
+    }
+
     pub fn get_solar_day(&self) -> SolarDay {
-        let d: SolarDay = self.get_start_solar_time().get_solar_day();
+        let d: SolarDay = self.get_start_solar_time(Precision::HIGH).get_solar_day();
         if self.get_index() % 2 == 1 {
             d.next(1)
         } else {
@@ -1055,6 +1223,11 @@ impl Sound {
     pub fn get_size(&self) -> usize {
         self.parent.get_size()
     }
+
+    /// 五行
+    pub fn get_element(&self) -> Element {
+        Element::from_name(self.get_name().chars().last().unwrap().to_string().as_str())
+    }
 }
 
 impl Display for Sound {
@@ -1457,6 +1630,16 @@ impl Into<LoopTyme> for Twenty {
 
 pub static WEEK_NAMES: [&str; 7] = ["日", "一", "二", "三", "四", "五", "六"];
 
+pub static WEEK_NAMES_EN: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+
 /// 星期
 #[derive(Debug, Clone)]
 pub struct Week {
@@ -1494,6 +1677,11 @@ impl Week {
         }
     }
 
+    /// 英文名称
+    pub fn name_en(&self) -> String {
+        WEEK_NAMES_EN[self.get_index()].to_string()
+    }
+
     pub fn get_index(&self) -> usize {
         self.parent.get_index()
     }
@@ -1527,6 +1715,11 @@ pub static ZODIAC_NAMES: [&str; 12] = [
     "鼠", "牛", "虎", "兔", "龙", "蛇", "马", "羊", "猴", "鸡", "狗", "猪",
 ];
 
+pub static ZODIAC_NAMES_EN: [&str; 12] = [
+    "Rat", "Ox", "Tiger", "Rabbit", "Dragon", "Snake", "Horse", "Goat", "Monkey", "Rooster", "Dog",
+    "Pig",
+];
+
 /// 生肖
 #[derive(Debug, Clone)]
 pub struct Zodiac {
@@ -1572,6 +1765,24 @@ impl Zodiac {
         }
     }
 
+    /// 英文名称
+    pub fn name_en(&self) -> String {
+        ZODIAC_NAMES_EN[self.get_index()].to_string()
+    }
+
+    /// 公历日所属的生肖，按年界取立春（当日起）或正月初一
+    pub fn of_solar_day(day: SolarDay, boundary: YearBoundary) -> Self {
+        let year: SixtyCycle = match boundary {
+            YearBoundary::SPRING => day.get_sixty_cycle_day().get_year(),
+            YearBoundary::LUNAR => day
+                .get_lunar_day()
+                .get_lunar_month()
+                .get_lunar_year()
+                .get_sixty_cycle(),
+        };
+        year.get_earth_branch().get_zodiac()
+    }
+
     pub fn get_index(&self) -> usize {
         self.parent.get_index()
     }
@@ -1601,6 +1812,176 @@ impl Into<LoopTyme> for Zodiac {
     }
 }
 
+pub static SEASON_NAMES: [&str; 4] = ["春", "夏", "秋", "冬"];
+
+/// 四季
+#[derive(Debug, Clone)]
+pub struct Season {
+    parent: LoopTyme,
+}
+
+impl Tyme for Season {
+    fn next(&self, n: isize) -> Self {
+        Self::from_index(self.parent.next_index(n) as isize)
+    }
+}
+
+impl Culture for Season {
+    fn get_name(&self) -> String {
+        self.parent.get_name()
+    }
+}
+
+impl Season {
+    pub fn from_index(index: isize) -> Self {
+        Self {
+            parent: LoopTyme::from_index(
+                SEASON_NAMES
+                    .to_vec()
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect(),
+                index,
+            ),
+        }
+    }
+
+    pub fn from_name(name: &str) -> Self {
+        Self {
+            parent: LoopTyme::from_name(
+                SEASON_NAMES
+                    .to_vec()
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect(),
+                name,
+            ),
+        }
+    }
+
+    /// 公历日按指定划分方法所属的季节及季内第几天
+    pub fn of(day: SolarDay, scheme: SeasonScheme) -> SeasonDay {
+        let (index, start): (isize, SolarDay) = match scheme {
+            SeasonScheme::METEOROLOGICAL => {
+                let month: isize = day.get_month() as isize;
+                let index: isize = (month + 9) % 12 / 3;
+                let start_month: isize = index * 3 + 3;
+                let start_year: isize = if start_month > month {
+                    day.get_year() - 1
+                } else {
+                    day.get_year()
+                };
+                (
+                    index,
+                    SolarDay::from_ymd(start_year, (start_month - 1) as usize % 12 + 1, 1),
+                )
+            }
+            _ => {
+                // 起季节气的索引：立春3，春分6
+                let first: isize = if scheme == SeasonScheme::TERM { 3 } else { 6 };
+                let term: SolarTerm = day.get_term();
+                let offset: isize = (term.get_index() as isize - first + 24) % 24;
+                (offset / 6, term.next(-(offset % 6)).get_solar_day())
+            }
+        };
+        SeasonDay::new(Self::from_index(index), start, day.subtract(start) as usize)
+    }
+
+    pub fn get_index(&self) -> usize {
+        self.parent.get_index()
+    }
+
+    pub fn get_size(&self) -> usize {
+        self.parent.get_size()
+    }
+}
+
+impl Display for Season {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.get_name())
+    }
+}
+
+impl PartialEq for Season {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
+impl Eq for Season {}
+
+impl Into<LoopTyme> for Season {
+    fn into(self) -> LoopTyme {
+        self.parent
+    }
+}
+
+/// 季节第几天
+#[derive(Debug, Clone)]
+pub struct SeasonDay {
+    parent: AbstractCultureDay,
+    season: Season,
+    /// 起季日
+    start: SolarDay,
+}
+
+impl Culture for SeasonDay {
+    fn get_name(&self) -> String {
+        self.season.get_name()
+    }
+}
+
+impl SeasonDay {
+    pub fn new(season: Season, start: SolarDay, day_index: usize) -> Self {
+        let loop_tyme: LoopTyme = season.clone().into();
+        let abstract_tyme: AbstractTyme = loop_tyme.into();
+        let culture: AbstractCulture = abstract_tyme.into();
+        Self {
+            parent: AbstractCultureDay::new(culture, day_index),
+            season,
+            start,
+        }
+    }
+
+    pub fn get_season(&self) -> Season {
+        self.season.clone()
+    }
+
+    /// 起季日
+    pub fn get_start_solar_day(&self) -> SolarDay {
+        self.start
+    }
+
+    pub fn get_day_index(&self) -> usize {
+        self.parent.get_day_index()
+    }
+}
+
+impl Display for SeasonDay {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{}第{}天",
+            self.get_name(),
+            self.parent.get_day_index() + 1
+        )
+    }
+}
+
+impl PartialEq for SeasonDay {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
+impl Eq for SeasonDay {}
+
+impl Into<AbstractCultureDay> for SeasonDay {
+    fn into(self) -> AbstractCultureDay {
+        self.parent
+    }
+}
+
 pub static ZONE_NAMES: [&str; 4] = ["东", "北", "西", "南"];
 
 /// 宫
@@ -1786,6 +2167,8 @@ impl Eq for KitchenGodSteed {}
 
 #[cfg(test)]
 mod tests {
+    #[cfg(feature = "god")]
+    use alloc::format;
     use alloc::string::String;
     use alloc::vec;
     use alloc::vec::Vec;
@@ -1793,8 +2176,10 @@ mod tests {
     use crate::alloc::string::ToString;
 
     use crate::culture::{
-        Animal, Beast, Constellation, Direction, Duty, Element, Land, Luck, Taboo,
+        Animal, Beast, Constellation, Direction, Duty, Element, Land, Luck, MonthGeneral, Phase,
+        Season, SeasonDay, Taboo, Zodiac,
     };
+    use crate::enums::{Precision, SeasonScheme, YearBoundary};
 
     #[cfg(feature = "god")]
     use crate::culture::God;
@@ -2178,4 +2563,176 @@ mod tests {
             l
         );
     }
+
+    #[test]
+    fn test42() {
+        let g: MonthGeneral =
+            MonthGeneral::from_solar_time(SolarTime::from_ymd_hms(2024, 3, 1, 12, 0, 0));
+        assert_eq!("登明", g.get_name());
+        assert_eq!("亥", g.get_earth_branch().get_name());
+        // 冬至（2024-12-21 17:20）前后换将
+        assert_eq!(
+            "功曹",
+            MonthGeneral::from_solar_time(SolarTime::from_ymd_hms(2024, 12, 21, 17, 0, 0))
+                .get_name()
+        );
+        assert_eq!(
+            "大吉",
+            MonthGeneral::from_solar_time(SolarTime::from_ymd_hms(2024, 12, 21, 18, 0, 0))
+                .get_name()
+        );
+        assert_eq!(
+            "河魁",
+            MonthGeneral::from_solar_time(SolarTime::from_ymd_hms(2024, 4, 1, 0, 0, 0)).get_name()
+        );
+    }
+
+    #[test]
+    fn test43() {
+        // 亥将加午时
+        let plate: Vec<String> = MonthGeneral::from_name("登明")
+            .get_heaven_plate(EarthBranch::from_name("午"))
+            .iter()
+            .map(|b| b.get_name())
+            .collect();
+        assert_eq!(
+            vec![
+                "巳", "午", "未", "申", "酉", "戌", "亥", "子", "丑", "寅", "卯", "辰"
+            ],
+            plate
+        );
+    }
+
+    #[test]
+    fn test44() {
+        let phase: Phase = Phase::from_name(2024, 1, "新月");
+        let high: SolarTime = phase.get_solar_time();
+        assert_eq!(high, phase.get_solar_time_with_precision(Precision::HIGH));
+        let standard: SolarTime = phase.get_solar_time_with_precision(Precision::STANDARD);
+        let fast: SolarTime = phase.get_solar_time_with_precision(Precision::FAST);
+        assert!((standard - high).abs() <= 60);
+        assert!((fast - high).abs() <= 7200);
+    }
+
+    #[test]
+    fn test45() {
+        // 2024年立春为2月4日，春节为2月10日
+        let day: SolarDay = SolarDay::from_ymd(2024, 2, 5);
+        assert_eq!(
+            "龙",
+            Zodiac::of_solar_day(day, YearBoundary::SPRING).get_name()
+        );
+        assert_eq!(
+            "兔",
+            Zodiac::of_solar_day(day, YearBoundary::LUNAR).get_name()
+        );
+        let day: SolarDay = SolarDay::from_ymd(2024, 2, 4);
+        assert_eq!(
+            "龙",
+            Zodiac::of_solar_day(day, YearBoundary::SPRING).get_name()
+        );
+        let day: SolarDay = SolarDay::from_ymd(2024, 2, 10);
+        assert_eq!(
+            "龙",
+            Zodiac::of_solar_day(day, YearBoundary::LUNAR).get_name()
+        );
+    }
+
+    #[test]
+    fn test46() {
+        // 2024年3月25日半影月食、4月9日（北京时间）日全食
+        let full: Phase = Phase::from_name(2024, 2, "满月");
+        assert_eq!("2024年3月25日", full.get_solar_day().to_string());
+        assert!(full.is_eclipse_possible());
+        assert!(full.get_moon_latitude().abs() < 1.5);
+        let new: Phase = Phase::from_name(2024, 3, "新月");
+        assert_eq!("2024年4月9日", new.get_solar_day().to_string());
+        assert!(new.is_eclipse_possible());
+
+        let new: Phase = Phase::from_name(2024, 5, "新月");
+        assert_eq!("2024年6月6日", new.get_solar_day().to_string());
+        assert!(!new.is_eclipse_possible());
+        assert!(new.get_moon_latitude() > 4.0);
+        // 太阳黄纬不超过约1角秒
+        let lat: f64 = new.get_sun_latitude();
+        assert!(lat != 0.0 && lat.abs() < 1.0 / 3600.0);
+        assert!(!Phase::from_name(2024, 2, "上弦月").is_eclipse_possible());
+    }
+
+    #[test]
+    fn test47() {
+        let day: SolarDay = SolarDay::from_ymd(2024, 3, 1);
+        // 2024年立春2月4日，春分3月20日
+        let term: SeasonDay = Season::of(day, SeasonScheme::TERM);
+        assert_eq!("春第27天", term.to_string());
+        assert_eq!("2024年2月4日", term.get_start_solar_day().to_string());
+        let astronomical: SeasonDay = Season::of(day, SeasonScheme::ASTRONOMICAL);
+        assert_eq!("冬", astronomical.get_season().get_name());
+        assert_eq!(
+            "2023年12月22日",
+            astronomical.get_start_solar_day().to_string()
+        );
+        assert_eq!(
+            "春第1天",
+            Season::of(day, SeasonScheme::METEOROLOGICAL).to_string()
+        );
+
+        let day: SolarDay = SolarDay::from_ymd(2024, 1, 15);
+        assert_eq!("冬", Season::of(day, SeasonScheme::TERM).get_name());
+        let meteorological: SeasonDay = Season::of(day, SeasonScheme::METEOROLOGICAL);
+        assert_eq!("冬第46天", meteorological.to_string());
+        assert_eq!(
+            "2023年12月1日",
+            meteorological.get_start_solar_day().to_string()
+        );
+        assert_eq!(
+            "秋第1天",
+            Season::of(SolarDay::from_ymd(2024, 9, 22), SeasonScheme::ASTRONOMICAL).to_string()
+        );
+        assert_eq!("夏", Season::from_index(5).get_name());
+    }
+
+    #[cfg(feature = "god")]
+    #[test]
+    fn test48() {
+        use crate::culture::generated_day_god_data::DAY_GODS_TABLE;
+        use crate::sixtycycle::SixtyCycle;
+
+        // 表中下标即干支日序号：寅月乙亥日（序号11）对应原始数据"0B0237130E2B4748727A3E66"
+        assert_eq!(
+            &[
+                0x02, 0x37, 0x13, 0x0E, 0x2B, 0x47, 0x48, 0x72, 0x7A, 0x3E, 0x66
+            ],
+            God::get_day_gods_slice(SixtyCycle::from_name("丙寅"), SixtyCycle::from_name("乙亥"))
+        );
+        // 丑月癸亥日（末格）
+        assert_eq!(
+            &[
+                0x12, 0x18, 0x25, 0x26, 0x30, 0x0F, 0x14, 0x17, 0x5B, 0x63, 0x72, 0x7D, 0x3E, 0x79,
+                0x74
+            ],
+            God::get_day_gods_slice(SixtyCycle::from_name("乙丑"), SixtyCycle::from_name("癸亥"))
+        );
+        assert!(DAY_GODS_TABLE.iter().flatten().all(|d| d.is_some()));
+        // 逐格对照原始数据：每段前2位为干支日序号，其后为该格的神煞序号
+        for (month, data) in original_strings::DAY_GODS.iter().enumerate() {
+            let mut count: usize = 0;
+            for segment in data.split(';').filter(|s| !s.is_empty()) {
+                let day: usize = usize::from_str_radix(&segment[..2], 16).unwrap();
+                let gods: String = DAY_GODS_TABLE[month][day]
+                    .unwrap()
+                    .iter()
+                    .map(|b| format!("{:02X}", b))
+                    .collect();
+                assert_eq!(segment[2..], gods, "month {} day {}", month, day);
+                count += 1;
+            }
+            assert_eq!(60, count, "month {}", month);
+        }
+    }
+
+    #[cfg(feature = "god")]
+    mod original_strings {
+        include!("../../builder/modules/day_god/original_strings.rs");
+    }
 }
@@ -0,0 +1,262 @@
+use core::fmt::{Display, Formatter};
+
+use alloc::format;
+use alloc::string::{String, ToString};
+
+use crate::culture::Element;
+use crate::enums::YinYang;
+use crate::lunar::LunarDay;
+use crate::sixtycycle::{EarthBranch, SixtyCycle, SixtyCycleYear};
+use crate::solar::{SolarDay, SolarTerm, SolarTime};
+use crate::types::Culture;
+
+/// 五行对应的颜色（木青、火红、土黄、金白、水黑）
+pub static SPRING_OX_COLOR_NAMES: [&str; 5] = ["青", "红", "黄", "白", "黑"];
+
+/// 拘绳、鞭结用料（孟、仲、季）
+pub static SPRING_OX_ROPE_NAMES: [&str; 3] = ["麻", "苎", "丝"];
+
+/// 芒神老少（孟、仲、季）
+pub static MANG_SHEN_AGE_NAMES: [&str; 3] = ["老", "壮", "童"];
+
+/// 芒神发髻（按立春日纳音五行：木、火、土、金、水）
+pub static MANG_SHEN_HAIR_NAMES: [&str; 5] = [
+    "两髻在耳后",
+    "右髻在耳后，左髻在耳前",
+    "两髻在顶直上",
+    "两髻在耳前",
+    "左髻在耳后，右髻在耳前",
+];
+
+/// 芒神罨耳（全戴、揭左、揭右、手提）
+pub static MANG_SHEN_EAR_MUFF_NAMES: [&str; 4] = ["全戴", "揭起左边", "揭起右边", "用手提"];
+
+/// 芒神鞋裤（按立春日纳音五行：木、火、土、金、水）
+pub static MANG_SHEN_SHOES_NAMES: [&str; 5] = [
+    "行缠鞋裤俱全，右行缠悬于腰",
+    "行缠鞋裤俱无",
+    "着裤，无行缠鞋子",
+    "行缠鞋裤俱全，左行缠悬于腰",
+    "行缠鞋裤俱全",
+];
+
+/// 芒神站位（立春在正月初一前5日外、前后5日内、后5日外）
+pub static MANG_SHEN_POSITION_NAMES: [&str; 3] = ["立于牛前", "与牛并立", "立于牛后"];
+
+/// 春牛芒神（春牛经）
+#[derive(Debug, Clone)]
+pub struct SpringOx {
+    /// 立春时刻
+    solar_time: SolarTime,
+    /// 年干支
+    year: SixtyCycle,
+    /// 立春日干支
+    day: SixtyCycle,
+}
+
+impl Culture for SpringOx {
+    fn get_name(&self) -> String {
+        format!("{}年春牛芒神", self.year)
+    }
+}
+
+impl SpringOx {
+    /// 公历年的立春
+    pub fn from_year(year: isize) -> Self {
+        let solar_time: SolarTime = SolarTerm::from_index(year, 3)
+            .get_julian_day()
+            .get_solar_time();
+        Self {
+            solar_time,
+            year: SixtyCycleYear::from_year(year).get_sixty_cycle(),
+            day: solar_time.get_solar_day().get_lunar_day().get_sixty_cycle(),
+        }
+    }
+
+    /// 立春时刻
+    pub fn get_solar_time(&self) -> SolarTime {
+        self.solar_time
+    }
+
+    /// 年干支
+    pub fn get_year(&self) -> SixtyCycle {
+        self.year.clone()
+    }
+
+    /// 立春日干支
+    pub fn get_day(&self) -> SixtyCycle {
+        self.day.clone()
+    }
+
+    /// 牛头色（年干）
+    pub fn get_head_color(&self) -> String {
+        Self::color(self.year.get_heaven_stem().get_element())
+    }
+
+    /// 牛身色（年支）
+    pub fn get_body_color(&self) -> String {
+        Self::color(self.year.get_earth_branch().get_element())
+    }
+
+    /// 牛腹色（年纳音）
+    pub fn get_belly_color(&self) -> String {
+        Self::color(self.year.get_sound().get_element())
+    }
+
+    /// 牛角、耳、尾色（立春日干）
+    pub fn get_horn_color(&self) -> String {
+        Self::color(self.day.get_heaven_stem().get_element())
+    }
+
+    /// 牛胫色（立春日支）
+    pub fn get_shin_color(&self) -> String {
+        Self::color(self.day.get_earth_branch().get_element())
+    }
+
+    /// 牛蹄色（立春日纳音）
+    pub fn get_hoof_color(&self) -> String {
+        Self::color(self.day.get_sound().get_element())
+    }
+
+    /// 牛尾（阳年左缴，阴年右缴）
+    pub fn get_tail(&self) -> String {
+        match self.year.get_earth_branch().get_yin_yang() {
+            YinYang::YANG => "左缴",
+            YinYang::YIN => "右缴",
+        }
+        .to_string()
+    }
+
+    /// 牛口（阳年开，阴年合）
+    pub fn get_mouth(&self) -> String {
+        match self.year.get_earth_branch().get_yin_yang() {
+            YinYang::YANG => "开",
+            YinYang::YIN => "合",
+        }
+        .to_string()
+    }
+
+    /// 拘绳（立春日支孟麻、仲苎、季丝）
+    pub fn get_rope(&self) -> String {
+        SPRING_OX_ROPE_NAMES[Self::season_index(self.day.get_earth_branch())].to_string()
+    }
+
+    /// 芒神老少（年支孟老、仲壮、季童）
+    pub fn get_mang_shen_age(&self) -> String {
+        MANG_SHEN_AGE_NAMES[Self::season_index(self.year.get_earth_branch())].to_string()
+    }
+
+    /// 芒神衣色（立春日支所克）
+    pub fn get_mang_shen_clothes_color(&self) -> String {
+        Self::color(self.day.get_earth_branch().get_element().get_restrain())
+    }
+
+    /// 芒神腰带色（立春日支所生）
+    pub fn get_mang_shen_belt_color(&self) -> String {
+        Self::color(self.day.get_earth_branch().get_element().get_reinforce())
+    }
+
+    /// 芒神发髻（立春日纳音）
+    pub fn get_mang_shen_hair(&self) -> String {
+        MANG_SHEN_HAIR_NAMES[self.day.get_sound().get_element().get_index()].to_string()
+    }
+
+    /// 芒神罨耳（立春时辰：子丑全戴，寅揭左，亥揭右，卯至戌手提）
+    pub fn get_mang_shen_ear_muff(&self) -> String {
+        let index: usize = match EarthBranch::from_hour(self.solar_time.get_hour()).get_index() {
+            0 | 1 => 0,
+            2 => 1,
+            11 => 2,
+            _ => 3,
+        };
+        MANG_SHEN_EAR_MUFF_NAMES[index].to_string()
+    }
+
+    /// 芒神鞋裤（立春日纳音）
+    pub fn get_mang_shen_shoes(&self) -> String {
+        MANG_SHEN_SHOES_NAMES[self.day.get_sound().get_element().get_index()].to_string()
+    }
+
+    /// 芒神站位（立春距正月初一）
+    pub fn get_mang_shen_position(&self) -> String {
+        let spring: SolarDay = self.solar_time.get_solar_day();
+        let new_year: SolarDay =
+            LunarDay::from_ymd(self.solar_time.get_year(), 1, 1).get_solar_day();
+        let diff: isize = spring.subtract(new_year);
+        let index: usize = if diff < -5 {
+            0
+        } else if diff > 5 {
+            2
+        } else {
+            1
+        };
+        MANG_SHEN_POSITION_NAMES[index].to_string()
+    }
+
+    fn color(element: Element) -> String {
+        SPRING_OX_COLOR_NAMES[element.get_index()].to_string()
+    }
+
+    /// 孟（寅申巳亥）0，仲（子午卯酉）1，季（辰戌丑未）2
+    fn season_index(earth_branch: EarthBranch) -> usize {
+        (earth_branch.get_index() + 10) % 3
+    }
+}
+
+impl Display for SpringOx {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.get_name())
+    }
+}
+
+impl PartialEq for SpringOx {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
+impl Eq for SpringOx {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::culture::springox::SpringOx;
+
+    #[test]
+    fn test1() {
+        let ox: SpringOx = SpringOx::from_year(2024);
+        assert_eq!("甲辰年春牛芒神", ox.to_string());
+        assert_eq!("戊戌", ox.get_day().to_string());
+        assert_eq!("青", ox.get_head_color());
+        assert_eq!("黄", ox.get_body_color());
+        assert_eq!("红", ox.get_belly_color());
+        assert_eq!("黄", ox.get_horn_color());
+        assert_eq!("黄", ox.get_shin_color());
+        assert_eq!("青", ox.get_hoof_color());
+        assert_eq!("左缴", ox.get_tail());
+        assert_eq!("开", ox.get_mouth());
+        assert_eq!("丝", ox.get_rope());
+    }
+
+    #[test]
+    fn test2() {
+        let ox: SpringOx = SpringOx::from_year(2024);
+        assert_eq!("童", ox.get_mang_shen_age());
+        assert_eq!("黑", ox.get_mang_shen_clothes_color());
+        assert_eq!("白", ox.get_mang_shen_belt_color());
+        assert_eq!("两髻在耳后", ox.get_mang_shen_hair());
+        assert_eq!("用手提", ox.get_mang_shen_ear_muff());
+        assert_eq!("立于牛前", ox.get_mang_shen_position());
+    }
+
+    #[test]
+    fn test3() {
+        // 2025年立春（2月3日）在正月初一（1月29日）后5日内
+        let ox: SpringOx = SpringOx::from_year(2025);
+        assert_eq!("乙巳年春牛芒神", ox.to_string());
+        assert_eq!("老", ox.get_mang_shen_age());
+        assert_eq!("右缴", ox.get_tail());
+        assert_eq!("与牛并立", ox.get_mang_shen_position());
+    }
+}
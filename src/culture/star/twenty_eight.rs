@@ -1,6 +1,8 @@
 use core::fmt::{Display, Formatter};
 
+use alloc::format;
 use alloc::string::{String, ToString};
+use libm::floor;
 
 use crate::culture::star::seven::SevenStar;
 use crate::culture::{Animal, Land, Luck, Zone};
@@ -9,6 +11,19 @@ use crate::types::{Culture, LoopTyme, Tyme};
 #[rustfmt::skip]
 pub static TWENTY_EIGHT_STAR_NAMES: [&str; 28] = ["角", "亢", "氐", "房", "心", "尾", "箕", "斗", "牛", "女", "虚", "危", "室", "壁", "奎", "娄", "胃", "昴", "毕", "觜", "参", "井", "鬼", "柳", "星", "张", "翼", "轸"];
 
+/// 二十八宿距度（古度，周天365.25度），角宿起
+#[rustfmt::skip]
+pub static TWENTY_EIGHT_STAR_WIDTHS: [f64; 28] = [12.0, 9.0, 15.0, 5.0, 5.0, 18.0, 11.0, 26.25, 8.0, 12.0, 10.0, 17.0, 16.0, 9.0, 16.0, 12.0, 14.0, 11.0, 16.0, 2.0, 9.0, 33.0, 4.0, 15.0, 7.0, 18.0, 18.0, 17.0];
+
+/// 周天古度
+pub static CIRCLE_DEGREES: f64 = 365.25;
+
+/// 角宿距星（角宿一）J2000.0平黄经（度）
+static JIAO_LONGITUDE_J2000: f64 = 203.841;
+
+/// 黄经总岁差（度/儒略世纪）
+static PRECESSION_PER_CENTURY: f64 = 1.396_971;
+
 /// 二十八宿
 #[derive(Debug, Clone)]
 pub struct TwentyEightStar {
@@ -110,3 +125,57 @@ impl Into<LoopTyme> for TwentyEightStar {
         self.parent
     }
 }
+
+/// 入宿度（日月所在的宿及入宿古度）
+///
+/// 以角宿距星的黄经（计岁差）为角宿起点，按传统距度比例划分黄道，为近似值。
+#[derive(Debug, Clone)]
+pub struct TwentyEightStarPosition {
+    /// 宿
+    star: TwentyEightStar,
+    /// 入宿古度
+    degree: f64,
+}
+
+impl TwentyEightStarPosition {
+    /// 由视黄经（度）及J2000起算的儒略世纪数计算
+    pub fn from_ecliptic_longitude(longitude: f64, t: f64) -> Self {
+        let start: f64 = JIAO_LONGITUDE_J2000 + PRECESSION_PER_CENTURY * t;
+        let mut d: f64 = (longitude - start) % 360.0;
+        if d < 0.0 {
+            d += 360.0;
+        }
+        let mut degree: f64 = d * CIRCLE_DEGREES / 360.0;
+        let mut index: usize = 0;
+        while index < 27 && degree >= TWENTY_EIGHT_STAR_WIDTHS[index] {
+            degree -= TWENTY_EIGHT_STAR_WIDTHS[index];
+            index += 1;
+        }
+        Self {
+            star: TwentyEightStar::from_index(index as isize),
+            degree,
+        }
+    }
+
+    /// 宿
+    pub fn get_star(&self) -> TwentyEightStar {
+        self.star.clone()
+    }
+
+    /// 入宿古度
+    pub fn get_degree(&self) -> f64 {
+        self.degree
+    }
+}
+
+impl Culture for TwentyEightStarPosition {
+    fn get_name(&self) -> String {
+        format!("入{}宿{}度", self.star, floor(self.degree) as usize)
+    }
+}
+
+impl Display for TwentyEightStarPosition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.get_name())
+    }
+}
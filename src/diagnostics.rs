@@ -0,0 +1,176 @@
+//! 运行时自检：计算若干已知锚点值，供软浮点、wasm等特殊目标在启动时核对数值行为
+
+use core::f64::consts::PI;
+use core::fmt::{Display, Formatter};
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::lunar::{LunarDay, LunarMonth, LunarYear};
+use crate::sxtwl::{PI_2, Sxtwl};
+use crate::types::Culture;
+
+/// J2000.0太阳视黄经（度，x86-64双精度参考值）
+const J2000_SUN_LONGITUDE: f64 = 280.36816;
+
+/// J2000.0太阳视黄经允许的偏差（度，约1角秒）
+const J2000_SUN_LONGITUDE_TOLERANCE: f64 = 0.0003;
+
+/// 自检项
+#[derive(Debug, Clone, PartialEq)]
+pub struct SelfCheckItem {
+    /// 名称
+    name: &'static str,
+    /// 期望值
+    expected: String,
+    /// 实际值
+    actual: String,
+    /// 是否通过
+    passed: bool,
+}
+
+impl SelfCheckItem {
+    fn new(name: &'static str, expected: String, actual: String, passed: bool) -> Self {
+        Self {
+            name,
+            expected,
+            actual,
+            passed,
+        }
+    }
+
+    /// 按文本是否相同判定
+    fn equal(name: &'static str, expected: &str, actual: String) -> Self {
+        let passed: bool = expected == actual;
+        Self::new(name, expected.to_string(), actual, passed)
+    }
+
+    /// 名称
+    pub fn get_name(&self) -> &'static str {
+        self.name
+    }
+
+    /// 期望值
+    pub fn get_expected(&self) -> &str {
+        &self.expected
+    }
+
+    /// 实际值
+    pub fn get_actual(&self) -> &str {
+        &self.actual
+    }
+
+    /// 是否通过
+    pub fn is_passed(&self) -> bool {
+        self.passed
+    }
+}
+
+impl Display for SelfCheckItem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{}：期望{}，实际{}，{}",
+            self.name,
+            self.expected,
+            self.actual,
+            if self.passed { "通过" } else { "未通过" }
+        )
+    }
+}
+
+/// 自检结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct SelfCheckReport {
+    /// 各自检项
+    items: Vec<SelfCheckItem>,
+}
+
+impl SelfCheckReport {
+    /// 各自检项
+    pub fn get_items(&self) -> &[SelfCheckItem] {
+        &self.items
+    }
+
+    /// 未通过的自检项
+    pub fn get_failures(&self) -> Vec<&SelfCheckItem> {
+        self.items.iter().filter(|i| !i.is_passed()).collect()
+    }
+
+    /// 是否全部通过
+    pub fn is_passed(&self) -> bool {
+        self.items.iter().all(|i| i.is_passed())
+    }
+}
+
+impl Display for SelfCheckReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        for (i, item) in self.items.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", item)?;
+        }
+        Ok(())
+    }
+}
+
+/// 运行自检：J2000.0太阳视黄经、2017年春节（1月28日）、2033年闰月（闰冬月）
+pub fn self_check() -> SelfCheckReport {
+    SelfCheckReport {
+        items: vec![
+            check_j2000_sun_longitude(),
+            check_spring_festival_2017(),
+            check_leap_month_2033(),
+        ],
+    }
+}
+
+fn check_j2000_sun_longitude() -> SelfCheckItem {
+    let v: f64 = Sxtwl::sa_lon(0.0, -1) % PI_2 * 180.0 / PI;
+    SelfCheckItem::new(
+        "J2000太阳视黄经",
+        format!("{:.5}°", J2000_SUN_LONGITUDE),
+        format!("{:.5}°", v),
+        (v - J2000_SUN_LONGITUDE).abs() < J2000_SUN_LONGITUDE_TOLERANCE,
+    )
+}
+
+fn check_spring_festival_2017() -> SelfCheckItem {
+    let actual: String = match LunarDay::new(2017, 1, 1) {
+        Ok(d) => d.get_solar_day().to_string(),
+        Err(e) => e,
+    };
+    SelfCheckItem::equal("2017年春节", "2017年1月28日", actual)
+}
+
+fn check_leap_month_2033() -> SelfCheckItem {
+    let leap: usize = LunarYear::from_year(2033).get_leap_month();
+    let actual: String = if leap == 0 {
+        String::from("无闰月")
+    } else {
+        LunarMonth::from_ym(2033, -(leap as isize)).get_name()
+    };
+    SelfCheckItem::equal("2033年闰月", "闰冬月", actual)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::diagnostics::{SelfCheckReport, self_check};
+
+    #[test]
+    fn test1() {
+        let report: SelfCheckReport = self_check();
+        assert_eq!(3, report.get_items().len());
+        assert!(report.is_passed(), "{}", report);
+        assert!(report.get_failures().is_empty());
+        assert_eq!(
+            "2033年闰月：期望闰冬月，实际闰冬月，通过",
+            report.get_items()[2].to_string()
+        );
+    }
+}
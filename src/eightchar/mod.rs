@@ -1,20 +1,31 @@
 use core::fmt::{Display, Formatter};
+use core::str::FromStr;
 
 use alloc::string::String;
 use alloc::vec::Vec;
 use alloc::{format, vec};
 use libm::ceil;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
+use crate::context::CalendarContext;
 use crate::culture::Duty;
-use crate::eightchar::provider::CHILD_LIMIT_PROVIDER;
-use crate::enums::{Gender, YinYang};
-use crate::lunar::LunarYear;
-use crate::sixtycycle::{EarthBranch, HeavenStem, SixtyCycle, SixtyCycleYear, ThreePillars};
+use crate::eightchar::provider::{CHILD_LIMIT_PROVIDER, EIGHT_CHAR_PROVIDER};
+use crate::enums::{Gender, SpringSwitch, YearBoundary, YinYang, ZiShiPolicy};
+use crate::lunar::{LunarHour, LunarYear};
+use crate::sixtycycle::{
+    EarthBranch, HeavenStem, SIXTY_CYCLE_NAMES, SixtyCycle, SixtyCycleDay, SixtyCycleHour,
+    SixtyCycleYear, ThreePillars,
+};
 use crate::solar::{SolarDay, SolarTerm, SolarTime};
 use crate::types::{Culture, Tyme};
 
 pub mod provider;
 
+/// 并行批量计算时每块的最多时刻数
+#[cfg(feature = "parallel")]
+const PAR_CHUNK_SIZE: usize = 4096;
+
 /// 八字
 #[derive(Debug, Clone)]
 pub struct EightChar {
@@ -48,6 +59,169 @@ impl EightChar {
         }
     }
 
+    /// 换用指定年柱，月支不变，月干按新年干以五虎遁重排
+    pub(crate) fn with_year(&self, year: SixtyCycle) -> Self {
+        let branch: EarthBranch = self.get_month().get_earth_branch();
+        let stem: HeavenStem = month_stem(&year, &branch);
+        Self::from_sixty_cycle(
+            year,
+            SixtyCycle::from_stem_branch(stem, branch).unwrap(),
+            self.get_day(),
+            self.get_hour(),
+        )
+    }
+
+    /// 紧凑文本，四柱以空格分隔，如：甲子 丙寅 戊辰 壬子，可由from_str解析
+    pub fn to_compact_string(&self) -> String {
+        format!(
+            "{} {} {} {}",
+            self.get_year(),
+            self.get_month(),
+            self.get_day(),
+            self.hour
+        )
+    }
+
+    /// 按全局八字服务（EIGHT_CHAR_PROVIDER）批量计算八字，等同逐个调用LunarHour::get_eight_char，见batch_with_context
+    pub fn batch(birth_times: &[SolarTime]) -> Vec<EightChar> {
+        EIGHT_CHAR_PROVIDER.batch(birth_times)
+    }
+
+    /// 批量计算八字，结果与输入顺序一致，等同逐个调用LunarHour::get_eight_char_with_context。
+    /// 内部按时刻排序，同一节令月内复用年柱（立春为界时）、月柱，同一天内复用日柱，适合大批量统计
+    pub fn batch_with_context(
+        birth_times: &[SolarTime],
+        context: &CalendarContext,
+    ) -> Vec<EightChar> {
+        let mut order: Vec<usize> = (0..birth_times.len()).collect();
+        order.sort_by_key(|i| birth_times[*i]);
+        let mut result: Vec<Option<EightChar>> = vec![None; birth_times.len()];
+        // 节令月：起止时刻（含起不含止）、立春为界的年柱、月柱、止于立春时的立春日
+        let mut month: Option<(
+            SolarTime,
+            SolarTime,
+            SixtyCycle,
+            SixtyCycle,
+            Option<SolarDay>,
+        )> = None;
+        // 公历日：日期、日柱（0时为日界）、农历年
+        let mut day: Option<(SolarDay, SixtyCycle, isize)> = None;
+        for i in order {
+            let time: SolarTime = birth_times[i];
+            let (spring_year, month_cycle, spring_day) = match &month {
+                Some((start, end, y, m, s)) if !time.is_before(*start) && time.is_before(*end) => {
+                    (y.clone(), m.clone(), *s)
+                }
+                _ => {
+                    let h: SixtyCycleHour = time.get_sixty_cycle_hour();
+                    let term: SolarTerm = time.get_term();
+                    let jie: SolarTerm = if term.is_jie() { term } else { term.next(-1) };
+                    let end: SolarTerm = jie.next(2);
+                    let end_time: SolarTime = end.get_julian_day().get_solar_time();
+                    let s: Option<SolarDay> = if end.get_index() == 3 {
+                        Some(end_time.get_solar_day())
+                    } else {
+                        None
+                    };
+                    month = Some((
+                        jie.get_julian_day().get_solar_time(),
+                        end_time,
+                        h.get_year(),
+                        h.get_month(),
+                        s,
+                    ));
+                    (h.get_year(), h.get_month(), s)
+                }
+            };
+            let solar_day: SolarDay = time.get_solar_day();
+            let (day_cycle, lunar_year) = match &day {
+                Some((d, c, y)) if *d == solar_day => (c.clone(), *y),
+                _ => {
+                    let c: SixtyCycle = SixtyCycle::of_julian_day(solar_day.get_julian_day());
+                    let y: isize = solar_day.get_lunar_day().get_year();
+                    day = Some((solar_day, c.clone(), y));
+                    (c, y)
+                }
+            };
+            // 立春当天交节前，按立春当日换年时年柱、月柱取当天的
+            let (spring_year, month_cycle) = if context.get_spring_switch() == SpringSwitch::DAY
+                && spring_day == Some(solar_day)
+            {
+                let d: SixtyCycleDay = SixtyCycleDay::from_solar_day(solar_day);
+                (d.get_year(), d.get_month())
+            } else {
+                (spring_year, month_cycle)
+            };
+            // 晚子时的时柱按次日日干起
+            let late: bool = time.get_hour() == 23;
+            let next_day_cycle: SixtyCycle = if late {
+                day_cycle.next(1)
+            } else {
+                day_cycle.clone()
+            };
+            let branch: EarthBranch = EarthBranch::from_hour(time.get_hour());
+            let hour: SixtyCycle =
+                SixtyCycle::from_stem_branch(hour_stem(&next_day_cycle, &branch), branch).unwrap();
+            let day_pillar: SixtyCycle = match context.get_zi_shi() {
+                ZiShiPolicy::NEXT => next_day_cycle,
+                ZiShiPolicy::CURRENT => day_cycle,
+            };
+            let eight_char: EightChar =
+                Self::from_sixty_cycle(spring_year, month_cycle, day_pillar, hour);
+            result[i] = Some(match context.get_year_boundary() {
+                YearBoundary::SPRING => eight_char,
+                YearBoundary::LUNAR => {
+                    eight_char.with_year(LunarYear::from_year(lunar_year).get_sixty_cycle())
+                }
+            });
+        }
+        result.into_iter().flatten().collect()
+    }
+
+    /// 按全局八字服务（EIGHT_CHAR_PROVIDER）并行批量计算八字，结果与batch相同，见par_batch_with_context
+    #[cfg(feature = "parallel")]
+    pub fn par_batch(birth_times: &[SolarTime]) -> Vec<EightChar> {
+        EIGHT_CHAR_PROVIDER.par_batch(birth_times)
+    }
+
+    /// 并行批量计算八字，结果与batch_with_context相同。
+    /// 按时刻排序后以公历年分块（单年超过4096个时刻时再切分），块内按batch_with_context顺序计算以复用各柱及农历月缓存，各块由rayon并行
+    #[cfg(feature = "parallel")]
+    pub fn par_batch_with_context(
+        birth_times: &[SolarTime],
+        context: &CalendarContext,
+    ) -> Vec<EightChar> {
+        let mut order: Vec<usize> = (0..birth_times.len()).collect();
+        order.sort_by_key(|i| birth_times[*i]);
+        let mut chunks: Vec<&[usize]> = Vec::new();
+        let mut rest: &[usize] = &order;
+        while !rest.is_empty() {
+            let year: isize = birth_times[rest[0]].get_year();
+            let n: usize = rest
+                .iter()
+                .take(PAR_CHUNK_SIZE)
+                .take_while(|i| birth_times[**i].get_year() == year)
+                .count();
+            let (chunk, r) = rest.split_at(n);
+            chunks.push(chunk);
+            rest = r;
+        }
+        let parts: Vec<Vec<EightChar>> = chunks
+            .par_iter()
+            .map(|chunk| {
+                let times: Vec<SolarTime> = chunk.iter().map(|i| birth_times[*i]).collect();
+                Self::batch_with_context(&times, context)
+            })
+            .collect();
+        let mut result: Vec<Option<EightChar>> = vec![None; birth_times.len()];
+        for (chunk, part) in chunks.iter().zip(parts) {
+            for (i, eight_char) in chunk.iter().zip(part) {
+                result[*i] = Some(eight_char);
+            }
+        }
+        result.into_iter().flatten().collect()
+    }
+
     pub fn get_year(&self) -> SixtyCycle {
         self.three_pillars.get_year()
     }
@@ -113,6 +287,16 @@ impl EightChar {
         )
     }
 
+    /// 命宫（按太阳过宫：以出生时刻的月将加临时支，地盘卯上所临之支为命宫，以年干五虎遁起干）
+    pub fn get_own_sign_by_sun(&self, time: SolarTime) -> SixtyCycle {
+        let branch: EarthBranch = time
+            .get_month_general()
+            .get_heaven_plate(self.hour.get_earth_branch())[3]
+            .clone();
+        let stem: HeavenStem = month_stem(&self.get_year(), &branch);
+        SixtyCycle::from_stem_branch(stem, branch).unwrap()
+    }
+
     pub fn get_body_sign(&self) -> SixtyCycle {
         let mut offset: isize = self.get_month().get_earth_branch().get_index() as isize - 1;
         if offset < 1 {
@@ -226,6 +410,75 @@ impl Display for EightChar {
     }
 }
 
+impl FromStr for EightChar {
+    type Err = String;
+
+    /// 解析紧凑文本，并校验月干与年干（五虎遁）、时干与日干（五鼠遁）的对应关系；
+    /// 子时另接受按次日日干起的时干（晚子时日柱不换日，如ZiShiPolicy::CURRENT、lunar-sect2）
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let names: Vec<&str> = s.split_whitespace().collect();
+        if names.len() != 4 {
+            return Err(format!("illegal eight char: {}", s));
+        }
+        for name in names.iter() {
+            if !SIXTY_CYCLE_NAMES.contains(name) {
+                return Err(format!("illegal sixty cycle: {}", name));
+            }
+        }
+        let eight_char: EightChar = Self::new(names[0], names[1], names[2], names[3]);
+        let month: SixtyCycle = eight_char.get_month();
+        let month_stem: HeavenStem = month_stem(&eight_char.get_year(), &month.get_earth_branch());
+        if month.get_heaven_stem() != month_stem {
+            return Err(format!(
+                "illegal month pillar {} for year {}, expected {}{}",
+                month,
+                eight_char.get_year(),
+                month_stem,
+                month.get_earth_branch()
+            ));
+        }
+        let hour: SixtyCycle = eight_char.get_hour();
+        let hour_stem: HeavenStem = hour_stem(&eight_char.get_day(), &hour.get_earth_branch());
+        let late_zi: bool = hour.get_earth_branch().get_index() == 0
+            && hour.get_heaven_stem()
+                == self::hour_stem(&eight_char.get_day().next(1), &hour.get_earth_branch());
+        if hour.get_heaven_stem() != hour_stem && !late_zi {
+            return Err(format!(
+                "illegal hour pillar {} for day {}, expected {}{}",
+                hour,
+                eight_char.get_day(),
+                hour_stem,
+                hour.get_earth_branch()
+            ));
+        }
+        Ok(eight_char)
+    }
+}
+
+/// 五虎遁：由年干起寅月天干，推得指定月支的天干
+fn month_stem(year: &SixtyCycle, month_branch: &EarthBranch) -> HeavenStem {
+    HeavenStem::from_index(
+        year.get_heaven_stem().get_index() as isize * 2
+            + (month_branch.get_index() as isize + 10) % 12
+            + 2,
+    )
+}
+
+/// 五鼠遁：由日干起子时天干，推得指定时支的天干
+fn hour_stem(day: &SixtyCycle, hour_branch: &EarthBranch) -> HeavenStem {
+    HeavenStem::from_index(
+        day.get_heaven_stem().get_index() as isize % 5 * 2 + hour_branch.get_index() as isize,
+    )
+}
+
+/// 指定年柱分界下，某时刻用于计算童限、大运、流年的干支年
+pub(crate) fn get_sixty_cycle_year(time: SolarTime, year_boundary: YearBoundary) -> SixtyCycleYear {
+    match year_boundary {
+        YearBoundary::SPRING => SixtyCycleYear::from_year(time.get_year()),
+        YearBoundary::LUNAR => SixtyCycleYear::from_year(time.get_lunar_hour().get_year()),
+    }
+}
+
 impl PartialEq for EightChar {
     fn eq(&self, other: &Self) -> bool {
         self.get_name() == other.get_name()
@@ -317,11 +570,42 @@ pub struct ChildLimit {
     gender: Gender,
     forward: bool,
     info: ChildLimitInfo,
+    year_boundary: YearBoundary,
 }
 
 impl ChildLimit {
     pub fn from_solar_time(birth_time: SolarTime, gender: Gender) -> Self {
-        let eight_char: EightChar = birth_time.get_lunar_hour().get_eight_char();
+        Self::from_eight_char(
+            birth_time,
+            gender,
+            birth_time.get_lunar_hour().get_eight_char(),
+            EIGHT_CHAR_PROVIDER.get_year_boundary(),
+        )
+    }
+
+    /// 按上下文的晚子时取法、年柱分界及立春当天换年取法计算八字及童限
+    pub fn from_solar_time_with_context(
+        birth_time: SolarTime,
+        gender: Gender,
+        context: &CalendarContext,
+    ) -> Self {
+        Self::from_eight_char(
+            birth_time,
+            gender,
+            birth_time
+                .get_lunar_hour()
+                .get_eight_char_with_context(context),
+            context.get_year_boundary(),
+        )
+    }
+
+    /// 由出生时刻的八字及其年柱分界计算童限，顺逆按八字年干阴阳，起止干支年按年柱分界
+    pub(crate) fn from_eight_char(
+        birth_time: SolarTime,
+        gender: Gender,
+        eight_char: EightChar,
+        year_boundary: YearBoundary,
+    ) -> Self {
         // 阳男阴女顺推，阴男阳女逆推
         let yang: bool = YinYang::YANG == eight_char.get_year().get_heaven_stem().get_yin_yang();
         let man: bool = Gender::MAN == gender;
@@ -340,6 +624,7 @@ impl ChildLimit {
             gender,
             forward,
             info,
+            year_boundary,
         }
     }
 
@@ -355,6 +640,11 @@ impl ChildLimit {
         self.forward
     }
 
+    /// 年柱分界
+    pub fn get_year_boundary(&self) -> YearBoundary {
+        self.year_boundary
+    }
+
     pub fn get_year_count(&self) -> usize {
         self.info.get_year_count()
     }
@@ -410,12 +700,12 @@ impl ChildLimit {
 
     /// 开始(即出生)干支年
     pub fn get_start_sixty_cycle_year(&self) -> SixtyCycleYear {
-        SixtyCycleYear::from_year(self.get_start_time().get_year())
+        get_sixty_cycle_year(self.get_start_time(), self.year_boundary)
     }
 
     /// 结束(即起运)干支年
     pub fn get_end_sixty_cycle_year(&self) -> SixtyCycleYear {
-        SixtyCycleYear::from_year(self.get_end_time().get_year())
+        get_sixty_cycle_year(self.get_end_time(), self.year_boundary)
     }
 
     /// 开始年龄
@@ -632,11 +922,46 @@ impl PartialEq for Fortune {
 
 impl Eq for Fortune {}
 
+/// 农历年、月（闰月为负）、日、时、分的八字，晚子时日柱按policy取法，年柱以立春为界。
+/// 时分越界、农历月不存在（含当年无此闰月）或该月无此日时返回错误
+pub fn from_lunar(
+    year: isize,
+    month: isize,
+    day: usize,
+    hour: usize,
+    minute: usize,
+    policy: ZiShiPolicy,
+) -> Result<EightChar, String> {
+    if hour > 23 {
+        return Err(format!("illegal hour: {}, expected 0-23", hour));
+    }
+    if minute > 59 {
+        return Err(format!("illegal minute: {}, expected 0-59", minute));
+    }
+    let lunar_hour: LunarHour = LunarHour::new(year, month, day, hour, minute, 0)?;
+    Ok(lunar_hour.get_eight_char_with_context(
+        &CalendarContext::default()
+            .with_zi_shi(policy)
+            .with_year_boundary(YearBoundary::SPRING),
+    ))
+}
+
 #[cfg(test)]
 mod tests {
-    use alloc::string::ToString;
-
-    use crate::solar::SolarTime;
+    use alloc::format;
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+    use core::str::FromStr;
+
+    use crate::context::CalendarContext;
+    #[cfg(feature = "eight-char-default-provider")]
+    use crate::eightchar::provider::DefaultEightCharProvider;
+    use crate::eightchar::provider::{EightCharProvider, EightCharService};
+    use crate::eightchar::{ChildLimit, EightChar, from_lunar};
+    use crate::enums::{Gender, SpringSwitch, YearBoundary, ZiShiPolicy};
+    use crate::lunar::LunarHour;
+    use crate::solar::{SolarDay, SolarTime};
+    use crate::types::Tyme;
 
     #[test]
     fn test1() {
@@ -648,4 +973,346 @@ mod tests {
                 .to_string()
         );
     }
+
+    #[cfg(feature = "eight-char-default-provider")]
+    #[test]
+    fn test2() {
+        let hour: LunarHour = SolarTime::from_ymd_hms(2024, 2, 5, 12, 0, 0).get_lunar_hour();
+        let spring: EightCharService<DefaultEightCharProvider> =
+            EightCharService::new(DefaultEightCharProvider::new());
+        assert_eq!(YearBoundary::SPRING, spring.get_year_boundary());
+        assert_eq!(
+            "甲辰",
+            spring.get_eight_char(hour.clone()).get_year().to_string()
+        );
+        let new_year: EightCharService<DefaultEightCharProvider> =
+            EightCharService::with_year_boundary(
+                DefaultEightCharProvider::new(),
+                YearBoundary::LUNAR,
+            );
+        let eight_char: EightChar = new_year.get_eight_char(hour);
+        // 年柱换为癸卯后，寅月月干按五虎遁为甲
+        assert_eq!("癸卯 甲寅 己亥 庚午", eight_char.to_string());
+        assert_eq!(
+            eight_char,
+            EightChar::from_str(&eight_char.to_string()).unwrap()
+        );
+        assert_eq!(
+            2023,
+            new_year
+                .get_sixty_cycle_year(SolarTime::from_ymd_hms(2024, 2, 5, 12, 0, 0))
+                .get_year()
+        );
+
+        // 童限随年柱分界：甲辰阳年男命顺推，癸卯阴年男命逆推
+        let time: SolarTime = SolarTime::from_ymd_hms(2024, 2, 5, 12, 0, 0);
+        let child_limit: ChildLimit = spring.get_child_limit(time, Gender::MAN);
+        assert!(child_limit.is_forward());
+        assert_eq!(2024, child_limit.get_start_sixty_cycle_year().get_year());
+        let child_limit: ChildLimit = new_year.get_child_limit(time, Gender::MAN);
+        assert!(!child_limit.is_forward());
+        assert_eq!(YearBoundary::LUNAR, child_limit.get_year_boundary());
+        assert_eq!("癸卯", child_limit.get_eight_char().get_year().to_string());
+        assert_eq!(2023, child_limit.get_start_sixty_cycle_year().get_year());
+        assert_eq!(
+            child_limit,
+            ChildLimit::from_solar_time_with_context(
+                time,
+                Gender::MAN,
+                &CalendarContext::default().with_year_boundary(YearBoundary::LUNAR)
+            )
+        );
+    }
+
+    #[test]
+    fn test3() {
+        // 立春后、雨水前太阳在子宫（月将神后），与按月支推算的命宫一致
+        let time: SolarTime = SolarTime::from_ymd_hms(2024, 2, 10, 12, 0, 0);
+        let eight_char: EightChar = time.get_lunar_hour().get_eight_char();
+        assert_eq!("丙寅", eight_char.get_month().to_string());
+        assert_eq!("神后", time.get_month_general().to_string());
+        assert_eq!(
+            eight_char.get_own_sign(),
+            eight_char.get_own_sign_by_sun(time)
+        );
+        // 雨水后太阳入亥宫（月将登明），命宫随之退一宫
+        let time: SolarTime = SolarTime::from_ymd_hms(2024, 2, 25, 12, 0, 0);
+        let eight_char: EightChar = time.get_lunar_hour().get_eight_char();
+        assert_eq!("癸酉", eight_char.get_own_sign().to_string());
+        assert_eq!("壬申", eight_char.get_own_sign_by_sun(time).to_string());
+        // 卯时生者命宫即太阳所在宫
+        let time: SolarTime = SolarTime::from_ymd_hms(2024, 2, 25, 6, 0, 0);
+        let eight_char: EightChar = time.get_lunar_hour().get_eight_char();
+        assert_eq!(
+            "亥",
+            eight_char
+                .get_own_sign_by_sun(time)
+                .get_earth_branch()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test4() {
+        let eight_char: EightChar = SolarTime::from_ymd_hms(2024, 2, 10, 12, 0, 0)
+            .get_lunar_hour()
+            .get_eight_char();
+        let text: String = eight_char.to_compact_string();
+        assert_eq!("甲辰 丙寅 甲辰 庚午", text);
+        assert_eq!(eight_char, text.parse::<EightChar>().unwrap());
+        assert_eq!(
+            eight_char,
+            EightChar::from_str(" 甲辰  丙寅 甲辰 庚午 ").unwrap()
+        );
+
+        assert!(EightChar::from_str("甲辰 丙寅 甲辰").is_err());
+        assert!(EightChar::from_str("甲丑 丙寅 甲辰 庚午").is_err());
+        // 甲年寅月为丙寅，甲日午时为庚午
+        assert!(EightChar::from_str("甲辰 戊寅 甲辰 庚午").is_err());
+        assert!(EightChar::from_str("甲辰 丙寅 甲辰 壬午").is_err());
+        // 子月、丑月
+        assert!(EightChar::from_str("甲子 丙子 戊辰 壬子").is_ok());
+        assert!(EightChar::from_str("甲子 丁丑 戊辰 壬子").is_ok());
+        // 晚子时日柱不换日：甲日子时为甲子，按次日乙日为丙子，两者都接受，其他不接受
+        assert!(EightChar::from_str("甲辰 丙寅 甲辰 丙子").is_ok());
+        assert!(EightChar::from_str("甲辰 丙寅 甲辰 戊子").is_err());
+    }
+
+    #[test]
+    fn test5() {
+        // 跨年、跨立春、跨节令、晚子时的时刻，乱序输入
+        let mut times: Vec<SolarTime> = Vec::new();
+        let mut t: SolarTime = SolarTime::from_ymd_hms(2023, 12, 6, 22, 17, 0);
+        while t.is_before(SolarTime::from_ymd_hms(2024, 3, 8, 0, 0, 0)) {
+            times.push(t);
+            t = t.next(37 * 3571);
+        }
+        for (i, h) in [23, 0, 16].iter().enumerate() {
+            times.push(SolarTime::from_ymd_hms(2024, 2, 4 + i, *h, 26, 53));
+        }
+        times.push(SolarTime::from_ymd_hms(2024, 2, 4, 16, 26, 52));
+        times.reverse();
+        for context in [
+            CalendarContext::default(),
+            CalendarContext::default()
+                .with_zi_shi(ZiShiPolicy::CURRENT)
+                .with_year_boundary(YearBoundary::LUNAR),
+            CalendarContext::default().with_spring_switch(SpringSwitch::DAY),
+        ] {
+            let l: Vec<EightChar> = EightChar::batch_with_context(&times, &context);
+            assert_eq!(times.len(), l.len());
+            for (time, eight_char) in times.iter().zip(l.iter()) {
+                assert_eq!(
+                    time.get_lunar_hour().get_eight_char_with_context(&context),
+                    *eight_char,
+                    "{}",
+                    time
+                );
+            }
+        }
+        assert!(EightChar::batch(&[]).is_empty());
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test7() {
+        // 跨多年、单年超过一块的乱序时刻
+        let mut times: Vec<SolarTime> = Vec::new();
+        let mut t: SolarTime = SolarTime::from_ymd_hms(2019, 1, 1, 0, 0, 0);
+        while t.is_before(SolarTime::from_ymd_hms(2026, 1, 1, 0, 0, 0)) {
+            times.push(t);
+            t = t.next(12 * 3600 + 7);
+        }
+        for i in 0..5000 {
+            times.push(SolarTime::from_ymd_hms(2024, 2, 4, 16, 26, 52).next(i * 61));
+        }
+        times.reverse();
+        let context: CalendarContext = CalendarContext::default().with_zi_shi(ZiShiPolicy::CURRENT);
+        assert_eq!(
+            EightChar::batch_with_context(&times, &context),
+            EightChar::par_batch_with_context(&times, &context)
+        );
+        assert_eq!(EightChar::batch(&times), EightChar::par_batch(&times));
+        assert!(EightChar::par_batch(&[]).is_empty());
+    }
+
+    #[test]
+    fn test6() {
+        // 农历2023年闰二月十一 = 2023-04-01
+        assert_eq!(
+            "癸卯 乙卯 己丑 丙寅",
+            from_lunar(2023, -2, 11, 4, 30, ZiShiPolicy::NEXT)
+                .unwrap()
+                .to_string()
+        );
+        // 农历2023年腊月三十 23:30 = 2024-02-09 23:30，晚子时
+        assert_eq!(
+            "甲辰 丙寅 甲辰 甲子",
+            from_lunar(2023, 12, 30, 23, 30, ZiShiPolicy::NEXT)
+                .unwrap()
+                .to_string()
+        );
+        assert_eq!(
+            "甲辰 丙寅 癸卯 甲子",
+            from_lunar(2023, 12, 30, 23, 30, ZiShiPolicy::CURRENT)
+                .unwrap()
+                .to_string()
+        );
+        assert_eq!(
+            "illegal leap month 2 in lunar year 2024",
+            from_lunar(2024, -2, 1, 0, 0, ZiShiPolicy::NEXT).unwrap_err()
+        );
+        assert!(from_lunar(2024, 2, 31, 0, 0, ZiShiPolicy::NEXT).is_err());
+        assert!(from_lunar(2024, 2, 1, 24, 0, ZiShiPolicy::NEXT).is_err());
+        assert!(from_lunar(2024, 2, 1, 23, 60, ZiShiPolicy::NEXT).is_err());
+    }
+
+    #[test]
+    fn test8() {
+        // 2024年立春交节于2月4日16:27:07
+        let instant: CalendarContext = CalendarContext::default()
+            .with_zi_shi(ZiShiPolicy::NEXT)
+            .with_year_boundary(YearBoundary::SPRING);
+        let day: CalendarContext = instant.with_spring_switch(SpringSwitch::DAY);
+        let cases = [
+            ((2, 3, 23, 59, 59), "癸卯 乙丑", "癸卯 乙丑"),
+            ((2, 4, 0, 0, 0), "癸卯 乙丑", "甲辰 丙寅"),
+            ((2, 4, 16, 26, 7), "癸卯 乙丑", "甲辰 丙寅"),
+            ((2, 4, 16, 27, 6), "癸卯 乙丑", "甲辰 丙寅"),
+            ((2, 4, 16, 27, 7), "甲辰 丙寅", "甲辰 丙寅"),
+            ((2, 4, 16, 28, 7), "甲辰 丙寅", "甲辰 丙寅"),
+            ((2, 5, 0, 0, 0), "甲辰 丙寅", "甲辰 丙寅"),
+        ];
+        let mut times: Vec<SolarTime> = Vec::new();
+        for ((month, d, hour, minute, second), expected_instant, expected_day) in cases {
+            let time: SolarTime = SolarTime::from_ymd_hms(2024, month, d, hour, minute, second);
+            times.push(time);
+            for (context, expected) in [(&instant, expected_instant), (&day, expected_day)] {
+                let eight_char: EightChar =
+                    time.get_lunar_hour().get_eight_char_with_context(context);
+                assert_eq!(
+                    expected,
+                    format!("{} {}", eight_char.get_year(), eight_char.get_month()),
+                    "{} {}",
+                    time,
+                    context.get_spring_switch()
+                );
+            }
+        }
+        // 年柱以正月初一为界时（2024年春节为2月10日）年柱为癸卯，月干随之按五虎遁为甲
+        let eight_char: EightChar = times[2]
+            .get_lunar_hour()
+            .get_eight_char_with_context(&day.with_year_boundary(YearBoundary::LUNAR));
+        assert_eq!(
+            "癸卯 甲寅",
+            format!("{} {}", eight_char.get_year(), eight_char.get_month())
+        );
+        for context in [instant, day] {
+            let l: Vec<EightChar> = EightChar::batch_with_context(&times, &context);
+            for (time, eight_char) in times.iter().zip(l.iter()) {
+                assert_eq!(
+                    time.get_lunar_hour().get_eight_char_with_context(&context),
+                    *eight_char
+                );
+            }
+        }
+    }
+
+    #[cfg(feature = "eight-char-default-provider")]
+    #[test]
+    fn test9() {
+        // 立春与春节之间逐时辰取八字，年柱以正月初一为界时仍可由from_str解析
+        let service: EightCharService<DefaultEightCharProvider> =
+            EightCharService::with_year_boundary(
+                DefaultEightCharProvider::new(),
+                YearBoundary::LUNAR,
+            );
+        for year in [2023, 2024, 2025, 2026] {
+            let mut time: SolarTime = SolarTime::from_ymd_hms(year, 1, 20, 0, 0, 0);
+            while time.get_month() < 3 {
+                let eight_char: EightChar = service.get_eight_char(time.get_lunar_hour());
+                assert_eq!(
+                    Ok(eight_char.clone()),
+                    EightChar::from_str(&eight_char.to_string()),
+                    "{}",
+                    time
+                );
+                time = time.next(7200);
+            }
+        }
+    }
+
+    #[test]
+    fn test10() {
+        // 批量计算与逐个调用get_eight_char一致（随八字提供器、年柱分界特性变化）
+        let mut times: Vec<SolarTime> = Vec::new();
+        let mut t: SolarTime = SolarTime::from_ymd_hms(2024, 1, 25, 23, 30, 0);
+        while t.is_before(SolarTime::from_ymd_hms(2024, 2, 15, 0, 0, 0)) {
+            times.push(t);
+            t = t.next(5 * 3600);
+        }
+        times.push(SolarTime::from_ymd_hms(2024, 2, 9, 23, 10, 0));
+        let l: Vec<EightChar> = EightChar::batch(&times);
+        for (time, eight_char) in times.iter().zip(l.iter()) {
+            assert_eq!(
+                time.get_lunar_hour().get_eight_char(),
+                *eight_char,
+                "{}",
+                time
+            );
+        }
+        #[cfg(feature = "parallel")]
+        assert_eq!(l, EightChar::par_batch(&times));
+    }
+
+    /// 自定义提供器：时柱取日柱，不给出等效上下文
+    struct DayAsHourProvider {}
+
+    impl EightCharProvider for DayAsHourProvider {
+        fn get_eight_char(&self, hour: LunarHour) -> EightChar {
+            let e: EightChar = hour.get_sixty_cycle_hour().get_eight_char();
+            EightChar::from_sixty_cycle(e.get_year(), e.get_month(), e.get_day(), e.get_day())
+        }
+    }
+
+    #[test]
+    fn test11() {
+        // 提供器未给出上下文时，批量计算逐个调用其get_eight_char
+        let service: EightCharService<DayAsHourProvider> =
+            EightCharService::new(DayAsHourProvider {});
+        assert!(service.get_context().is_none());
+        let times: Vec<SolarTime> = (0..48)
+            .map(|i| SolarTime::from_ymd_hms(2024, 2, 4, 0, 30, 0).next(i * 3600))
+            .collect();
+        let l: Vec<EightChar> = service.batch(&times);
+        for (time, eight_char) in times.iter().zip(l.iter()) {
+            assert_eq!(service.get_eight_char(time.get_lunar_hour()), *eight_char);
+        }
+        assert_ne!(
+            l,
+            EightChar::batch_with_context(&times, &CalendarContext::default())
+        );
+        #[cfg(feature = "parallel")]
+        assert_eq!(l, service.par_batch(&times));
+    }
+
+    #[test]
+    fn test12() {
+        // 晚子时（23点）按两种子时归日的八字都能往返解析
+        let context: CalendarContext = CalendarContext::default().with_zi_shi(ZiShiPolicy::CURRENT);
+        let mut day: SolarDay = SolarDay::from_ymd(2024, 1, 1);
+        for _ in 0..60 {
+            let hour: LunarHour =
+                SolarTime::from_ymd_hms(day.get_year(), day.get_month(), day.get_day(), 23, 30, 0)
+                    .get_lunar_hour();
+            for eight_char in [
+                hour.get_eight_char_with_context(&context),
+                hour.get_eight_char_with_context(&context.with_zi_shi(ZiShiPolicy::NEXT)),
+            ] {
+                let text: String = eight_char.to_compact_string();
+                assert_eq!(eight_char, text.parse::<EightChar>().unwrap(), "{}", text);
+            }
+            day = day.next(1);
+        }
+    }
 }
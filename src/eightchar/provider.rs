@@ -1,9 +1,16 @@
 use core::marker::PhantomData;
 
-use crate::eightchar::{ChildLimitInfo, EightChar};
-use crate::lunar::LunarHour;
-#[cfg(feature = "eight-char-lunar-sect2-provider")]
-use crate::sixtycycle::SixtyCycleHour;
+use alloc::vec::Vec;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::context::{CalendarContext, DEFAULT_YEAR_BOUNDARY};
+use crate::eightchar::{ChildLimit, ChildLimitInfo, EightChar, get_sixty_cycle_year};
+use crate::enums::{Gender, SpringSwitch, YearBoundary, ZiShiPolicy};
+use crate::lunar::{LunarHour, LunarYear};
+#[cfg(feature = "child-limit-lunar-sect1-provider")]
+use crate::sixtycycle::EarthBranch;
+use crate::sixtycycle::{SixtyCycle, SixtyCycleHour, SixtyCycleYear};
 use crate::solar::{SolarMonth, SolarTerm, SolarTime};
 use crate::types::Tyme;
 
@@ -15,6 +22,12 @@ pub trait ChildLimitProvider {
 /// 八字计算接口
 pub trait EightCharProvider {
     fn get_eight_char(&self, hour: LunarHour) -> EightChar;
+
+    /// 与本提供器等效的配置上下文（晚子时取法、立春当天换年取法），批量计算据此复用各柱；
+    /// 返回None（默认）时批量计算逐个调用get_eight_char
+    fn get_context(&self) -> Option<CalendarContext> {
+        None
+    }
 }
 
 /// 默认的八字计算（晚子时算第二天）
@@ -34,6 +47,10 @@ impl EightCharProvider for DefaultEightCharProvider {
     fn get_eight_char(&self, hour: LunarHour) -> EightChar {
         hour.get_sixty_cycle_hour().get_eight_char()
     }
+
+    fn get_context(&self) -> Option<CalendarContext> {
+        Some(CalendarContext::default().with_zi_shi(ZiShiPolicy::NEXT))
+    }
 }
 
 /// Lunar流派2的八字计算（晚子时日柱算当天）
@@ -59,6 +76,42 @@ impl EightCharProvider for LunarSect2EightCharProvider {
             h.get_sixty_cycle(),
         )
     }
+
+    fn get_context(&self) -> Option<CalendarContext> {
+        Some(CalendarContext::default().with_zi_shi(ZiShiPolicy::CURRENT))
+    }
+}
+
+/// 按配置上下文的八字计算（晚子时取法、立春当天换年取法），年柱分界由EightCharService处理
+#[derive(Debug, Copy, Clone)]
+pub struct ContextEightCharProvider {
+    context: CalendarContext,
+}
+
+impl ContextEightCharProvider {
+    pub const fn new(context: CalendarContext) -> Self {
+        Self { context }
+    }
+}
+
+impl EightCharProvider for ContextEightCharProvider {
+    fn get_eight_char(&self, hour: LunarHour) -> EightChar {
+        let h: SixtyCycleHour = match self.context.get_spring_switch() {
+            SpringSwitch::INSTANT => hour.get_sixty_cycle_hour(),
+            SpringSwitch::DAY => {
+                SixtyCycleHour::from_solar_time_with_context(hour.get_solar_time(), &self.context)
+            }
+        };
+        let day: SixtyCycle = match self.context.get_zi_shi() {
+            ZiShiPolicy::NEXT => h.get_day(),
+            ZiShiPolicy::CURRENT => hour.get_lunar_day().get_sixty_cycle(),
+        };
+        EightChar::from_sixty_cycle(h.get_year(), h.get_month(), day, h.get_sixty_cycle())
+    }
+
+    fn get_context(&self) -> Option<CalendarContext> {
+        Some(self.context)
+    }
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -228,15 +281,16 @@ impl ChildLimitProvider for LunarSect1ChildLimitProvider {
             end = birth_time;
             start = term_time;
         }
+        // 时辰序号，23点按11计
         let end_time_zhi_index: usize = if end.get_hour() == 23 {
             11
         } else {
-            end.get_lunar_hour().get_index_in_day()
+            EarthBranch::from_hour(end.get_hour()).get_index()
         };
         let start_time_zhi_index: usize = if start.get_hour() == 23 {
             11
         } else {
-            start.get_lunar_hour().get_index_in_day()
+            EarthBranch::from_hour(start.get_hour()).get_index()
         };
         // 时辰差
         let mut hour_diff: isize = end_time_zhi_index as isize - start_time_zhi_index as isize;
@@ -305,31 +359,102 @@ impl ChildLimitProvider for LunarSect2ChildLimitProvider {
 // 八字服务
 pub struct EightCharService<P: EightCharProvider> {
     provider: P,
+    year_boundary: YearBoundary,
     _marker: PhantomData<P>,
 }
 
 impl<P: EightCharProvider> EightCharService<P> {
     pub const fn new(provider: P) -> Self {
+        Self::with_year_boundary(provider, YearBoundary::SPRING)
+    }
+
+    pub const fn with_year_boundary(provider: P, year_boundary: YearBoundary) -> Self {
         Self {
             provider,
+            year_boundary,
             _marker: PhantomData,
         }
     }
 
-    /// 获取八字
+    /// 年柱分界
+    pub fn get_year_boundary(&self) -> YearBoundary {
+        self.year_boundary
+    }
+
+    /// 获取八字，年柱以正月初一为界时月干随年干重排（五虎遁）
     pub fn get_eight_char(&self, lunar: LunarHour) -> EightChar {
-        self.provider.get_eight_char(lunar)
+        let eight_char: EightChar = self.provider.get_eight_char(lunar.clone());
+        match self.year_boundary {
+            YearBoundary::SPRING => eight_char,
+            YearBoundary::LUNAR => {
+                eight_char.with_year(LunarYear::from_year(lunar.get_year()).get_sixty_cycle())
+            }
+        }
+    }
+
+    /// 与本服务等效的配置上下文（提供器的取法及本服务的年柱分界），提供器未给出时为None
+    pub fn get_context(&self) -> Option<CalendarContext> {
+        self.provider
+            .get_context()
+            .map(|context| context.with_year_boundary(self.year_boundary))
+    }
+
+    /// 批量计算八字，等同逐个调用get_eight_char；提供器给出等效上下文时按batch_with_context复用各柱
+    pub fn batch(&self, birth_times: &[SolarTime]) -> Vec<EightChar> {
+        match self.get_context() {
+            Some(context) => EightChar::batch_with_context(birth_times, &context),
+            None => birth_times
+                .iter()
+                .map(|time| self.get_eight_char(time.get_lunar_hour()))
+                .collect(),
+        }
+    }
+
+    /// 并行批量计算八字，等同逐个调用get_eight_char；提供器未给出等效上下文时逐个并行调用get_eight_char
+    #[cfg(feature = "parallel")]
+    pub fn par_batch(&self, birth_times: &[SolarTime]) -> Vec<EightChar>
+    where
+        P: Sync,
+    {
+        match self.get_context() {
+            Some(context) => EightChar::par_batch_with_context(birth_times, &context),
+            None => birth_times
+                .par_iter()
+                .map(|time| self.get_eight_char(time.get_lunar_hour()))
+                .collect(),
+        }
+    }
+
+    /// 按本服务的八字及年柱分界计算童限
+    pub fn get_child_limit(&self, birth_time: SolarTime, gender: Gender) -> ChildLimit {
+        ChildLimit::from_eight_char(
+            birth_time,
+            gender,
+            self.get_eight_char(birth_time.get_lunar_hour()),
+            self.year_boundary,
+        )
+    }
+
+    /// 用于计算童限、大运、流年的干支年
+    pub fn get_sixty_cycle_year(&self, time: SolarTime) -> SixtyCycleYear {
+        get_sixty_cycle_year(time, self.year_boundary)
     }
 }
 
-// 八字全局静态实例
-#[cfg(feature = "eight-char-default-provider")]
+// 八字年柱分界
+const YEAR_BOUNDARY: YearBoundary = DEFAULT_YEAR_BOUNDARY;
+
+// 八字全局静态实例（同时启用时农历分节提供器优先）
+#[cfg(all(
+    feature = "eight-char-default-provider",
+    not(feature = "eight-char-lunar-sect2-provider")
+))]
 pub static EIGHT_CHAR_PROVIDER: EightCharService<DefaultEightCharProvider> =
-    EightCharService::new(DefaultEightCharProvider::new());
+    EightCharService::with_year_boundary(DefaultEightCharProvider::new(), YEAR_BOUNDARY);
 
 #[cfg(feature = "eight-char-lunar-sect2-provider")]
 pub static EIGHT_CHAR_PROVIDER: EightCharService<LunarSect2EightCharProvider> =
-    EightCharService::new(LunarSect2EightCharProvider::new());
+    EightCharService::with_year_boundary(LunarSect2EightCharProvider::new(), YEAR_BOUNDARY);
 
 // 童限服务
 pub struct ChildLimitService<P: ChildLimitProvider> {
@@ -0,0 +1,59 @@
+//! 英文别名
+
+use crate::culture::{Element, Zodiac};
+use crate::lunar::{LunarDay, LunarHour, LunarMonth, LunarYear};
+use crate::sixtycycle::{EarthBranch, HeavenStem, SixtyCycle};
+
+/// 天干
+pub type HeavenlyStem = HeavenStem;
+
+/// 地支
+pub type EarthlyBranch = EarthBranch;
+
+/// 干支
+pub type SexagenaryCycle = SixtyCycle;
+
+/// 生肖
+pub type ChineseZodiac = Zodiac;
+
+/// 五行
+pub type FiveElement = Element;
+
+/// 农历年
+pub type ChineseYear = LunarYear;
+
+/// 农历月
+pub type ChineseMonth = LunarMonth;
+
+/// 农历日
+pub type ChineseDay = LunarDay;
+
+/// 时辰
+pub type ChineseHour = LunarHour;
+
+#[cfg(test)]
+mod tests {
+    use crate::culture::Zodiac;
+    use crate::en::{ChineseMonth, SexagenaryCycle};
+    use crate::lunar::LunarMonth;
+    use crate::solar::{SolarDay, SolarTerm};
+    use crate::types::Culture;
+
+    #[test]
+    fn test1() {
+        assert_eq!("Start of Spring", SolarTerm::from_index(2024, 3).name_en());
+        assert_eq!("Dragon", Zodiac::from_name("龙").name_en());
+        assert_eq!("Month 6 (leap)", LunarMonth::from_ym(2025, -6).name_en());
+        assert_eq!("Month 1", ChineseMonth::from_ym(2025, 1).name_en());
+        assert_eq!("Jia-Zi", SexagenaryCycle::from_index(0).name_en());
+        assert_eq!(
+            "Wednesday",
+            SolarDay::from_ymd(2025, 1, 1).get_week().name_en()
+        );
+        assert_eq!(
+            "Capricorn",
+            SolarDay::from_ymd(2025, 1, 1).get_constellation().name_en()
+        );
+        assert_eq!("闰六月", LunarMonth::from_ym(2025, -6).get_name());
+    }
+}
@@ -191,6 +191,54 @@ impl PartialEq for Side {
 
 impl Eq for Side {}
 
+/// 八字年柱分界
+#[derive(Debug, Copy, Clone)]
+pub enum YearBoundary {
+    /// 立春
+    SPRING,
+    /// 正月初一
+    LUNAR,
+}
+
+impl YearBoundary {
+    pub fn from_code(code: usize) -> Result<Self, String> {
+        match code {
+            0 => Ok(Self::SPRING),
+            1 => Ok(Self::LUNAR),
+            _ => Err(format!("illegal YearBoundary code: {}", code)),
+        }
+    }
+
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        match name {
+            "立春" => Ok(Self::SPRING),
+            "正月初一" => Ok(Self::LUNAR),
+            _ => Err(format!("illegal YearBoundary name: {}", name)),
+        }
+    }
+
+    pub fn get_name(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl Display for YearBoundary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::SPRING => write!(f, "{}", "立春"),
+            Self::LUNAR => write!(f, "{}", "正月初一"),
+        }
+    }
+}
+
+impl PartialEq for YearBoundary {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
+impl Eq for YearBoundary {}
+
 #[derive(Debug, Copy, Clone)]
 pub enum YinYang {
     YIN,
@@ -236,6 +284,579 @@ impl PartialEq for YinYang {
 
 impl Eq for YinYang {}
 
+/// 交节、朔望时刻的计算精度
+#[derive(Debug, Copy, Clone)]
+pub enum Precision {
+    /// 低精度（最快，节气误差半小时内，朔望误差可达2小时）
+    FAST,
+    /// 标准（截断级数，误差通常在1分钟内）
+    STANDARD,
+    /// 高精度（完整级数，误差数秒，最慢）
+    HIGH,
+}
+
+impl Precision {
+    pub fn from_code(code: usize) -> Result<Self, String> {
+        match code {
+            0 => Ok(Self::FAST),
+            1 => Ok(Self::STANDARD),
+            2 => Ok(Self::HIGH),
+            _ => Err(format!("illegal Precision code: {}", code)),
+        }
+    }
+
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        match name {
+            "低精度" => Ok(Self::FAST),
+            "标准" => Ok(Self::STANDARD),
+            "高精度" => Ok(Self::HIGH),
+            _ => Err(format!("illegal Precision name: {}", name)),
+        }
+    }
+
+    pub fn get_name(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl Display for Precision {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::FAST => write!(f, "{}", "低精度"),
+            Self::STANDARD => write!(f, "{}", "标准"),
+            Self::HIGH => write!(f, "{}", "高精度"),
+        }
+    }
+}
+
+impl PartialEq for Precision {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
+impl Eq for Precision {}
+
+/// 晚子时（23:00-24:00）日柱的取法
+#[derive(Debug, Copy, Clone)]
+pub enum ZiShiPolicy {
+    /// 晚子时日柱算第二天
+    NEXT,
+    /// 晚子时日柱算当天
+    CURRENT,
+}
+
+impl ZiShiPolicy {
+    pub fn from_code(code: usize) -> Result<Self, String> {
+        match code {
+            0 => Ok(Self::NEXT),
+            1 => Ok(Self::CURRENT),
+            _ => Err(format!("illegal ZiShiPolicy code: {}", code)),
+        }
+    }
+
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        match name {
+            "晚子时算次日" => Ok(Self::NEXT),
+            "晚子时算当日" => Ok(Self::CURRENT),
+            _ => Err(format!("illegal ZiShiPolicy name: {}", name)),
+        }
+    }
+
+    pub fn get_name(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl Display for ZiShiPolicy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NEXT => write!(f, "{}", "晚子时算次日"),
+            Self::CURRENT => write!(f, "{}", "晚子时算当日"),
+        }
+    }
+}
+
+impl PartialEq for ZiShiPolicy {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
+impl Eq for ZiShiPolicy {}
+
+/// 立春当天（交节时刻之前）年柱、月柱的取法
+#[derive(Debug, Copy, Clone)]
+pub enum SpringSwitch {
+    /// 按立春交节时刻换年，交节前仍为上一年
+    INSTANT,
+    /// 立春当天整日算新一年
+    DAY,
+}
+
+impl SpringSwitch {
+    pub fn from_code(code: usize) -> Result<Self, String> {
+        match code {
+            0 => Ok(Self::INSTANT),
+            1 => Ok(Self::DAY),
+            _ => Err(format!("illegal SpringSwitch code: {}", code)),
+        }
+    }
+
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        match name {
+            "交节时刻换年" => Ok(Self::INSTANT),
+            "立春当日换年" => Ok(Self::DAY),
+            _ => Err(format!("illegal SpringSwitch name: {}", name)),
+        }
+    }
+
+    pub fn get_name(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl Display for SpringSwitch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::INSTANT => write!(f, "{}", "交节时刻换年"),
+            Self::DAY => write!(f, "{}", "立春当日换年"),
+        }
+    }
+}
+
+impl PartialEq for SpringSwitch {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
+impl Eq for SpringSwitch {}
+
+/// 请求的闰月在该年不存在时的处理方式
+#[derive(Debug, Copy, Clone)]
+pub enum LeapFallbackPolicy {
+    /// 报错
+    ERROR,
+    /// 改为同名的常规月
+    REGULAR,
+    /// 改为同名常规月之后最近的闰月（可能在以后的年份）
+    NEXT,
+}
+
+impl LeapFallbackPolicy {
+    pub fn from_code(code: usize) -> Result<Self, String> {
+        match code {
+            0 => Ok(Self::ERROR),
+            1 => Ok(Self::REGULAR),
+            2 => Ok(Self::NEXT),
+            _ => Err(format!("illegal LeapFallbackPolicy code: {}", code)),
+        }
+    }
+
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        match name {
+            "报错" => Ok(Self::ERROR),
+            "取常规月" => Ok(Self::REGULAR),
+            "取后续闰月" => Ok(Self::NEXT),
+            _ => Err(format!("illegal LeapFallbackPolicy name: {}", name)),
+        }
+    }
+
+    pub fn get_name(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl Display for LeapFallbackPolicy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::ERROR => write!(f, "{}", "报错"),
+            Self::REGULAR => write!(f, "{}", "取常规月"),
+            Self::NEXT => write!(f, "{}", "取后续闰月"),
+        }
+    }
+}
+
+impl PartialEq for LeapFallbackPolicy {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
+impl Eq for LeapFallbackPolicy {}
+
+/// 名称语言
+#[derive(Debug, Copy, Clone)]
+pub enum Locale {
+    /// 中文
+    ZH,
+    /// 英文
+    EN,
+}
+
+impl Locale {
+    pub fn from_code(code: usize) -> Result<Self, String> {
+        match code {
+            0 => Ok(Self::ZH),
+            1 => Ok(Self::EN),
+            _ => Err(format!("illegal Locale code: {}", code)),
+        }
+    }
+
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        match name {
+            "中文" => Ok(Self::ZH),
+            "英文" => Ok(Self::EN),
+            _ => Err(format!("illegal Locale name: {}", name)),
+        }
+    }
+
+    pub fn get_name(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl Display for Locale {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::ZH => write!(f, "{}", "中文"),
+            Self::EN => write!(f, "{}", "英文"),
+        }
+    }
+}
+
+impl PartialEq for Locale {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
+impl Eq for Locale {}
+
+/// 日期时间字符串的解析模式
+#[derive(Debug, Copy, Clone)]
+pub enum ParseMode {
+    /// 严格：不允许多余空白，各项须在合法范围内
+    STRICT,
+    /// 宽松：忽略多余空白，24:00:00计为次日0时
+    LENIENT,
+}
+
+impl ParseMode {
+    pub fn from_code(code: usize) -> Result<Self, String> {
+        match code {
+            0 => Ok(Self::STRICT),
+            1 => Ok(Self::LENIENT),
+            _ => Err(format!("illegal ParseMode code: {}", code)),
+        }
+    }
+
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        match name {
+            "严格" => Ok(Self::STRICT),
+            "宽松" => Ok(Self::LENIENT),
+            _ => Err(format!("illegal ParseMode name: {}", name)),
+        }
+    }
+
+    pub fn get_name(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl Display for ParseMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::STRICT => write!(f, "{}", "严格"),
+            Self::LENIENT => write!(f, "{}", "宽松"),
+        }
+    }
+}
+
+impl PartialEq for ParseMode {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
+impl Eq for ParseMode {}
+
+/// 日历图层（导出、渲染时可选的信息类别）
+#[derive(Debug, Copy, Clone)]
+pub enum CalendarLayer {
+    /// 节气
+    TERM,
+    /// 农历初一
+    LUNAR,
+    /// 公历、农历节日
+    FESTIVAL,
+    /// 法定假日及调休
+    HOLIDAY,
+}
+
+impl CalendarLayer {
+    pub fn from_code(code: usize) -> Result<Self, String> {
+        match code {
+            0 => Ok(Self::TERM),
+            1 => Ok(Self::LUNAR),
+            2 => Ok(Self::FESTIVAL),
+            3 => Ok(Self::HOLIDAY),
+            _ => Err(format!("illegal CalendarLayer code: {}", code)),
+        }
+    }
+
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        match name {
+            "节气" => Ok(Self::TERM),
+            "农历初一" => Ok(Self::LUNAR),
+            "节日" => Ok(Self::FESTIVAL),
+            "法定假日" => Ok(Self::HOLIDAY),
+            _ => Err(format!("illegal CalendarLayer name: {}", name)),
+        }
+    }
+
+    pub fn get_name(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl Display for CalendarLayer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::TERM => write!(f, "{}", "节气"),
+            Self::LUNAR => write!(f, "{}", "农历初一"),
+            Self::FESTIVAL => write!(f, "{}", "节日"),
+            Self::HOLIDAY => write!(f, "{}", "法定假日"),
+        }
+    }
+}
+
+impl PartialEq for CalendarLayer {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
+impl Eq for CalendarLayer {}
+
+/// 四季的划分方法
+#[derive(Debug, Copy, Clone)]
+pub enum SeasonScheme {
+    /// 节气：立春、立夏、立秋、立冬起季
+    TERM,
+    /// 天文：春分、夏至、秋分、冬至起季
+    ASTRONOMICAL,
+    /// 气象：3至5月为春，6至8月为夏，9至11月为秋，12至次年2月为冬
+    METEOROLOGICAL,
+}
+
+impl SeasonScheme {
+    pub fn from_code(code: usize) -> Result<Self, String> {
+        match code {
+            0 => Ok(Self::TERM),
+            1 => Ok(Self::ASTRONOMICAL),
+            2 => Ok(Self::METEOROLOGICAL),
+            _ => Err(format!("illegal SeasonScheme code: {}", code)),
+        }
+    }
+
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        match name {
+            "节气" => Ok(Self::TERM),
+            "天文" => Ok(Self::ASTRONOMICAL),
+            "气象" => Ok(Self::METEOROLOGICAL),
+            _ => Err(format!("illegal SeasonScheme name: {}", name)),
+        }
+    }
+
+    pub fn get_name(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl Display for SeasonScheme {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::TERM => write!(f, "{}", "节气"),
+            Self::ASTRONOMICAL => write!(f, "{}", "天文"),
+            Self::METEOROLOGICAL => write!(f, "{}", "气象"),
+        }
+    }
+}
+
+impl PartialEq for SeasonScheme {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
+impl Eq for SeasonScheme {}
+
+/// 半球
+#[derive(Debug, Copy, Clone)]
+pub enum Hemisphere {
+    /// 北半球
+    NORTH,
+    /// 南半球
+    SOUTH,
+}
+
+impl Hemisphere {
+    pub fn from_code(code: usize) -> Result<Self, String> {
+        match code {
+            0 => Ok(Self::NORTH),
+            1 => Ok(Self::SOUTH),
+            _ => Err(format!("illegal Hemisphere code: {}", code)),
+        }
+    }
+
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        match name {
+            "北半球" => Ok(Self::NORTH),
+            "南半球" => Ok(Self::SOUTH),
+            _ => Err(format!("illegal Hemisphere name: {}", name)),
+        }
+    }
+
+    pub fn get_name(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl Display for Hemisphere {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NORTH => write!(f, "{}", "北半球"),
+            Self::SOUTH => write!(f, "{}", "南半球"),
+        }
+    }
+}
+
+impl PartialEq for Hemisphere {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
+impl Eq for Hemisphere {}
+
+/// 数据来源
+#[derive(Debug, Copy, Clone)]
+pub enum Projection {
+    /// 内置数据
+    EXACT,
+    /// 超出内置数据范围，按规则推算
+    PROJECTED,
+}
+
+impl Projection {
+    pub fn from_code(code: usize) -> Result<Self, String> {
+        match code {
+            0 => Ok(Self::EXACT),
+            1 => Ok(Self::PROJECTED),
+            _ => Err(format!("illegal Projection code: {}", code)),
+        }
+    }
+
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        match name {
+            "精确" => Ok(Self::EXACT),
+            "推算" => Ok(Self::PROJECTED),
+            _ => Err(format!("illegal Projection name: {}", name)),
+        }
+    }
+
+    pub fn get_name(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl Display for Projection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::EXACT => write!(f, "{}", "精确"),
+            Self::PROJECTED => write!(f, "{}", "推算"),
+        }
+    }
+}
+
+impl PartialEq for Projection {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
+impl Eq for Projection {}
+
+/// 日历事件类别
+#[derive(Debug, Copy, Clone)]
+pub enum EventKind {
+    /// 法定假日（含调休上班）
+    HOLIDAY,
+    /// 公历现代节日
+    SOLAR,
+    /// 农历传统节日
+    LUNAR,
+    /// 交节
+    TERM,
+    /// 月相
+    PHASE,
+    /// 自定义事件
+    CUSTOM,
+}
+
+impl EventKind {
+    pub fn from_code(code: usize) -> Result<Self, String> {
+        match code {
+            0 => Ok(Self::HOLIDAY),
+            1 => Ok(Self::SOLAR),
+            2 => Ok(Self::LUNAR),
+            3 => Ok(Self::TERM),
+            4 => Ok(Self::PHASE),
+            5 => Ok(Self::CUSTOM),
+            _ => Err(format!("illegal EventKind code: {}", code)),
+        }
+    }
+
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        match name {
+            "法定假日" => Ok(Self::HOLIDAY),
+            "公历节日" => Ok(Self::SOLAR),
+            "农历节日" => Ok(Self::LUNAR),
+            "交节" => Ok(Self::TERM),
+            "月相" => Ok(Self::PHASE),
+            "自定义" => Ok(Self::CUSTOM),
+            _ => Err(format!("illegal EventKind name: {}", name)),
+        }
+    }
+
+    pub fn get_name(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl Display for EventKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::HOLIDAY => write!(f, "{}", "法定假日"),
+            Self::SOLAR => write!(f, "{}", "公历节日"),
+            Self::LUNAR => write!(f, "{}", "农历节日"),
+            Self::TERM => write!(f, "{}", "交节"),
+            Self::PHASE => write!(f, "{}", "月相"),
+            Self::CUSTOM => write!(f, "{}", "自定义"),
+        }
+    }
+}
+
+impl PartialEq for EventKind {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
+impl Eq for EventKind {}
+
 #[cfg(test)]
 mod tests {
     use crate::enums::Gender;
@@ -0,0 +1,429 @@
+use core::fmt::{Display, Formatter};
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::generated_era_data::ERA_TABLE;
+use crate::lunar::{LUNAR_DAY_NAMES, LUNAR_MONTH_NAMES, LunarDay, LunarMonth};
+use crate::sixtycycle::SIXTY_CYCLE_NAMES;
+use crate::solar::SolarDay;
+use crate::types::Culture;
+
+/// 中文数字
+static NUMBER_NAMES: [&str; 10] = ["〇", "一", "二", "三", "四", "五", "六", "七", "八", "九"];
+
+/// 年号
+#[derive(Debug, Copy, Clone)]
+pub struct Era {
+    /// 年号表索引
+    index: usize,
+}
+
+impl Culture for Era {
+    fn get_name(&self) -> String {
+        ERA_TABLE[self.index].name.to_string()
+    }
+}
+
+impl Era {
+    /// 年号名称，重名时可加朝代前缀区分（如：唐上元），否则取最早的
+    pub fn new(name: &str) -> Result<Self, String> {
+        match Self::match_prefix(name) {
+            Some((era, size)) if size == name.len() => Ok(era),
+            _ => Err(format!("illegal era name: {}", name)),
+        }
+    }
+
+    pub fn from_name(name: &str) -> Self {
+        Self::new(name).unwrap()
+    }
+
+    /// 朝代
+    pub fn get_dynasty(&self) -> String {
+        ERA_TABLE[self.index].dynasty.to_string()
+    }
+
+    /// 元年对应的农历年
+    pub fn get_start_year(&self) -> isize {
+        ERA_TABLE[self.index].start_year as isize
+    }
+
+    /// 年数
+    pub fn get_year_count(&self) -> usize {
+        ERA_TABLE[self.index].year_count as usize
+    }
+
+    /// 第n年（元年为1）对应的农历年
+    pub fn get_year(&self, n: usize) -> Result<isize, String> {
+        if n < 1 || n > self.get_year_count() {
+            Err(format!("illegal year {} of era {}", n, self.get_name()))
+        } else {
+            Ok(self.get_start_year() + n as isize - 1)
+        }
+    }
+
+    /// 匹配文本开头的年号（可带朝代前缀），返回年号及匹配的字节数
+    fn match_prefix(text: &str) -> Option<(Self, usize)> {
+        let mut matched: Option<(Self, usize)> = None;
+        for (index, entry) in ERA_TABLE.iter().enumerate() {
+            let size: usize = if text.starts_with(entry.dynasty)
+                && text[entry.dynasty.len()..].starts_with(entry.name)
+            {
+                entry.dynasty.len() + entry.name.len()
+            } else if text.starts_with(entry.name) {
+                entry.name.len()
+            } else {
+                continue;
+            };
+            if matched.is_none_or(|(_, n)| size > n) {
+                matched = Some((Self { index }, size));
+            }
+        }
+        matched
+    }
+}
+
+impl Display for Era {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.get_name())
+    }
+}
+
+impl PartialEq for Era {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index
+    }
+}
+
+impl Eq for Era {}
+
+/// 年号纪年
+#[derive(Debug, Copy, Clone)]
+pub struct EraYear {
+    /// 年号
+    era: Era,
+    /// 年号第几年（元年为1）
+    year: usize,
+}
+
+impl Culture for EraYear {
+    fn get_name(&self) -> String {
+        let year: String = if self.year == 1 {
+            "元".to_string()
+        } else {
+            number_name(self.year)
+        };
+        format!("{}{}年", self.era, year)
+    }
+}
+
+impl EraYear {
+    pub fn new(era: Era, year: usize) -> Result<Self, String> {
+        era.get_year(year)?;
+        Ok(Self { era, year })
+    }
+
+    pub fn from_era_year(era: Era, year: usize) -> Self {
+        Self::new(era, year).unwrap()
+    }
+
+    /// 公历年对应的年号纪年（改元之年及并立政权会有多个）
+    pub fn from_solar_year(year: isize) -> Vec<Self> {
+        let mut l: Vec<Self> = Vec::new();
+        for (index, entry) in ERA_TABLE.iter().enumerate() {
+            let n: isize = year - entry.start_year as isize + 1;
+            if n >= 1 && n <= entry.year_count as isize {
+                l.push(Self {
+                    era: Era { index },
+                    year: n as usize,
+                });
+            }
+        }
+        l
+    }
+
+    /// 年号
+    pub fn get_era(&self) -> Era {
+        self.era
+    }
+
+    /// 年号第几年（元年为1）
+    pub fn get_year(&self) -> usize {
+        self.year
+    }
+
+    /// 公历年
+    pub fn get_solar_year(&self) -> isize {
+        self.era.get_start_year() + self.year as isize - 1
+    }
+}
+
+impl Display for EraYear {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.get_name())
+    }
+}
+
+impl PartialEq for EraYear {
+    fn eq(&self, other: &Self) -> bool {
+        self.era == other.era && self.year == other.year
+    }
+}
+
+impl Eq for EraYear {}
+
+/// 年号纪日（如：康熙五十年冬月甲子日）
+#[derive(Debug, Clone)]
+pub struct EraDay {
+    /// 年号纪年
+    era_year: EraYear,
+    /// 农历日
+    lunar_day: LunarDay,
+}
+
+impl Culture for EraDay {
+    fn get_name(&self) -> String {
+        format!(
+            "{}{}{}",
+            self.era_year,
+            self.lunar_day.get_lunar_month().get_name(),
+            self.lunar_day.get_name()
+        )
+    }
+}
+
+impl EraDay {
+    /// 解析年号纪日，日可为初一至三十、朔或干支日，省略日时为初一
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let (era, size) = Era::match_prefix(text).ok_or(format!("illegal era day: {}", text))?;
+        let rest: &str = &text[size..];
+
+        let (year_text, rest) = rest
+            .split_once('年')
+            .ok_or(format!("illegal era day: {}", text))?;
+        let year: usize = if year_text == "元" {
+            1
+        } else {
+            parse_number(year_text).ok_or(format!("illegal era year: {}", year_text))?
+        };
+        let era_year: EraYear = EraYear::new(era, year)?;
+        let lunar_year: isize = era_year.get_solar_year();
+
+        let (leap, rest) = match rest.strip_prefix('闰') {
+            Some(r) => (true, r),
+            None => (false, rest),
+        };
+        let (month_text, day_text) = rest
+            .split_once('月')
+            .ok_or(format!("illegal era day: {}", text))?;
+        let month_name: String = format!("{}月", month_text);
+        let month: usize = match LUNAR_MONTH_NAMES.iter().position(|x| *x == month_name) {
+            Some(i) => i + 1,
+            None => parse_number(month_text).ok_or(format!("illegal month: {}", month_name))?,
+        };
+        let lunar_month: LunarMonth = LunarMonth::new(
+            lunar_year,
+            if leap {
+                -(month as isize)
+            } else {
+                month as isize
+            },
+        )?;
+
+        let day_text: &str = day_text.strip_suffix('日').unwrap_or(day_text);
+        let lunar_day: LunarDay = if day_text.is_empty() || day_text == "朔" {
+            LunarDay::new(lunar_year, lunar_month.get_month_with_leap(), 1)?
+        } else if let Some(i) = LUNAR_DAY_NAMES.iter().position(|x| *x == day_text) {
+            LunarDay::new(lunar_year, lunar_month.get_month_with_leap(), i + 1)?
+        } else if SIXTY_CYCLE_NAMES.contains(&day_text) {
+            let days: Vec<LunarDay> = lunar_month.get_days();
+            days.into_iter()
+                .find(|d| d.get_sixty_cycle().get_name() == day_text)
+                .ok_or(format!("{} not in {}", day_text, lunar_month))?
+        } else {
+            return Err(format!("illegal day: {}", day_text));
+        };
+
+        Ok(Self {
+            era_year,
+            lunar_day,
+        })
+    }
+
+    pub fn from_text(text: &str) -> Self {
+        Self::parse(text).unwrap()
+    }
+
+    /// 年号纪年
+    pub fn get_era_year(&self) -> EraYear {
+        self.era_year
+    }
+
+    /// 年号
+    pub fn get_era(&self) -> Era {
+        self.era_year.get_era()
+    }
+
+    /// 年号第几年（元年为1）
+    pub fn get_year(&self) -> usize {
+        self.era_year.get_year()
+    }
+
+    /// 农历日
+    pub fn get_lunar_day(&self) -> LunarDay {
+        self.lunar_day.clone()
+    }
+
+    /// 公历日
+    pub fn get_solar_day(&self) -> SolarDay {
+        self.lunar_day.get_solar_day()
+    }
+}
+
+impl Display for EraDay {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.get_name())
+    }
+}
+
+impl PartialEq for EraDay {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
+impl Eq for EraDay {}
+
+/// 解析一至九十九的中文数字
+fn parse_number(text: &str) -> Option<usize> {
+    let digit = |c: char| {
+        NUMBER_NAMES
+            .iter()
+            .position(|x| x.starts_with(c))
+            .filter(|i| *i > 0)
+    };
+    let chars: Vec<char> = text.chars().collect();
+    match chars.as_slice() {
+        [a] if *a == '十' => Some(10),
+        [a] => digit(*a),
+        [a, b] if *a == '十' => digit(*b).map(|n| 10 + n),
+        [a, b] if *a == '廿' => digit(*b).map(|n| 20 + n),
+        [a, b] if *b == '十' => digit(*a).map(|n| n * 10),
+        [a, b, c] if *b == '十' => digit(*a).and_then(|m| digit(*c).map(|n| m * 10 + n)),
+        _ => None,
+    }
+}
+
+/// 一至九十九的中文数字
+fn number_name(n: usize) -> String {
+    let (tens, ones) = (n / 10, n % 10);
+    let mut s: String = String::new();
+    if tens > 1 {
+        s.push_str(NUMBER_NAMES[tens]);
+    }
+    if tens > 0 {
+        s.push('十');
+    }
+    if ones > 0 {
+        s.push_str(NUMBER_NAMES[ones]);
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::{String, ToString};
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use crate::era::{Era, EraDay, EraYear};
+    use crate::types::Culture;
+
+    #[test]
+    fn test1() {
+        let era: Era = Era::from_name("康熙");
+        assert_eq!("清", era.get_dynasty());
+        assert_eq!(1662, era.get_start_year());
+        assert_eq!(61, era.get_year_count());
+        assert_eq!(1711, era.get_year(50).unwrap());
+        assert!(era.get_year(62).is_err());
+        assert!(Era::new("不存在").is_err());
+    }
+
+    #[test]
+    fn test2() {
+        let d: EraDay = EraDay::from_text("康熙五十年冬月庚寅日");
+        assert_eq!("康熙", d.get_era().get_name());
+        assert_eq!(50, d.get_year());
+        assert_eq!("康熙五十年冬月初五", d.to_string());
+        assert_eq!("1711年12月14日", d.get_solar_day().to_string());
+    }
+
+    #[test]
+    fn test3() {
+        assert_eq!(
+            "1644年2月8日",
+            EraDay::from_text("崇祯十七年正月朔")
+                .get_solar_day()
+                .to_string()
+        );
+        assert_eq!(
+            "乾隆元年正月十五",
+            EraDay::from_text("乾隆元年正月十五日").to_string()
+        );
+        assert_eq!(
+            "道光二十三年九月初一",
+            EraDay::from_text("道光廿三年九月").to_string()
+        );
+    }
+
+    #[test]
+    fn test4() {
+        assert!(EraDay::parse("康熙六十二年正月初一").is_err());
+        assert!(EraDay::parse("康熙五十年冬月三十一").is_err());
+        assert!(EraDay::parse("不存在五十年冬月初一").is_err());
+        assert!(EraDay::parse("康熙五十年闰正月初一").is_err());
+        // 该月无甲子日
+        assert!(EraDay::parse("康熙五十年冬月甲子日").is_err());
+    }
+
+    #[test]
+    fn test5() {
+        let names: Vec<String> = EraYear::from_solar_year(1662)
+            .iter()
+            .map(|y| y.to_string())
+            .collect();
+        assert_eq!(vec!["康熙元年"], names);
+        let names: Vec<String> = EraYear::from_solar_year(1644)
+            .iter()
+            .map(|y| y.to_string())
+            .collect();
+        assert_eq!(vec!["崇祯十七年", "顺治元年"], names);
+        assert_eq!(
+            "民国三十八年",
+            EraYear::from_solar_year(1949)[0].to_string()
+        );
+        assert!(EraYear::from_solar_year(2000).is_empty());
+    }
+
+    #[test]
+    fn test6() {
+        assert_eq!(
+            627,
+            EraYear::from_era_year(Era::from_name("贞观"), 1).get_solar_year()
+        );
+        assert_eq!(
+            -139,
+            EraYear::from_era_year(Era::from_name("建元"), 1).get_solar_year()
+        );
+        assert!(EraYear::new(Era::from_name("靖康"), 3).is_err());
+        // 重名年号
+        assert_eq!(674, Era::from_name("上元").get_start_year());
+        assert_eq!(936, Era::from_name("天福").get_start_year());
+        assert_eq!(947, Era::from_name("后汉天福").get_start_year());
+        assert_eq!(25, Era::from_name("东汉建武").get_start_year());
+        assert_eq!(317, Era::from_name("东晋建武").get_start_year());
+        assert_eq!("唐", Era::from_name("开元").get_dynasty());
+    }
+}
@@ -0,0 +1,521 @@
+//! 统一的日历事件：节日、法定假日、节气、月相及自定义事件
+
+use core::fmt::{Display, Formatter};
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::cache::Lock;
+use crate::context::CalendarContext;
+use crate::culture::Phase;
+use crate::enums::EventKind;
+#[cfg(feature = "festival")]
+use crate::festival::{LunarFestival, SolarFestival};
+#[cfg(feature = "holiday")]
+use crate::holiday::LegalHoliday;
+use crate::lunar::LunarDay;
+use crate::solar::{SolarDay, SolarTerm, SolarTime};
+use crate::types::{Culture, Tyme};
+
+/// 运行时登记的自定义事件（名称, 规则）
+static CUSTOM_EVENT_RULES: Lock<Vec<(String, CustomEventRule)>> = Lock::new(Vec::new());
+
+/// 自定义事件
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomEvent {
+    /// 名称
+    name: String,
+    /// 公历日
+    day: SolarDay,
+}
+
+impl CustomEvent {
+    pub fn new(name: &str, day: SolarDay) -> Result<Self, String> {
+        if name.is_empty() {
+            return Err(String::from("illegal custom event name: empty"));
+        }
+        Ok(Self {
+            name: name.to_string(),
+            day,
+        })
+    }
+
+    /// 公历日
+    pub fn get_solar_day(&self) -> SolarDay {
+        self.day
+    }
+}
+
+impl Culture for CustomEvent {
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+}
+
+impl Display for CustomEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.get_name())
+    }
+}
+
+/// 自定义事件的每年重复规则
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CustomEventRule {
+    /// 公历固定日期
+    Solar { month: usize, day: usize },
+    /// 农历固定日期，闰月为负数
+    Lunar { month: isize, day: usize },
+    /// 公历某月第n个星期几（week：0为星期日），n为负数时倒数
+    NthWeekday { month: usize, n: isize, week: usize },
+}
+
+impl CustomEventRule {
+    fn validate(&self) -> Result<(), String> {
+        let valid: bool = match *self {
+            Self::Solar { month, day } => (1..=12).contains(&month) && (1..=31).contains(&day),
+            Self::Lunar { month, day } => {
+                (1..=12).contains(&month.abs()) && (1..=30).contains(&day)
+            }
+            Self::NthWeekday { month, n, week } => {
+                (1..=12).contains(&month) && (1..=5).contains(&n.abs()) && week < 7
+            }
+        };
+        if !valid {
+            return Err(format!("illegal custom event rule: {:?}", self));
+        }
+        Ok(())
+    }
+
+    /// 公历日是否符合规则
+    pub fn matches(&self, day: SolarDay) -> bool {
+        match *self {
+            Self::Solar { month, day: d } => day.get_month() == month && day.get_day() == d,
+            Self::Lunar { month, day: d } => {
+                let lunar: LunarDay = day.get_lunar_day();
+                lunar.get_lunar_month().get_month_with_leap() == month && lunar.get_day() == d
+            }
+            Self::NthWeekday { month, n, week } => {
+                if day.get_month() != month || day.get_week().get_index() != week {
+                    return false;
+                }
+                if n > 0 {
+                    (day.get_day() - 1) / 7 + 1 == n as usize
+                } else {
+                    let count: usize = day.get_solar_month().get_day_count();
+                    (count - day.get_day()) / 7 + 1 == n.unsigned_abs()
+                }
+            }
+        }
+    }
+}
+
+/// 自定义事件登记表，登记的事件出现在events()的结果中
+pub struct CustomEventRegistry;
+
+impl CustomEventRegistry {
+    /// 登记自定义事件，同名时替换原规则
+    pub fn register(name: &str, rule: CustomEventRule) -> Result<(), String> {
+        if name.is_empty() {
+            return Err(String::from("illegal custom event name: empty"));
+        }
+        rule.validate()?;
+        CUSTOM_EVENT_RULES.with(|rules| match rules.iter_mut().find(|(n, _)| n == name) {
+            Some(entry) => entry.1 = rule,
+            None => rules.push((name.to_string(), rule)),
+        });
+        Ok(())
+    }
+
+    /// 注销自定义事件
+    pub fn unregister(name: &str) {
+        CUSTOM_EVENT_RULES.with(|rules| rules.retain(|(n, _)| n != name));
+    }
+
+    /// 注销全部自定义事件
+    pub fn clear() {
+        CUSTOM_EVENT_RULES.with(|rules| rules.clear());
+    }
+
+    /// 公历日的自定义事件，按登记顺序
+    pub fn get_events(day: SolarDay) -> Vec<CustomEvent> {
+        // 锁内只复制登记表，规则（农历规则需换算农历）在锁外匹配
+        let rules: Vec<(String, CustomEventRule)> = CUSTOM_EVENT_RULES.with(|rules| rules.clone());
+        rules
+            .into_iter()
+            .filter(|(_, rule)| rule.matches(day))
+            .map(|(name, _)| CustomEvent { name, day })
+            .collect()
+    }
+}
+
+/// 默认的事件类别顺序：法定假日、公历节日、农历节日、交节、月相、自定义
+static DEFAULT_EVENT_ORDER: [EventKind; 6] = [
+    EventKind::HOLIDAY,
+    EventKind::SOLAR,
+    EventKind::LUNAR,
+    EventKind::TERM,
+    EventKind::PHASE,
+    EventKind::CUSTOM,
+];
+
+/// 同一天多个事件的排序策略：按事件类别的先后排列，同类别保持events()的收集顺序
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct EventPriority {
+    /// 事件类别，靠前的优先
+    order: [EventKind; 6],
+}
+
+impl Default for EventPriority {
+    fn default() -> Self {
+        Self {
+            order: DEFAULT_EVENT_ORDER,
+        }
+    }
+}
+
+impl EventPriority {
+    /// 指定优先的事件类别（靠前的优先），未列出的类别按默认顺序排在其后；类别重复时报错
+    pub fn new(kinds: &[EventKind]) -> Result<Self, String> {
+        let mut order: Vec<EventKind> = Vec::new();
+        for kind in kinds {
+            if order.contains(kind) {
+                return Err(format!("duplicate event kind: {}", kind));
+            }
+            order.push(*kind);
+        }
+        for kind in DEFAULT_EVENT_ORDER {
+            if !order.contains(&kind) {
+                order.push(kind);
+            }
+        }
+        let mut priority: Self = Self::default();
+        priority.order.copy_from_slice(&order);
+        Ok(priority)
+    }
+
+    /// 事件类别，靠前的优先
+    pub fn get_order(&self) -> [EventKind; 6] {
+        self.order
+    }
+
+    /// 事件类别的优先级，数值越小越优先
+    pub fn get_priority(&self, kind: EventKind) -> usize {
+        self.order.iter().position(|k| *k == kind).unwrap()
+    }
+}
+
+/// 日历事件
+#[derive(Debug, Clone)]
+pub enum CalendarEvent {
+    /// 法定假日（含调休上班）
+    #[cfg(feature = "holiday")]
+    LegalHoliday(LegalHoliday),
+    /// 公历现代节日
+    #[cfg(feature = "festival")]
+    SolarFestival(SolarFestival),
+    /// 农历传统节日
+    #[cfg(feature = "festival")]
+    LunarFestival(LunarFestival),
+    /// 交节
+    SolarTerm(SolarTerm),
+    /// 朔、上弦、望、下弦
+    Phase(Phase),
+    /// 自定义事件
+    Custom(CustomEvent),
+}
+
+impl CalendarEvent {
+    /// 公历日
+    pub fn get_solar_day(&self) -> SolarDay {
+        match self {
+            #[cfg(feature = "holiday")]
+            Self::LegalHoliday(h) => h.get_day(),
+            #[cfg(feature = "festival")]
+            Self::SolarFestival(f) => f.get_day(),
+            #[cfg(feature = "festival")]
+            Self::LunarFestival(f) => f.get_day().get_solar_day(),
+            Self::SolarTerm(t) => t.get_julian_day().get_solar_day(),
+            Self::Phase(p) => p.get_solar_day(),
+            Self::Custom(c) => c.get_solar_day(),
+        }
+    }
+
+    /// 发生时刻，仅交节、月相有确切时刻
+    pub fn get_solar_time(&self) -> Option<SolarTime> {
+        match self {
+            Self::SolarTerm(t) => Some(t.get_julian_day().get_solar_time()),
+            Self::Phase(p) => Some(p.get_solar_time()),
+            _ => None,
+        }
+    }
+
+    /// 事件类别
+    pub fn get_kind(&self) -> EventKind {
+        match self {
+            #[cfg(feature = "holiday")]
+            Self::LegalHoliday(_) => EventKind::HOLIDAY,
+            #[cfg(feature = "festival")]
+            Self::SolarFestival(_) => EventKind::SOLAR,
+            #[cfg(feature = "festival")]
+            Self::LunarFestival(_) => EventKind::LUNAR,
+            Self::SolarTerm(_) => EventKind::TERM,
+            Self::Phase(_) => EventKind::PHASE,
+            Self::Custom(_) => EventKind::CUSTOM,
+        }
+    }
+
+    /// 默认排序（EventPriority::default()）下的优先级，数值越小越优先：法定假日0，公历节日1，农历节日2，交节3，月相4，自定义5；
+    /// 按上下文排序时用CalendarContext::get_event_priority()
+    pub fn get_priority(&self) -> usize {
+        EventPriority::default().get_priority(self.get_kind())
+    }
+}
+
+impl Culture for CalendarEvent {
+    fn get_name(&self) -> String {
+        match self {
+            #[cfg(feature = "holiday")]
+            Self::LegalHoliday(h) => h.get_name(),
+            #[cfg(feature = "festival")]
+            Self::SolarFestival(f) => f.get_name(),
+            #[cfg(feature = "festival")]
+            Self::LunarFestival(f) => f.get_name(),
+            Self::SolarTerm(t) => t.get_name(),
+            Self::Phase(p) => p.get_name(),
+            Self::Custom(c) => c.get_name(),
+        }
+    }
+}
+
+impl Display for CalendarEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.get_name())
+    }
+}
+
+/// 公历日的全部事件（含登记的自定义事件），按优先级排序（同优先级保持法定假日、公历节日、农历节日、交节、月相的顺序）
+pub fn events(day: SolarDay) -> Vec<CalendarEvent> {
+    events_with_context(day, &CalendarContext::default())
+}
+
+/// 公历日的全部事件，按配置上下文的事件排序策略排序
+pub fn events_with_context(day: SolarDay, context: &CalendarContext) -> Vec<CalendarEvent> {
+    let mut l: Vec<CalendarEvent> = Vec::new();
+    #[cfg(feature = "holiday")]
+    if let Some(h) = day.get_legal_holiday() {
+        l.push(CalendarEvent::LegalHoliday(h));
+    }
+    #[cfg(feature = "festival")]
+    {
+        if let Some(f) = day.get_festival() {
+            l.push(CalendarEvent::SolarFestival(f));
+        }
+        if let Some(f) = day.get_lunar_day().get_festival() {
+            l.push(CalendarEvent::LunarFestival(f));
+        }
+    }
+    // 交节按精确时刻所在的公历日（与CalendarEvent::get_solar_day一致），日历用的交节日在子夜附近可能相差一天，
+    // 故同时检查所在节气及下一节气
+    let term: SolarTerm = day.get_term_day().get_solar_term();
+    for t in [term.next(1), term] {
+        if t.get_julian_day().get_solar_day() == day {
+            l.push(CalendarEvent::SolarTerm(t));
+        }
+    }
+    let phase: Phase = day.get_phase_day().get_phase();
+    if phase.get_index().is_multiple_of(2) && phase.get_solar_day() == day {
+        l.push(CalendarEvent::Phase(phase));
+    }
+    for custom in CustomEventRegistry::get_events(day) {
+        l.push(CalendarEvent::Custom(custom));
+    }
+    let priority: EventPriority = context.get_event_priority();
+    l.sort_by_key(|e| priority.get_priority(e.get_kind()));
+    l
+}
+
+/// 公历日最优先的事件（供界面只显示一个标注时使用），无事件时为None
+pub fn primary_event(day: SolarDay) -> Option<CalendarEvent> {
+    primary_event_with_context(day, &CalendarContext::default())
+}
+
+/// 按配置上下文的事件排序策略，公历日最优先的事件
+pub fn primary_event_with_context(
+    day: SolarDay,
+    context: &CalendarContext,
+) -> Option<CalendarEvent> {
+    events_with_context(day, context).into_iter().next()
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+
+    #[cfg(all(feature = "festival", feature = "holiday"))]
+    use crate::context::CalendarContext;
+    use crate::enums::EventKind;
+    use crate::event::{
+        CalendarEvent, CustomEvent, CustomEventRegistry, CustomEventRule, EventPriority, events,
+        primary_event,
+    };
+    #[cfg(all(feature = "festival", feature = "holiday"))]
+    use crate::event::{events_with_context, primary_event_with_context};
+    use crate::solar::SolarDay;
+    use crate::types::{Culture, Tyme};
+
+    #[test]
+    fn test1() {
+        // 2024-01-11：朔
+        let l: Vec<CalendarEvent> = events(SolarDay::from_ymd(2024, 1, 11));
+        assert_eq!(1, l.len());
+        assert_eq!("新月", l[0].get_name());
+        assert!(l[0].get_solar_time().is_some());
+
+        let l: Vec<CalendarEvent> = events(SolarDay::from_ymd(2024, 8, 7));
+        assert_eq!(1, l.len());
+        assert_eq!("立秋", l[0].to_string());
+        assert_eq!(SolarDay::from_ymd(2024, 8, 7), l[0].get_solar_day());
+
+        let custom: CustomEvent =
+            CustomEvent::new("公司周年", SolarDay::from_ymd(2024, 3, 1)).unwrap();
+        assert_eq!(5, CalendarEvent::Custom(custom).get_priority());
+        assert!(CustomEvent::new("", SolarDay::from_ymd(2024, 3, 1)).is_err());
+    }
+
+    #[cfg(all(feature = "festival", feature = "holiday"))]
+    #[test]
+    fn test2() {
+        // 2024-10-01：国庆节法定假日、国庆节
+        let l: Vec<CalendarEvent> = events(SolarDay::from_ymd(2024, 10, 1));
+        assert_eq!(2, l.len());
+        assert_eq!(0, l[0].get_priority());
+        assert_eq!(1, l[1].get_priority());
+
+        // 2024-04-04：清明（法定假日、清明节、交节）
+        let l: Vec<CalendarEvent> = events(SolarDay::from_ymd(2024, 4, 4));
+        let priorities: Vec<usize> = l.iter().map(|e| e.get_priority()).collect();
+        assert_eq!(alloc::vec![0, 2, 3], priorities);
+    }
+
+    #[test]
+    fn test3() {
+        // 2025年：公司周年（公历3月2日）、外婆生日（农历四月初八）、感恩节（11月第4个星期四）、月末周五（5月最后一个星期五）
+        CustomEventRegistry::register("公司周年", CustomEventRule::Solar { month: 3, day: 2 })
+            .unwrap();
+        CustomEventRegistry::register("外婆生日", CustomEventRule::Lunar { month: 4, day: 8 })
+            .unwrap();
+        CustomEventRegistry::register(
+            "感恩节",
+            CustomEventRule::NthWeekday {
+                month: 11,
+                n: 4,
+                week: 4,
+            },
+        )
+        .unwrap();
+        CustomEventRegistry::register(
+            "月末周五",
+            CustomEventRule::NthWeekday {
+                month: 5,
+                n: -1,
+                week: 5,
+            },
+        )
+        .unwrap();
+        assert!(
+            CustomEventRegistry::register("闰月", CustomEventRule::Lunar { month: 13, day: 1 })
+                .is_err()
+        );
+
+        let names = |y: isize, m: usize, d: usize| -> Vec<alloc::string::String> {
+            events(SolarDay::from_ymd(y, m, d))
+                .iter()
+                .filter(|e| e.get_kind() == EventKind::CUSTOM)
+                .map(|e| e.get_name())
+                .collect()
+        };
+        assert_eq!(alloc::vec!["公司周年"], names(2025, 3, 2));
+        assert_eq!(alloc::vec!["外婆生日"], names(2025, 5, 5));
+        assert_eq!(alloc::vec!["感恩节"], names(2025, 11, 27));
+        assert_eq!(alloc::vec!["月末周五"], names(2025, 5, 30));
+        assert!(names(2025, 5, 23).is_empty());
+
+        CustomEventRegistry::unregister("外婆生日");
+        assert!(names(2025, 5, 5).is_empty());
+        CustomEventRegistry::unregister("公司周年");
+        CustomEventRegistry::unregister("感恩节");
+        CustomEventRegistry::unregister("月末周五");
+    }
+
+    #[test]
+    fn test4() {
+        let priority: EventPriority =
+            EventPriority::new(&[EventKind::TERM, EventKind::LUNAR]).unwrap();
+        assert_eq!(0, priority.get_priority(EventKind::TERM));
+        assert_eq!(1, priority.get_priority(EventKind::LUNAR));
+        assert_eq!(2, priority.get_priority(EventKind::HOLIDAY));
+        assert_eq!(5, priority.get_priority(EventKind::CUSTOM));
+        assert!(EventPriority::new(&[EventKind::TERM, EventKind::TERM]).is_err());
+        assert_eq!(EventPriority::default(), EventPriority::new(&[]).unwrap());
+
+        // 2024-01-11：朔
+        assert_eq!(
+            "新月",
+            primary_event(SolarDay::from_ymd(2024, 1, 11))
+                .unwrap()
+                .get_name()
+        );
+        assert!(primary_event(SolarDay::from_ymd(2024, 1, 12)).is_none());
+    }
+
+    #[cfg(all(feature = "festival", feature = "holiday"))]
+    #[test]
+    fn test5() {
+        // 2024-04-04：清明（法定假日、清明节、交节），交节优先时排在最前
+        let day: SolarDay = SolarDay::from_ymd(2024, 4, 4);
+        let context: CalendarContext = CalendarContext::default()
+            .with_event_priority(EventPriority::new(&[EventKind::TERM]).unwrap());
+        let kinds: Vec<EventKind> = events_with_context(day, &context)
+            .iter()
+            .map(|e| e.get_kind())
+            .collect();
+        assert_eq!(
+            alloc::vec![EventKind::TERM, EventKind::HOLIDAY, EventKind::LUNAR],
+            kinds
+        );
+        assert_eq!(EventKind::HOLIDAY, primary_event(day).unwrap().get_kind());
+        assert_eq!(
+            EventKind::TERM,
+            primary_event_with_context(day, &context)
+                .unwrap()
+                .get_kind()
+        );
+    }
+
+    #[test]
+    fn test6() {
+        // 交节时刻临近子夜：1912年小雪在11月22日23:48（日历用的交节日为23日），1927年白露在9月9日00:05（日历用的交节日为8日），
+        // 交节事件按精确时刻所在日，与其公历日一致
+        for (y, m, d, name) in [(1912, 11, 22, "小雪"), (1927, 9, 9, "白露")] {
+            let day: SolarDay = SolarDay::from_ymd(y, m, d);
+            let terms: Vec<CalendarEvent> = events(day)
+                .into_iter()
+                .filter(|e| e.get_kind() == EventKind::TERM)
+                .collect();
+            assert_eq!(1, terms.len(), "{}", day);
+            assert_eq!(name, terms[0].get_name());
+            assert_eq!(day, terms[0].get_solar_day());
+            for other in [day.next(-1), day.next(1)] {
+                assert!(
+                    events(other)
+                        .iter()
+                        .all(|e| e.get_kind() != EventKind::TERM),
+                    "{}",
+                    other
+                );
+            }
+        }
+    }
+}
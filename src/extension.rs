@@ -0,0 +1,213 @@
+//! 扩展指数：下游按日计算的自定义指数（穿衣、洗车、晾晒等），登记后出现在黄历中，本库不内置非历法逻辑
+
+use core::fmt::{Display, Formatter};
+
+#[cfg(not(feature = "thread-safe"))]
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+#[cfg(feature = "thread-safe")]
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use crate::almanac::Almanac;
+use crate::cache::Lock;
+use crate::types::Culture;
+
+/// 登记的扩展：thread-safe下为Arc，复制后在锁外计算；single-thread下为Box（alloc::sync需要原子操作），在临界区内计算
+#[cfg(feature = "thread-safe")]
+type ExtRef = Arc<dyn CalendarExt>;
+
+/// 登记的扩展（single-thread）
+#[cfg(not(feature = "thread-safe"))]
+type ExtRef = Box<dyn CalendarExt>;
+
+/// 运行时登记的扩展（名称, 扩展），按登记顺序
+static CALENDAR_EXTS: Lock<Vec<(String, ExtRef)>> = Lock::new(Vec::new());
+
+/// 日历扩展：由下游实现，为每个公历日计算一项自定义指数
+pub trait CalendarExt: Send + Sync {
+    /// 名称，登记表中唯一
+    fn get_name(&self) -> String;
+
+    /// 黄历当天的指数值，不适用时为None；thread-safe下在登记表的锁外计算，single-thread下在临界区内计算，不可访问CalendarExtRegistry
+    fn compute(&self, almanac: &Almanac) -> Option<String>;
+}
+
+/// 扩展指数
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayIndex {
+    /// 名称
+    name: String,
+    /// 指数值
+    value: String,
+}
+
+impl DayIndex {
+    pub fn new(name: &str, value: &str) -> Result<Self, String> {
+        if name.is_empty() {
+            return Err(String::from("illegal day index name: empty"));
+        }
+        Ok(Self {
+            name: name.to_string(),
+            value: value.to_string(),
+        })
+    }
+
+    /// 指数值
+    pub fn get_value(&self) -> String {
+        self.value.clone()
+    }
+}
+
+impl Culture for DayIndex {
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+}
+
+impl Display for DayIndex {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}：{}", self.name, self.value)
+    }
+}
+
+/// 日历扩展登记表，登记的扩展出现在Almanac::get_indices()的结果中
+pub struct CalendarExtRegistry;
+
+impl CalendarExtRegistry {
+    /// 登记扩展，同名时替换原扩展
+    pub fn register(ext: impl CalendarExt + 'static) -> Result<(), String> {
+        let name: String = ext.get_name();
+        if name.is_empty() {
+            return Err(String::from("illegal calendar extension name: empty"));
+        }
+        #[cfg(feature = "thread-safe")]
+        let ext: ExtRef = Arc::new(ext);
+        #[cfg(not(feature = "thread-safe"))]
+        let ext: ExtRef = Box::new(ext);
+        CALENDAR_EXTS.with(|exts| match exts.iter_mut().find(|(n, _)| *n == name) {
+            Some(entry) => entry.1 = ext,
+            None => exts.push((name, ext)),
+        });
+        Ok(())
+    }
+
+    /// 注销扩展
+    pub fn unregister(name: &str) {
+        CALENDAR_EXTS.with(|exts| exts.retain(|(n, _)| n != name));
+    }
+
+    /// 注销全部扩展
+    pub fn clear() {
+        CALENDAR_EXTS.with(|exts| exts.clear());
+    }
+
+    /// 已登记扩展的名称，按登记顺序
+    pub fn get_names() -> Vec<String> {
+        CALENDAR_EXTS.with(|exts| exts.iter().map(|(n, _)| n.clone()).collect())
+    }
+
+    /// 黄历当天各扩展的指数，按登记顺序，不适用的扩展不列出
+    #[cfg(feature = "thread-safe")]
+    pub fn get_indices(almanac: &Almanac) -> Vec<DayIndex> {
+        // 锁内只复制登记表，扩展在锁外计算
+        let exts: Vec<(String, ExtRef)> = CALENDAR_EXTS.with(|exts| exts.clone());
+        exts.into_iter()
+            .filter_map(|(name, e)| e.compute(almanac).map(|value| DayIndex { name, value }))
+            .collect()
+    }
+
+    /// 黄历当天各扩展的指数，按登记顺序，不适用的扩展不列出
+    #[cfg(not(feature = "thread-safe"))]
+    pub fn get_indices(almanac: &Almanac) -> Vec<DayIndex> {
+        // Box不可复制出锁，扩展在临界区内计算
+        CALENDAR_EXTS.with(|exts| {
+            exts.iter()
+                .filter_map(|(name, e)| {
+                    e.compute(almanac).map(|value| DayIndex {
+                        name: name.clone(),
+                        value,
+                    })
+                })
+                .collect()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+
+    use crate::almanac::Almanac;
+    use crate::extension::{CalendarExt, CalendarExtRegistry, DayIndex};
+    use crate::solar::SolarDay;
+    use crate::sun::Observer;
+    use crate::types::Culture;
+
+    /// 洗车指数：逢周末宜洗车
+    struct CarWash;
+
+    impl CalendarExt for CarWash {
+        fn get_name(&self) -> String {
+            String::from("洗车")
+        }
+
+        fn compute(&self, almanac: &Almanac) -> Option<String> {
+            let week: usize = almanac.get_solar_day().get_week().get_index();
+            Some(String::from(if week == 0 || week == 6 {
+                "适宜"
+            } else {
+                "较不宜"
+            }))
+        }
+    }
+
+    /// 晾晒指数：仅在指定观测者时给出，昼长超过13小时为适宜
+    struct Drying(usize);
+
+    impl CalendarExt for Drying {
+        fn get_name(&self) -> String {
+            String::from("晾晒")
+        }
+
+        fn compute(&self, almanac: &Almanac) -> Option<String> {
+            let seconds: usize = almanac.get_sun_times()?.day_length();
+            Some(String::from(if seconds > self.0 {
+                "适宜"
+            } else {
+                "一般"
+            }))
+        }
+    }
+
+    #[test]
+    fn test1() {
+        CalendarExtRegistry::register(CarWash).unwrap();
+        CalendarExtRegistry::register(Drying(13 * 3600)).unwrap();
+        let almanac: Almanac = Almanac::from_solar_day(SolarDay::from_ymd(2024, 6, 22));
+        let l: Vec<DayIndex> = almanac.get_indices();
+        assert_eq!(1, l.len());
+        assert_eq!("洗车：适宜", l[0].to_string());
+
+        let almanac: Almanac = almanac.with_observer(Observer::from_degrees(116.4, 39.9));
+        let l: Vec<DayIndex> = almanac.get_indices();
+        assert_eq!(2, l.len());
+        assert_eq!("晾晒", l[1].get_name());
+        assert_eq!("适宜", l[1].get_value());
+
+        // 同名替换，顺序不变
+        CalendarExtRegistry::register(Drying(16 * 3600)).unwrap();
+        assert_eq!("一般", almanac.get_indices()[1].get_value());
+        assert_eq!(
+            alloc::vec![String::from("洗车"), String::from("晾晒")],
+            CalendarExtRegistry::get_names()
+        );
+
+        CalendarExtRegistry::unregister("洗车");
+        assert_eq!(1, almanac.get_indices().len());
+        CalendarExtRegistry::unregister("晾晒");
+        assert!(almanac.get_indices().is_empty());
+        assert!(DayIndex::new("", "适宜").is_err());
+    }
+}
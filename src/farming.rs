@@ -0,0 +1,133 @@
+use core::fmt::{Display, Formatter};
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::cache::Lock;
+use crate::generated_farming_data::FARMING_TABLE;
+use crate::solar::SolarTerm;
+use crate::types::Culture;
+
+/// 运行时覆盖的节气农事（节气索引, 农事）
+static FARMING_OVERRIDES: Lock<Vec<(usize, FarmingAdvisory)>> = Lock::new(Vec::new());
+
+/// 节气农事（农事提示及农谚）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FarmingAdvisory {
+    /// 农事提示
+    advice: String,
+    /// 农谚
+    proverbs: Vec<String>,
+}
+
+impl Culture for FarmingAdvisory {
+    fn get_name(&self) -> String {
+        self.advice.clone()
+    }
+}
+
+impl FarmingAdvisory {
+    pub fn new(advice: &str, proverbs: &[&str]) -> Self {
+        Self {
+            advice: advice.to_string(),
+            proverbs: proverbs.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    /// 农事提示
+    pub fn get_advice(&self) -> String {
+        self.advice.clone()
+    }
+
+    /// 农谚
+    pub fn get_proverbs(&self) -> Vec<String> {
+        self.proverbs.clone()
+    }
+}
+
+impl Display for FarmingAdvisory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.get_name())
+    }
+}
+
+/// 节气农事登记表，内置默认数据，可在运行时按节气覆盖
+pub struct FarmingRegistry;
+
+impl FarmingRegistry {
+    /// 节气的内置默认农事
+    pub fn get_default(term: &SolarTerm) -> FarmingAdvisory {
+        let entry = &FARMING_TABLE[term.get_index()];
+        FarmingAdvisory::new(entry.advice, entry.proverbs)
+    }
+
+    /// 节气农事，有覆盖时返回覆盖的内容
+    pub fn get(term: &SolarTerm) -> FarmingAdvisory {
+        let index: usize = term.get_index();
+        let advisory: Option<FarmingAdvisory> = FARMING_OVERRIDES.with(|overrides| {
+            overrides
+                .iter()
+                .find(|(i, _)| *i == index)
+                .map(|(_, advisory)| advisory.clone())
+        });
+        if let Some(advisory) = advisory {
+            return advisory;
+        }
+        Self::get_default(term)
+    }
+
+    /// 覆盖节气农事
+    pub fn set(term: &SolarTerm, advisory: FarmingAdvisory) {
+        let index: usize = term.get_index();
+        FARMING_OVERRIDES.with(
+            |overrides| match overrides.iter_mut().find(|(i, _)| *i == index) {
+                Some(entry) => entry.1 = advisory,
+                None => overrides.push((index, advisory)),
+            },
+        );
+    }
+
+    /// 恢复节气的默认农事
+    pub fn reset(term: &SolarTerm) {
+        let index: usize = term.get_index();
+        FARMING_OVERRIDES.with(|overrides| overrides.retain(|(i, _)| *i != index));
+    }
+
+    /// 恢复全部默认农事
+    pub fn reset_all() {
+        FARMING_OVERRIDES.with(|overrides| overrides.clear());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::farming::{FarmingAdvisory, FarmingRegistry};
+    use crate::solar::SolarTerm;
+    use crate::types::Tyme;
+
+    #[test]
+    fn test1() {
+        let advisory: FarmingAdvisory = FarmingRegistry::get(&SolarTerm::from_name(2024, "清明"));
+        assert_eq!("种瓜点豆，植树插柳", advisory.to_string());
+        assert_eq!("清明前后，种瓜点豆", advisory.get_proverbs()[0]);
+    }
+
+    #[test]
+    fn test2() {
+        let term: SolarTerm = SolarTerm::from_name(2024, "大雪");
+        FarmingRegistry::set(&term, FarmingAdvisory::new("大棚保温", &["瑞雪兆丰年"]));
+        assert_eq!("大棚保温", FarmingRegistry::get(&term).get_advice());
+        assert_eq!(
+            "大棚保温",
+            FarmingRegistry::get(&term.next(24)).get_advice()
+        );
+        assert_eq!(
+            "越冬作物覆盖防冻",
+            FarmingRegistry::get_default(&term).get_advice()
+        );
+        FarmingRegistry::reset(&term);
+        assert_eq!("越冬作物覆盖防冻", FarmingRegistry::get(&term).get_advice());
+    }
+}
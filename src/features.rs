@@ -0,0 +1,319 @@
+//! 编译特性自省：运行时查询本库编译时启用的特性及所含数据，便于下游插件按编译配置调整而不是在链接或运行时失败
+
+use core::fmt::{Display, Formatter};
+
+/// 已启用的特性（与Cargo.toml中的特性名一致，不含default）
+static ENABLED: &[&str] = &[
+    #[cfg(feature = "eight-char")]
+    "eight-char",
+    #[cfg(feature = "eight-char-default-provider")]
+    "eight-char-default-provider",
+    #[cfg(feature = "eight-char-lunar-sect2-provider")]
+    "eight-char-lunar-sect2-provider",
+    #[cfg(feature = "eight-char-lunar-new-year-boundary")]
+    "eight-char-lunar-new-year-boundary",
+    #[cfg(feature = "child-limit")]
+    "child-limit",
+    #[cfg(feature = "child-limit-default-provider")]
+    "child-limit-default-provider",
+    #[cfg(feature = "child-limit-china95-provider")]
+    "child-limit-china95-provider",
+    #[cfg(feature = "child-limit-lunar-sect1-provider")]
+    "child-limit-lunar-sect1-provider",
+    #[cfg(feature = "child-limit-lunar-sect2-provider")]
+    "child-limit-lunar-sect2-provider",
+    #[cfg(feature = "festival")]
+    "festival",
+    #[cfg(feature = "holiday")]
+    "holiday",
+    #[cfg(feature = "ics")]
+    "ics",
+    #[cfg(feature = "cli-render")]
+    "cli-render",
+    #[cfg(feature = "plain-date")]
+    "plain-date",
+    #[cfg(feature = "rabbyung")]
+    "rabbyung",
+    #[cfg(feature = "era")]
+    "era",
+    #[cfg(feature = "farming")]
+    "farming",
+    #[cfg(feature = "reference")]
+    "reference",
+    #[cfg(feature = "debug-trace")]
+    "debug-trace",
+    #[cfg(feature = "heapless")]
+    "heapless",
+    #[cfg(feature = "parallel")]
+    "parallel",
+    #[cfg(feature = "thread-safe")]
+    "thread-safe",
+    #[cfg(feature = "single-thread")]
+    "single-thread",
+    #[cfg(feature = "external-ephemeris")]
+    "external-ephemeris",
+    #[cfg(feature = "ephemeris-full")]
+    "ephemeris-full",
+    #[cfg(feature = "ephemeris-medium")]
+    "ephemeris-medium",
+    #[cfg(feature = "ephemeris-small")]
+    "ephemeris-small",
+    #[cfg(feature = "compensated-sum")]
+    "compensated-sum",
+    #[cfg(feature = "trig-lut")]
+    "trig-lut",
+    #[cfg(feature = "dog")]
+    "dog",
+    #[cfg(feature = "god")]
+    "god",
+    #[cfg(feature = "peng_zu")]
+    "peng_zu",
+    #[cfg(feature = "phenology")]
+    "phenology",
+    #[cfg(feature = "fetus")]
+    "fetus",
+    #[cfg(feature = "nine")]
+    "nine",
+    #[cfg(feature = "plumrain")]
+    "plumrain",
+    #[cfg(feature = "earthking")]
+    "earthking",
+    #[cfg(feature = "springox")]
+    "springox",
+    #[cfg(feature = "miniren")]
+    "miniren",
+    #[cfg(feature = "star-nine")]
+    "star-nine",
+    #[cfg(feature = "star-seven")]
+    "star-seven",
+    #[cfg(feature = "star-six")]
+    "star-six",
+    #[cfg(feature = "star-ten")]
+    "star-ten",
+    #[cfg(feature = "star-twelve")]
+    "star-twelve",
+    #[cfg(feature = "star-twenty-eight")]
+    "star-twenty-eight",
+];
+
+/// 已编入的八字提供器特性
+static EIGHT_CHAR_PROVIDERS: &[&str] = &[
+    #[cfg(feature = "eight-char-default-provider")]
+    "eight-char-default-provider",
+    #[cfg(feature = "eight-char-lunar-sect2-provider")]
+    "eight-char-lunar-sect2-provider",
+];
+
+/// 已编入的童限提供器特性
+static CHILD_LIMIT_PROVIDERS: &[&str] = &[
+    #[cfg(feature = "child-limit-default-provider")]
+    "child-limit-default-provider",
+    #[cfg(feature = "child-limit-china95-provider")]
+    "child-limit-china95-provider",
+    #[cfg(feature = "child-limit-lunar-sect1-provider")]
+    "child-limit-lunar-sect1-provider",
+    #[cfg(feature = "child-limit-lunar-sect2-provider")]
+    "child-limit-lunar-sect2-provider",
+];
+
+/// 已启用的特性
+pub fn enabled() -> &'static [&'static str] {
+    ENABLED
+}
+
+/// 是否启用了指定特性
+pub fn is_enabled(name: &str) -> bool {
+    ENABLED.contains(&name)
+}
+
+/// 法定假日数据覆盖到的最后一年，未启用holiday时为None
+pub fn has_holiday_data_until() -> Option<isize> {
+    #[cfg(feature = "holiday")]
+    {
+        Some(crate::holiday::LegalHoliday::get_last_year())
+    }
+    #[cfg(not(feature = "holiday"))]
+    {
+        None
+    }
+}
+
+/// 是否编入藏历
+pub fn has_rabbyung() -> bool {
+    cfg!(feature = "rabbyung")
+}
+
+/// 是否编入神煞
+pub fn has_god() -> bool {
+    cfg!(feature = "god")
+}
+
+/// 已编入的八字提供器
+pub fn eight_char_providers() -> &'static [&'static str] {
+    EIGHT_CHAR_PROVIDERS
+}
+
+/// 已编入的童限提供器
+pub fn child_limit_providers() -> &'static [&'static str] {
+    CHILD_LIMIT_PROVIDERS
+}
+
+/// 星历系数表各级数保留项数的千分比（1000为全表）
+pub fn ephemeris_keep_permille() -> usize {
+    crate::sxtwl::ephemeris::KEEP_PERMILLE
+}
+
+/// 数据版本：本库版本及各内置数据表的来源，反馈计算差异时附上以确定所用的数据构建
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct VersionInfo {
+    /// 本库版本
+    crate_version: &'static str,
+    /// 星历系数表各级数保留项数的千分比
+    ephemeris_keep_permille: usize,
+    /// 星历系数表是否在运行时加载
+    external_ephemeris: bool,
+    /// 朔、气修正表原始数据的哈希
+    correction_table_hash: &'static str,
+    /// 法定假日数据快照日期，未启用holiday时为None
+    holiday_snapshot: Option<&'static str>,
+    /// 法定假日原始数据的哈希，未启用holiday时为None
+    holiday_hash: Option<&'static str>,
+}
+
+impl VersionInfo {
+    /// 本库版本
+    pub fn get_crate_version(&self) -> &'static str {
+        self.crate_version
+    }
+
+    /// 星历系数表各级数保留项数的千分比（1000为全表）
+    pub fn get_ephemeris_keep_permille(&self) -> usize {
+        self.ephemeris_keep_permille
+    }
+
+    /// 星历系数表是否在运行时加载（external-ephemeris）
+    pub fn is_external_ephemeris(&self) -> bool {
+        self.external_ephemeris
+    }
+
+    /// 朔、气修正表原始数据的哈希
+    pub fn get_correction_table_hash(&self) -> &'static str {
+        self.correction_table_hash
+    }
+
+    /// 法定假日数据快照日期
+    pub fn get_holiday_snapshot(&self) -> Option<&'static str> {
+        self.holiday_snapshot
+    }
+
+    /// 法定假日原始数据的哈希
+    pub fn get_holiday_hash(&self) -> Option<&'static str> {
+        self.holiday_hash
+    }
+
+    /// 已启用的特性
+    pub fn get_features(&self) -> &'static [&'static str] {
+        ENABLED
+    }
+}
+
+impl Display for VersionInfo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "sxtwl-rs {} 星历{}‰{} 气朔修正表{}",
+            self.crate_version,
+            self.ephemeris_keep_permille,
+            if self.external_ephemeris {
+                "(外部)"
+            } else {
+                ""
+            },
+            self.correction_table_hash
+        )?;
+        if let (Some(snapshot), Some(hash)) = (self.holiday_snapshot, self.holiday_hash) {
+            write!(f, " 法定假日{}({})", snapshot, hash)?;
+        }
+        write!(f, " 特性[{}]", ENABLED.join(","))
+    }
+}
+
+/// 本次构建的数据版本
+pub fn version_info() -> VersionInfo {
+    VersionInfo {
+        crate_version: env!("CARGO_PKG_VERSION"),
+        ephemeris_keep_permille: crate::sxtwl::ephemeris::KEEP_PERMILLE,
+        external_ephemeris: cfg!(feature = "external-ephemeris"),
+        correction_table_hash: crate::sxtwl::CORRECTION_TABLE_SOURCE_HASH,
+        #[cfg(feature = "holiday")]
+        holiday_snapshot: Some(crate::holiday::HOLIDAY_DATA_SNAPSHOT),
+        #[cfg(not(feature = "holiday"))]
+        holiday_snapshot: None,
+        #[cfg(feature = "holiday")]
+        holiday_hash: Some(crate::holiday::HOLIDAY_DATA_SOURCE_HASH),
+        #[cfg(not(feature = "holiday"))]
+        holiday_hash: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::{String, ToString};
+
+    use crate::features::{
+        VersionInfo, child_limit_providers, eight_char_providers, enabled, ephemeris_keep_permille,
+        has_holiday_data_until, has_rabbyung, is_enabled, version_info,
+    };
+
+    #[test]
+    fn test1() {
+        assert_eq!(cfg!(feature = "rabbyung"), has_rabbyung());
+        assert_eq!(cfg!(feature = "rabbyung"), is_enabled("rabbyung"));
+        assert!(!is_enabled("default"));
+        assert_eq!(
+            cfg!(feature = "holiday"),
+            has_holiday_data_until().is_some()
+        );
+        assert_eq!(
+            cfg!(feature = "eight-char-default-provider"),
+            eight_char_providers().contains(&"eight-char-default-provider")
+        );
+        assert!(
+            child_limit_providers()
+                .iter()
+                .all(|p| enabled().contains(p))
+        );
+        assert!(ephemeris_keep_permille() <= 1000);
+    }
+
+    #[cfg(feature = "holiday")]
+    #[test]
+    fn test2() {
+        assert_eq!(Some(2026), has_holiday_data_until());
+    }
+
+    #[test]
+    fn test3() {
+        let info: VersionInfo = version_info();
+        assert_eq!(env!("CARGO_PKG_VERSION"), info.get_crate_version());
+        assert_eq!(
+            ephemeris_keep_permille(),
+            info.get_ephemeris_keep_permille()
+        );
+        assert_eq!(16, info.get_correction_table_hash().len());
+        assert_eq!(
+            cfg!(feature = "holiday"),
+            info.get_holiday_snapshot().is_some()
+        );
+        assert_eq!(enabled(), info.get_features());
+        let s: String = info.to_string();
+        assert!(s.starts_with("sxtwl-rs "));
+        assert!(s.contains(info.get_correction_table_hash()));
+    }
+
+    #[cfg(feature = "holiday")]
+    #[test]
+    fn test4() {
+        assert_eq!(Some("2025-11-04"), version_info().get_holiday_snapshot());
+    }
+}
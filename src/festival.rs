@@ -4,12 +4,16 @@ use alloc::string::{String, ToString};
 
 use crate::enums::FestivalType;
 use crate::lunar::LunarDay;
-use crate::solar::{SolarDay, SolarTerm};
+use crate::solar::{SolarDay, SolarTerm, TermDayResolution};
 use crate::types::{AbstractCulture, Culture, Tyme};
 
 #[rustfmt::skip]
-pub static SOLAR_FESTIVAL_NAMES: [&str; 10] = ["元旦", "三八妇女节", "植树节", "五一劳动节", "五四青年节", "六一儿童节", "建党节", "八一建军节", "教师节", "国庆节"];
+pub static SOLAR_FESTIVAL_NAMES: [&str; 11] = ["元旦", "三八妇女节", "植树节", "五一劳动节", "五四青年节", "六一儿童节", "建党节", "八一建军节", "教师节", "国庆节", "护士节"];
 
+/// next()循环的节日数：最初的10个节日，其后追加的节日（护士节）保持索引不变，不参与循环
+const SOLAR_FESTIVAL_CYCLE_SIZE: usize = 10;
+
+/// 公历节日条目，同一节日在不同年代日期不同时可有多条，按有效年份区分
 #[derive(Debug, Clone, Copy)]
 pub struct SolarFestivalEntry {
     pub index: u8,
@@ -17,20 +21,34 @@ pub struct SolarFestivalEntry {
     pub month: u8,
     pub day: u8,
     pub start_year: i16,
+    /// 截止年（含），i16::MAX为沿用至今
+    pub end_year: i16,
+    /// 该条目的专名（如1932—1949年4月4日的四四儿童节），None时为节日索引对应的名称
+    pub name: Option<&'static str>,
 }
 
+impl SolarFestivalEntry {
+    /// 该年是否有效
+    pub fn is_valid_in(&self, year: isize) -> bool {
+        year >= self.start_year as isize && year <= self.end_year as isize
+    }
+}
+
+/// 公历节日表，按节日索引排列，新增节日追加在末尾。儿童节：1932年起为4月4日（四四儿童节），1950年起改为6月1日；教师节：1985年起为9月10日；护士节：国际护士会1974年起定于5月12日
 #[rustfmt::skip]
-pub const SOLAR_FESTIVAL_TABLE: [SolarFestivalEntry; 10] = [
-    SolarFestivalEntry { index: 0, festival_type: FestivalType::DAY, month: 1, day: 1, start_year: 1950 },
-    SolarFestivalEntry { index: 1, festival_type: FestivalType::DAY, month: 3, day: 8, start_year: 1950 },
-    SolarFestivalEntry { index: 2, festival_type: FestivalType::DAY, month: 3, day: 12, start_year: 1979 },
-    SolarFestivalEntry { index: 3, festival_type: FestivalType::DAY, month: 5, day: 1, start_year: 1950 },
-    SolarFestivalEntry { index: 4, festival_type: FestivalType::DAY, month: 5, day: 4, start_year: 1950 },
-    SolarFestivalEntry { index: 5, festival_type: FestivalType::DAY, month: 6, day: 1, start_year: 1950 },
-    SolarFestivalEntry { index: 6, festival_type: FestivalType::DAY, month: 7, day: 1, start_year: 1941 },
-    SolarFestivalEntry { index: 7, festival_type: FestivalType::DAY, month: 8, day: 1, start_year: 1933 },
-    SolarFestivalEntry { index: 8, festival_type: FestivalType::DAY, month: 9, day: 10, start_year: 1985 },
-    SolarFestivalEntry { index: 9, festival_type: FestivalType::DAY, month: 10, day: 1, start_year: 1950 },
+pub const SOLAR_FESTIVAL_TABLE: [SolarFestivalEntry; 12] = [
+    SolarFestivalEntry { index: 0, festival_type: FestivalType::DAY, month: 1, day: 1, start_year: 1950, end_year: i16::MAX, name: None },
+    SolarFestivalEntry { index: 1, festival_type: FestivalType::DAY, month: 3, day: 8, start_year: 1950, end_year: i16::MAX, name: None },
+    SolarFestivalEntry { index: 2, festival_type: FestivalType::DAY, month: 3, day: 12, start_year: 1979, end_year: i16::MAX, name: None },
+    SolarFestivalEntry { index: 3, festival_type: FestivalType::DAY, month: 5, day: 1, start_year: 1950, end_year: i16::MAX, name: None },
+    SolarFestivalEntry { index: 4, festival_type: FestivalType::DAY, month: 5, day: 4, start_year: 1950, end_year: i16::MAX, name: None },
+    SolarFestivalEntry { index: 5, festival_type: FestivalType::DAY, month: 4, day: 4, start_year: 1932, end_year: 1949, name: Some("四四儿童节") },
+    SolarFestivalEntry { index: 5, festival_type: FestivalType::DAY, month: 6, day: 1, start_year: 1950, end_year: i16::MAX, name: None },
+    SolarFestivalEntry { index: 6, festival_type: FestivalType::DAY, month: 7, day: 1, start_year: 1941, end_year: i16::MAX, name: None },
+    SolarFestivalEntry { index: 7, festival_type: FestivalType::DAY, month: 8, day: 1, start_year: 1933, end_year: i16::MAX, name: None },
+    SolarFestivalEntry { index: 8, festival_type: FestivalType::DAY, month: 9, day: 10, start_year: 1985, end_year: i16::MAX, name: None },
+    SolarFestivalEntry { index: 9, festival_type: FestivalType::DAY, month: 10, day: 1, start_year: 1950, end_year: i16::MAX, name: None },
+    SolarFestivalEntry { index: 10, festival_type: FestivalType::DAY, month: 5, day: 12, start_year: 1974, end_year: i16::MAX, name: None },
 ];
 
 /// 公历现代节日
@@ -44,43 +62,49 @@ pub struct SolarFestival {
     index: usize,
     /// 起始年
     start_year: isize,
+    /// 截止年（含）
+    end_year: isize,
+    /// 名称
+    name: &'static str,
 }
 
 impl Culture for SolarFestival {
     fn get_name(&self) -> String {
-        SOLAR_FESTIVAL_NAMES[self.index].to_string()
+        self.name.to_string()
     }
 }
 
 impl SolarFestival {
     pub fn from_ymd(year: isize, month: usize, day: usize) -> Option<Self> {
-        SOLAR_FESTIVAL_TABLE
+        Self::find(&SOLAR_FESTIVAL_TABLE, year, |entry| {
+            entry.month == month as u8 && entry.day == day as u8
+        })
+    }
+
+    pub fn from_index(year: isize, index: usize) -> Option<Self> {
+        Self::find(&SOLAR_FESTIVAL_TABLE, year, |entry| {
+            entry.index as usize == index
+        })
+    }
+
+    /// 查找该年有效的条目
+    fn find<F: Fn(&SolarFestivalEntry) -> bool>(
+        table: &[SolarFestivalEntry],
+        year: isize,
+        predicate: F,
+    ) -> Option<Self> {
+        table
             .iter()
-            .find(|entry| {
-                entry.month == month as u8
-                    && entry.day == day as u8
-                    && year >= entry.start_year as isize
-            })
+            .find(|entry| entry.is_valid_in(year) && predicate(entry))
             .map(|entry| Self {
                 festival_type: entry.festival_type.clone(),
-                day: SolarDay::from_ymd(year, month, day),
+                day: SolarDay::from_ymd(year, entry.month as usize, entry.day as usize),
                 index: entry.index as usize,
                 start_year: entry.start_year as isize,
-            })
-    }
-
-    pub fn from_index(year: isize, index: usize) -> Option<Self> {
-        SOLAR_FESTIVAL_TABLE
-            .get(index)
-            .filter(|entry| year >= entry.start_year as isize)
-            .map(|entry| {
-                let day = SolarDay::from_ymd(year, entry.month as usize, entry.day as usize);
-                Self {
-                    festival_type: entry.festival_type.clone(),
-                    day,
-                    index: entry.index as usize,
-                    start_year: entry.start_year as isize,
-                }
+                end_year: entry.end_year as isize,
+                name: entry
+                    .name
+                    .unwrap_or(SOLAR_FESTIVAL_NAMES[entry.index as usize]),
             })
     }
 
@@ -100,8 +124,17 @@ impl SolarFestival {
         self.start_year
     }
 
+    /// 截止年（含），沿用至今的为i16::MAX
+    pub fn get_end_year(&self) -> isize {
+        self.end_year
+    }
+
+    /// 在最初的10个节日中循环推移，追加的节日（索引不小于10）返回None
     pub fn next(&self, n: isize) -> Option<Self> {
-        let size: isize = SOLAR_FESTIVAL_NAMES.len() as isize;
+        if self.index >= SOLAR_FESTIVAL_CYCLE_SIZE {
+            return None;
+        }
+        let size: isize = SOLAR_FESTIVAL_CYCLE_SIZE as isize;
         let i: isize = self.get_index() as isize + n;
         Self::from_index(
             (self.day.get_year() * size + i) / size,
@@ -275,6 +308,13 @@ impl LunarFestival {
         self.solar_term.clone()
     }
 
+    /// 节气类节日的交节日判定，交节临近子夜时可提示“或前后一天”
+    pub fn get_term_day_resolution(&self) -> Option<TermDayResolution> {
+        self.solar_term
+            .clone()
+            .map(TermDayResolution::from_solar_term)
+    }
+
     pub fn next(&self, n: isize) -> Option<Self> {
         let size: isize = LUNAR_FESTIVAL_NAMES.len() as isize;
         let i: isize = self.get_index() as isize + n;
@@ -378,4 +418,74 @@ mod tests {
         let f: Option<SolarFestival> = SolarDay::from_ymd(1939, 5, 4).get_festival();
         assert_eq!(true, f.is_none());
     }
+
+    #[test]
+    fn test10() {
+        // 儿童节1932至1949年为4月4日，1950年起为6月1日
+        let f: SolarFestival = SolarDay::from_ymd(1940, 4, 4).get_festival().unwrap();
+        assert_eq!("1940年4月4日 四四儿童节", f.to_string());
+        assert_eq!(1949, f.get_end_year());
+        assert_eq!(
+            "1949年4月4日 四四儿童节",
+            SolarFestival::from_index(1949, 5).unwrap().to_string()
+        );
+        assert_eq!(
+            "1950年6月1日 六一儿童节",
+            SolarFestival::from_index(1950, 5).unwrap().to_string()
+        );
+        assert!(SolarDay::from_ymd(1931, 4, 4).get_festival().is_none());
+        assert!(SolarDay::from_ymd(1950, 4, 4).get_festival().is_none());
+        assert!(SolarDay::from_ymd(1949, 6, 1).get_festival().is_none());
+
+        // 教师节1985年起为9月10日
+        assert!(SolarFestival::from_ymd(1984, 9, 10).is_none());
+        assert!(SolarFestival::from_index(1984, 8).is_none());
+        let f: SolarFestival = SolarFestival::from_ymd(1985, 9, 10).unwrap();
+        assert_eq!("1985年9月10日 教师节", f.to_string());
+        assert_eq!(i16::MAX as isize, f.get_end_year());
+
+        // 护士节1974年起为5月12日
+        assert!(SolarDay::from_ymd(1973, 5, 12).get_festival().is_none());
+        assert_eq!(
+            "1974年5月12日 护士节",
+            SolarDay::from_ymd(1974, 5, 12)
+                .get_festival()
+                .unwrap()
+                .to_string()
+        );
+        assert_eq!(
+            "2024年6月1日 六一儿童节",
+            SolarFestival::from_index(2024, 4)
+                .unwrap()
+                .next(1)
+                .unwrap()
+                .to_string()
+        );
+        // 追加的护士节索引为10，不参与next()循环
+        let f: SolarFestival = SolarFestival::from_index(2024, 10).unwrap();
+        assert_eq!("2024年5月12日 护士节", f.to_string());
+        assert!(f.next(1).is_none());
+        assert_eq!(
+            "2025年1月1日 元旦",
+            SolarFestival::from_index(2024, 9)
+                .unwrap()
+                .next(1)
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test11() {
+        let f: LunarFestival = LunarFestival::from_index(2009, 4).unwrap();
+        assert!(f.get_term_day_resolution().unwrap().is_ambiguous());
+        let f: LunarFestival = LunarFestival::from_index(2024, 4).unwrap();
+        assert!(!f.get_term_day_resolution().unwrap().is_ambiguous());
+        assert!(
+            LunarFestival::from_index(2024, 0)
+                .unwrap()
+                .get_term_day_resolution()
+                .is_none()
+        );
+    }
 }
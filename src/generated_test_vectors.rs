@@ -0,0 +1,263 @@
+// 种子: 2024
+// 自动生成的测试向量（reference::generate_test_vectors，全精度星历计算），请勿手工修改
+// 重新生成：SXTWL_TEST_VECTORS=2024,256 cargo test --features reference
+
+/// 每行：公历日期,农历年,农历月（闰月为负）,农历日,年干支,月干支,日干支,所在节气,节气儒略日
+pub static TEST_VECTORS: &str = "\
+1942-01-20,1941,12,4,辛巳,辛丑,癸酉,小寒,2430365.79326491
+1979-04-17,1979,3,21,己未,戊辰,甲寅,清明,2443969.22080271
+1919-10-26,1919,9,3,己未,甲戌,辛亥,霜降,2422256.30641370
+2030-02-27,2030,1,25,庚戌,戊寅,癸巳,雨水,2462551.45829818
+1976-08-22,1976,7,27,丙辰,丙申,丙午,立秋,2442998.23495972
+1930-09-26,1930,8,5,庚午,乙酉,己卯,秋分,2426243.60824523
+2053-04-11,2053,2,23,癸酉,丙辰,丁丑,清明,2470997.14902458
+1952-04-06,1952,3,12,壬辰,甲辰,壬午,清明,2434107.67711205
+1965-06-09,1965,5,10,乙巳,壬午,甲午,芒种,2438917.75145574
+2006-11-08,2006,9,18,丙戌,己亥,辛丑,立冬,2454047.39920222
+1908-07-01,1908,6,3,戊申,戊午,丁巳,夏至,2418114.67987600
+2099-04-10,2099,-2,20,己未,戊辰,丁丑,清明,2487798.28571562
+1990-06-01,1990,5,9,庚午,辛巳,丁酉,小满,2448033.15096200
+2038-01-06,2037,12,2,丁巳,癸丑,癸未,小寒,2465429.10195157
+2039-04-01,2039,3,8,己未,丁卯,癸丑,春分,2465868.60559243
+2006-11-14,2006,9,24,丙戌,己亥,丁未,立冬,2454047.39920222
+2011-05-26,2011,4,24,辛卯,癸巳,辛巳,小满,2455703.22303565
+1989-09-26,1989,8,27,己巳,癸酉,己丑,秋分,2447792.88862025
+2018-06-12,2018,4,29,戊戌,戊午,乙亥,芒种,2458275.56186171
+2047-05-25,2047,5,1,丁卯,乙巳,己丑,小满,2468851.93055826
+1929-05-29,1929,4,21,己巳,己巳,甲戌,小满,2425753.40801905
+1940-01-10,1939,12,2,己卯,丁丑,壬子,小寒,2429635.30810353
+1990-10-24,1990,9,7,庚午,丙戌,壬戌,霜降,2448188.50967981
+2099-01-17,2098,12,27,戊午,乙丑,甲寅,小寒,2487708.90226774
+2049-08-16,2049,7,18,己巳,壬申,癸亥,立秋,2469660.87362317
+2074-03-02,2074,2,5,甲午,丙寅,丁亥,雨水,2478622.14725226
+1937-06-17,1937,5,9,丁丑,丙午,乙亥,芒种,2428690.97416674
+1988-07-13,1988,5,30,戊辰,己未,己巳,小暑,2447349.73118590
+1998-03-04,1998,2,6,戊寅,甲寅,庚戌,雨水,2450863.70478065
+2075-10-04,2075,8,25,乙未,乙酉,戊辰,秋分,2479203.70751177
+2033-11-27,2033,11,6,癸丑,癸亥,壬午,小雪,2463923.84457023
+1998-10-26,1998,9,7,戊寅,壬戌,丙午,霜降,2451110.45734746
+1912-01-10,1911,11,22,辛亥,辛丑,乙酉,小寒,2419408.50519863
+1940-12-08,1940,11,10,庚辰,戊子,乙酉,大雪,2429971.08183659
+1936-08-17,1936,7,1,丙子,丙申,辛未,立秋,2428388.57164401
+2035-05-04,2035,3,27,乙卯,庚辰,乙丑,谷雨,2464438.07568355
+1937-01-01,1936,11,19,丙子,庚子,戊子,冬至,2428524.85182162
+1954-07-08,1954,6,9,甲午,辛未,乙丑,小暑,2434931.51331450
+2034-09-02,2034,7,20,甲寅,壬申,辛酉,处暑,2464197.86650697
+2085-02-21,2085,1,27,乙巳,戊寅,丙子,雨水,2482639.80524962
+1943-07-31,1943,6,30,癸未,己未,庚寅,大暑,2430929.58645846
+2001-07-17,2001,5,27,辛巳,乙未,辛巳,小暑,2452097.87965155
+1933-08-04,1933,6,13,癸酉,己未,壬寅,大暑,2427277.17039137
+2065-05-01,2065,3,26,乙酉,庚辰,庚子,谷雨,2475395.33745961
+1953-10-17,1953,9,10,癸巳,壬戌,辛丑,寒露,2434659.42389684
+2000-04-28,2000,3,24,庚辰,庚辰,丙辰,谷雨,2451654.61076072
+1962-08-17,1962,7,18,壬寅,戊申,丁亥,立秋,2437884.85671441
+2068-11-13,2068,10,19,戊子,癸亥,壬申,立冬,2476692.42597862
+2050-02-28,2050,2,8,庚午,戊寅,己卯,雨水,2469856.31611696
+1985-02-23,1985,1,4,乙丑,戊寅,癸巳,雨水,2446115.54677589
+2069-09-28,2069,8,14,己丑,癸酉,辛卯,秋分,2477012.24437824
+1922-03-10,1922,2,12,壬戌,癸卯,丁丑,惊蛰,2423120.19015364
+2063-05-19,2063,4,22,癸未,丁巳,丁未,立夏,2474680.26969005
+1909-11-24,1909,10,12,己酉,乙亥,戊子,小雪,2418633.76405883
+2026-02-05,2025,12,18,丙午,庚寅,庚戌,立春,2461075.66814573
+1934-08-16,1934,7,7,甲戌,壬申,己未,立秋,2427658.08585716
+2088-05-25,2088,-4,5,戊申,丁巳,乙丑,小满,2483826.84736720
+1947-09-14,1947,7,30,丁亥,己酉,丙申,白露,2432437.34795025
+2027-11-28,2027,11,1,丁未,辛亥,辛亥,小雪,2461732.38625198
+1925-07-27,1925,6,7,乙丑,癸未,壬子,大暑,2424355.23943446
+1986-12-16,1986,11,15,丙寅,庚子,甲午,大雪,2446772.25064848
+1986-03-20,1986,2,11,丙寅,辛卯,癸亥,惊蛰,2446495.71676215
+2014-08-11,2014,7,16,甲午,壬申,甲寅,立秋,2456877.41837915
+1985-04-30,1985,3,11,乙丑,庚辰,己亥,谷雨,2446175.97622626
+1984-05-07,1984,4,7,甲子,己巳,辛丑,立夏,2445826.16038363
+2097-12-29,2097,11,26,丁巳,壬子,庚寅,冬至,2487328.94266651
+2046-03-27,2046,2,20,丙寅,辛卯,乙酉,春分,2468425.29024838
+1940-10-26,1940,9,26,庚辰,丙戌,壬寅,霜降,2429926.40228692
+1985-12-29,1985,11,18,乙丑,戊子,壬寅,冬至,2446421.75532814
+2090-04-01,2090,3,2,庚戌,己卯,辛巳,春分,2484495.95971847
+2056-01-25,2055,12,9,乙亥,己丑,丙子,大寒,2472018.18959842
+1941-06-22,1941,5,28,辛巳,甲午,辛丑,夏至,2430167.64808904
+1975-09-29,1975,8,24,乙卯,乙酉,戊寅,秋分,2442679.49666756
+2030-03-19,2030,2,16,庚戌,己卯,癸丑,惊蛰,2462566.37729182
+1999-02-01,1998,12,16,戊寅,乙丑,甲申,大寒,2451199.35926563
+2043-07-13,2043,6,7,癸亥,己未,丁巳,小暑,2467438.01936208
+1958-12-22,1958,11,12,戊戌,甲子,癸酉,冬至,2436560.19421432
+2047-09-21,2047,8,2,丁卯,己酉,戊子,白露,2468961.52656283
+1959-02-11,1959,1,4,己亥,丙寅,甲子,立春,2436604.40427898
+1960-04-22,1960,3,27,庚子,庚辰,庚辰,谷雨,2437044.92072637
+2037-08-07,2037,6,26,丁巳,戊申,辛亥,立秋,2465277.98823155
+1994-05-30,1994,4,20,甲戌,己巳,丙辰,小满,2449494.11699164
+2003-12-27,2003,12,5,癸未,甲子,甲戌,冬至,2452996.12763601
+2094-05-18,2094,4,5,甲寅,己巳,己丑,立夏,2486002.77480688
+1949-10-29,1949,9,8,己丑,甲戌,壬辰,霜降,2433213.58538678
+1903-10-12,1903,8,22,癸卯,壬戌,癸酉,寒露,2416397.32065312
+2057-02-17,2057,1,14,丁丑,壬寅,乙巳,立春,2472398.19624067
+1994-09-07,1994,8,2,甲戌,壬申,丙申,处暑,2449588.19704802
+2067-04-14,2067,3,1,丁亥,甲辰,癸巳,清明,2476110.52821006
+2017-05-29,2017,5,4,丁酉,乙巳,丙辰,小满,2457894.68813120
+2015-03-27,2015,2,8,乙未,己卯,壬寅,春分,2457102.78134232
+1966-12-08,1966,10,27,丙午,庚子,辛丑,大雪,2439467.40121635
+1963-09-03,1963,7,16,癸卯,庚申,己酉,处暑,2438265.70658965
+1985-07-29,1985,6,12,乙丑,癸未,己巳,大暑,2446269.73363608
+2033-05-01,2033,4,3,癸丑,丙辰,壬子,谷雨,2463707.59245904
+2018-07-19,2018,6,7,戊戌,己未,壬子,小暑,2458306.98736560
+2007-05-08,2007,3,22,丁亥,乙巳,壬寅,立夏,2454226.72249557
+2046-06-27,2046,5,24,丙寅,甲午,丁巳,夏至,2468518.01022456
+1900-06-03,1900,5,7,庚子,辛巳,丁未,小满,2415161.38674732
+2081-04-28,2081,3,20,辛丑,壬辰,辛酉,谷雨,2481239.20940521
+2082-08-05,2082,7,12,壬寅,丁未,乙巳,大暑,2481698.16182345
+1942-08-11,1942,6,30,壬午,戊申,丙申,立秋,2430580.02103893
+1933-08-30,1933,7,10,癸酉,庚申,戊辰,处暑,2427308.45299852
+1913-04-08,1913,3,2,癸丑,丙辰,己未,清明,2419863.23323198
+2082-03-01,2082,2,3,壬寅,壬寅,戊辰,雨水,2481544.08359323
+2023-07-27,2023,6,10,癸卯,己未,丙戌,大暑,2460148.90993905
+2083-07-07,2083,5,23,癸卯,己未,辛巳,小暑,2482047.67765972
+1930-01-22,1929,12,23,己巳,丁丑,壬申,大寒,2425997.60620698
+2078-06-10,2078,5,1,戊戌,戊午,戊子,芒种,2480190.05885356
+2000-05-05,2000,4,2,庚辰,辛巳,癸亥,立夏,2451670.03483562
+2026-05-23,2026,4,7,丙午,癸巳,丁酉,小满,2461181.85885063
+2073-08-12,2073,7,9,癸巳,庚申,乙丑,立秋,2478426.68070487
+1967-07-08,1967,6,1,丁未,丁未,癸酉,小暑,2439679.66202446
+2019-07-19,2019,6,17,己亥,辛未,丁巳,小暑,2458672.22250922
+2099-01-31,2099,1,11,戊午,乙丑,戊辰,大寒,2487723.62659899
+1999-06-27,1999,5,14,己卯,庚午,庚戌,夏至,2451351.65910717
+1998-06-15,1998,5,21,戊寅,戊午,癸巳,芒种,2450970.71761976
+2068-07-12,2068,6,14,戊子,己未,戊辰,小暑,2476569.05339111
+2016-04-15,2016,3,9,丙申,壬辰,丁卯,清明,2457483.18577555
+2006-07-04,2006,6,9,丙戌,甲午,甲午,夏至,2453908.35129138
+1993-08-25,1993,7,8,癸酉,庚申,戊寅,处暑,2449222.95159969
+2039-05-06,2039,4,14,己未,己巳,戊子,立夏,2465914.47095720
+1940-07-22,1940,6,18,庚辰,癸未,丙寅,小暑,2429818.13056308
+2018-08-28,2018,7,18,戊戌,庚申,壬辰,处暑,2458354.00590982
+2029-05-25,2029,4,13,己酉,己巳,乙卯,小满,2462277.58046186
+2000-04-26,2000,3,22,庚辰,庚辰,甲寅,谷雨,2451654.61076072
+2072-04-26,2072,3,9,壬辰,甲辰,壬申,谷雨,2477952.03819919
+2042-04-21,2042,3,2,壬戌,甲辰,己丑,谷雨,2466994.77764782
+2005-04-15,2005,3,7,乙酉,庚辰,己巳,清明,2453465.52380607
+2064-05-14,2064,3,28,甲申,己巳,戊申,立夏,2475045.51288791
+2038-01-02,2037,11,27,丁巳,壬子,己卯,冬至,2465414.38040091
+2087-09-28,2087,9,2,丁未,己酉,乙丑,秋分,2483586.60301694
+2037-06-03,2037,4,20,丁巳,乙巳,丙午,小满,2465199.52463854
+2084-08-21,2084,7,20,甲辰,壬申,壬申,立秋,2482444.33081374
+1983-04-24,1983,3,12,癸亥,丙辰,壬午,谷雨,2445445.49316222
+2024-10-21,2024,9,19,甲辰,甲戌,戊午,寒露,2460591.62496905
+1975-11-02,1975,9,29,乙卯,丙戌,壬子,霜降,2442709.87917750
+1934-05-15,1934,4,3,甲戌,己巳,丙戌,立夏,2427564.06300430
+1904-08-01,1904,6,20,甲辰,辛未,丁卯,大暑,2416685.15943116
+1912-09-08,1912,7,27,壬子,己酉,丁亥,白露,2419653.87891894
+2081-03-25,2081,2,16,辛丑,辛卯,丁亥,春分,2481208.77377535
+2024-08-26,2024,7,23,甲辰,壬申,壬戌,处暑,2460545.45489188
+1906-07-16,1906,5,25,丙午,乙未,辛酉,小暑,2417399.92726530
+1939-05-16,1939,3,27,己卯,己巳,癸丑,立夏,2429390.26460915
+1975-09-22,1975,8,17,乙卯,乙酉,辛未,白露,2442664.10645172
+1977-01-11,1976,11,22,丙辰,辛丑,戊辰,小寒,2443149.28545202
+1952-10-18,1952,8,30,壬辰,庚戌,丁酉,寒露,2434294.18917631
+2094-10-09,2094,9,1,甲寅,甲戌,癸丑,寒露,2486158.58006947
+1901-12-31,1901,11,21,辛丑,庚子,癸未,冬至,2415741.35873951
+1944-04-19,1944,3,27,甲申,戊辰,癸丑,清明,2431185.74580614
+1914-09-01,1914,7,12,甲寅,壬申,庚寅,处暑,2420368.85386834
+2080-11-13,2080,10,3,庚子,丁亥,乙亥,立冬,2481075.34628456
+2093-01-17,2092,12,20,壬子,癸丑,癸未,小寒,2485517.44932865
+1966-05-06,1966,-3,16,丙午,癸巳,乙丑,立夏,2439251.81280326
+2047-07-11,2047,-5,19,丁卯,丁未,丙子,小暑,2468898.97955687
+2003-03-01,2003,1,29,癸未,甲寅,癸酉,雨水,2452689.91682190
+1915-08-14,1915,7,4,乙卯,甲申,丁丑,立秋,2420718.49144510
+1929-12-11,1929,11,11,己巳,丙子,庚寅,大雪,2425953.41416760
+2090-03-13,2090,2,13,庚戌,己卯,壬戌,惊蛰,2484480.93160615
+2025-08-17,2025,-6,24,乙巳,甲申,戊午,立秋,2460895.07748519
+2055-10-15,2055,8,25,乙亥,丙戌,甲午,寒露,2471914.13828380
+2013-03-05,2013,1,24,癸巳,乙卯,庚午,惊蛰,2456357.26031558
+1975-03-25,1975,2,13,乙卯,己卯,庚午,春分,2442493.08100332
+1912-01-17,1911,11,29,辛亥,辛丑,壬辰,小寒,2419408.50519863
+1913-09-06,1913,8,6,癸丑,庚申,庚寅,处暑,2420003.61677630
+1939-04-06,1939,2,17,己卯,戊辰,癸酉,清明,2429359.52596849
+1995-04-23,1995,3,24,乙亥,庚辰,甲申,谷雨,2449828.38991962
+2064-11-01,2064,9,23,甲申,甲戌,己亥,霜降,2475216.44606561
+2064-02-06,2063,12,20,甲申,丙寅,庚午,立春,2474954.88531909
+2001-03-07,2001,2,13,辛巳,辛卯,己巳,惊蛰,2451974.35588511
+1927-06-08,1927,5,9,丁卯,丙午,癸酉,芒种,2425038.55884941
+2077-05-30,2077,-4,9,丁酉,乙巳,壬申,小满,2479809.19791632
+2048-06-26,2048,5,16,戊辰,戊午,丁卯,夏至,2469248.49585070
+2052-06-26,2052,5,30,壬申,丙午,戊子,夏至,2470709.46967515
+1944-02-14,1944,1,21,甲申,丙寅,戊申,立春,2431125.76591679
+1994-11-01,1994,9,28,甲戌,甲戌,辛卯,霜降,2449649.48334383
+1903-09-25,1903,8,5,癸卯,辛酉,丙辰,秋分,2416382.07199829
+1925-06-09,1925,-4,19,乙丑,壬午,甲子,芒种,2424308.08081456
+2030-10-24,2030,9,28,庚戌,丙戌,壬辰,霜降,2462798.20872992
+1943-11-27,1943,11,1,癸未,癸亥,己丑,小雪,2431052.01488724
+2020-10-13,2020,8,27,庚子,丙戌,己丑,寒露,2459130.66337037
+1990-05-27,1990,5,4,庚午,辛巳,壬辰,小满,2448033.15096200
+1915-09-22,1915,8,14,乙卯,乙酉,丙辰,白露,2420749.59519395
+1917-08-20,1917,7,3,丁巳,戊申,甲午,立秋,2421448.97925018
+1948-02-04,1947,12,25,丁亥,癸丑,己未,大寒,2432571.97108402
+1979-04-02,1979,3,6,己未,丁卯,己亥,春分,2443954.05689066
+2015-08-06,2015,6,22,乙未,癸未,甲寅,大暑,2457226.97947315
+2038-07-02,2038,6,1,戊午,戊午,庚辰,夏至,2465596.08987233
+2085-05-17,2085,4,24,乙巳,辛巳,辛丑,立夏,2482715.59239987
+1985-11-29,1985,10,18,乙丑,丁亥,壬申,小雪,2446392.20192605
+1973-02-20,1973,1,18,癸丑,甲寅,丁亥,雨水,2441732.62579555
+2069-11-05,2069,9,22,己丑,甲戌,己巳,霜降,2477042.65429037
+2029-09-29,2029,8,22,己酉,癸酉,壬戌,秋分,2462402.56841502
+2090-06-06,2090,5,9,庚戌,壬午,丁亥,芒种,2484572.95483344
+2007-06-04,2007,4,19,丁亥,乙巳,己巳,小满,2454242.25829430
+1997-06-24,1997,5,20,丁丑,丙午,丁酉,夏至,2450621.18051110
+1907-03-08,1907,1,24,丁未,癸卯,丙辰,惊蛰,2417641.56047865
+2016-04-21,2016,3,15,丙申,壬辰,癸酉,谷雨,2457498.47876909
+1904-07-11,1904,5,28,甲辰,辛未,丙午,小暑,2416669.43867364
+1911-06-18,1911,5,22,辛亥,甲午,己未,芒种,2419194.69296162
+1976-02-20,1976,1,21,丙辰,庚寅,壬寅,雨水,2442828.36105088
+1903-12-27,1903,11,9,癸卯,甲子,己丑,冬至,2416471.84751269
+1998-11-07,1998,9,19,戊寅,癸亥,戊午,立冬,2451125.46415700
+2005-09-17,2005,8,14,乙酉,乙酉,甲辰,白露,2453621.37268771
+2088-03-31,2088,3,9,戊申,乙卯,庚午,春分,2483765.47020666
+1903-05-05,1903,4,9,癸卯,丙辰,癸巳,谷雨,2416226.12406011
+2052-10-05,2052,-8,13,壬申,己酉,己巳,秋分,2470803.13597713
+1986-01-28,1985,12,19,乙丑,己丑,壬申,大寒,2446451.19874660
+1968-07-08,1968,6,13,戊申,己未,己卯,小暑,2440044.90389854
+2027-10-15,2027,9,16,丁未,庚戌,丁卯,寒露,2461687.34518767
+1974-09-07,1974,7,21,甲寅,壬申,辛亥,处暑,2442283.35324130
+2098-10-06,2098,9,12,戊午,辛酉,辛未,秋分,2487604.26680354
+1978-06-16,1978,5,11,戊午,戊午,己酉,芒种,2443665.89102708
+1999-10-09,1999,9,1,己卯,甲戌,甲午,寒露,2451460.57524687
+1966-06-13,1966,4,25,丙午,甲午,癸卯,芒种,2439282.99278478
+1963-02-12,1963,1,19,癸卯,甲寅,丙戌,立春,2438065.38037192
+2077-11-19,2077,10,4,丁酉,辛亥,乙丑,立冬,2479979.61823407
+1904-05-11,1904,3,26,甲辰,己巳,乙巳,立夏,2416606.80456211
+2074-01-22,2073,12,25,癸巳,乙丑,戊申,大寒,2478592.56537261
+2067-08-22,2067,7,13,丁亥,戊申,癸卯,立秋,2476235.22576905
+2033-12-24,2033,-11,3,癸丑,甲子,己酉,冬至,2463953.40695604
+2093-02-01,2093,1,6,壬子,癸丑,戊戌,大寒,2485532.17623116
+2031-08-15,2031,6,28,辛亥,丙申,丁亥,立秋,2463086.52981316
+1936-08-15,1936,6,29,丙子,丙申,己巳,立秋,2428388.57164401
+2006-12-29,2006,11,10,丙戌,庚子,壬辰,冬至,2454091.84867567
+1906-04-22,1906,3,29,丙午,壬辰,丙申,谷雨,2417321.86053497
+1976-07-06,1976,6,10,丙辰,甲午,己未,夏至,2442951.10012325
+2035-02-22,2035,1,15,乙卯,戊寅,甲寅,雨水,2464377.67789315
+1996-01-27,1995,12,8,乙亥,己丑,癸亥,大寒,2450103.61978658
+1943-06-28,1943,5,26,癸未,戊午,丁巳,夏至,2430898.13352458
+2079-11-11,2079,10,18,己亥,乙亥,丁卯,立冬,2480710.10215641
+1928-08-01,1928,6,16,戊辰,己未,癸酉,大暑,2425450.95987789
+1945-08-28,1945,7,21,乙酉,甲申,己巳,处暑,2431691.35781871
+1923-11-15,1923,10,8,癸亥,癸亥,壬辰,立冬,2423732.27801004
+1969-07-29,1969,6,16,己酉,辛未,乙巳,大暑,2440425.86675785
+1961-06-10,1961,4,27,辛丑,甲午,甲戌,芒种,2437456.78193913
+2013-08-20,2013,7,14,癸巳,庚申,戊午,立秋,2456512.18080795
+1945-06-04,1945,4,24,乙酉,辛巳,甲辰,小满,2431597.27791934
+2022-10-15,2022,9,20,壬寅,庚戌,辛丑,寒露,2459861.14054446
+2010-08-23,2010,7,14,庚寅,甲申,乙巳,处暑,2455432.06038506
+2077-04-08,2077,3,16,丁酉,甲辰,庚辰,清明,2479762.96438829
+2019-02-09,2019,1,5,己亥,丙寅,丁丑,立春,2458518.96822291
+1913-07-26,1913,6,23,癸丑,己未,戊申,大暑,2419972.33589430
+1942-09-20,1942,8,11,壬午,己酉,丙子,白露,2430611.12924701
+1953-06-16,1953,5,6,癸巳,戊午,戊戌,芒种,2434534.84449189
+1953-08-09,1953,6,30,癸巳,庚申,壬辰,立秋,2434597.67679225
+1902-04-14,1902,3,7,壬寅,甲辰,丁卯,清明,2415845.56766754
+1980-04-28,1980,3,14,庚申,庚辰,辛未,谷雨,2444349.76575503
+2052-08-20,2052,7,26,壬申,戊申,癸未,立秋,2470756.60650663
+1919-07-31,1919,7,5,己未,辛未,甲申,大暑,2422163.78085586
+2071-03-07,2071,2,6,辛卯,辛卯,丙子,惊蛰,2477541.32818781
+2042-08-18,2042,7,3,壬戌,戊申,戊子,立秋,2467104.19363679
+2097-08-28,2097,7,22,丁巳,戊申,丁亥,处暑,2487208.09871573
+1904-04-25,1904,3,10,甲辰,戊辰,己丑,谷雨,2416591.36259560
+2080-08-15,2080,7,1,庚子,甲申,乙巳,立秋,2480983.37715856
+2100-11-14,2100,10,13,庚申,丁亥,庚申,立冬,2488380.18062226
+";
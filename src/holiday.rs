@@ -1,14 +1,46 @@
 use core::fmt::{Display, Formatter};
+use core::ops::RangeInclusive;
 
 use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
-use crate::generated_holidays_data::LEGAL_HOLIDAY_TABLE;
-use crate::solar::SolarDay;
-use crate::types::Culture;
+use crate::enums::Projection;
+use crate::generated_holidays_data::{
+    LEGAL_HOLIDAY_FIRST_YEAR, LEGAL_HOLIDAY_SNAPSHOT, LEGAL_HOLIDAY_SOURCE_HASH,
+    LEGAL_HOLIDAY_TABLE, LEGAL_HOLIDAY_YEAR_OFFSETS, LegalHolidayEntry,
+};
+use crate::lunar::LunarDay;
+use crate::solar::{SolarDay, SolarTermDay};
+use crate::types::{Culture, Tyme};
 
 #[rustfmt::skip]
 pub static LEGAL_HOLIDAY_NAMES: [&str; 9] = ["元旦节", "春节", "清明节", "劳动节", "端午节", "中秋节", "国庆节", "国庆中秋", "抗战胜利日"];
 
+/// 内置法定假日数据覆盖的年份（含首尾），更新数据时须同步修改，否则编译失败
+pub const HOLIDAY_YEARS: RangeInclusive<isize> = 2001..=2026;
+
+/// 内置法定假日数据的快照日期（所依据节假日安排通知的发布日期，YYYY-MM-DD）
+pub const HOLIDAY_DATA_SNAPSHOT: &str = LEGAL_HOLIDAY_SNAPSHOT;
+
+/// 内置法定假日原始数据的哈希（FNV-1a 64位，十六进制）
+pub const HOLIDAY_DATA_SOURCE_HASH: &str = LEGAL_HOLIDAY_SOURCE_HASH;
+
+const _: () = {
+    assert!(LEGAL_HOLIDAY_FIRST_YEAR as isize == *HOLIDAY_YEARS.start());
+    assert!(
+        LEGAL_HOLIDAY_FIRST_YEAR as isize + LEGAL_HOLIDAY_YEAR_OFFSETS.len() as isize - 2
+            == *HOLIDAY_YEARS.end()
+    );
+    assert!(
+        LEGAL_HOLIDAY_YEAR_OFFSETS[LEGAL_HOLIDAY_YEAR_OFFSETS.len() - 1] as usize
+            == LEGAL_HOLIDAY_TABLE.len()
+    );
+    assert!(LEGAL_HOLIDAY_TABLE[0].year as isize == *HOLIDAY_YEARS.start());
+    assert!(
+        LEGAL_HOLIDAY_TABLE[LEGAL_HOLIDAY_TABLE.len() - 1].year as isize == *HOLIDAY_YEARS.end()
+    );
+};
+
 /// 法定假日（自2001-12-29起）
 #[derive(Debug, Copy, Clone)]
 pub struct LegalHoliday {
@@ -18,6 +50,14 @@ pub struct LegalHoliday {
     index: usize,
     /// 是否上班
     work: bool,
+    /// 假期编号
+    period: usize,
+    /// 假期第几天（调休上班为0）
+    day_index: usize,
+    /// 假期共几天
+    day_count: usize,
+    /// 数据来源
+    projection: Projection,
 }
 
 impl Culture for LegalHoliday {
@@ -28,19 +68,87 @@ impl Culture for LegalHoliday {
 
 impl LegalHoliday {
     pub fn from_ymd(year: isize, month: usize, day: usize) -> Option<Self> {
-        LEGAL_HOLIDAY_TABLE
-            .iter()
-            .find(|entry| {
-                entry.year == year as u16 && entry.month == month as u8 && entry.day == day as u8
-            })
-            .map(|entry| {
-                let solar_day = SolarDay::from_ymd(year, month, day);
-                Self {
-                    day: solar_day,
-                    index: entry.index as usize,
-                    work: entry.work,
-                }
+        let entry: &LegalHolidayEntry = &LEGAL_HOLIDAY_TABLE[Self::find_index(year, month, day)?];
+        Some(Self {
+            day: SolarDay::from_ymd(year, month, day),
+            index: entry.index as usize,
+            work: entry.work,
+            period: entry.period as usize,
+            day_index: entry.day_index as usize,
+            day_count: entry.day_count as usize,
+            projection: Projection::EXACT,
+        })
+    }
+
+    /// 条目在内置数据中的下标：按年份下标取出当年条目，再按月日二分查找
+    fn find_index(year: isize, month: usize, day: usize) -> Option<usize> {
+        let i: usize = usize::try_from(year - LEGAL_HOLIDAY_FIRST_YEAR as isize).ok()?;
+        if i + 1 >= LEGAL_HOLIDAY_YEAR_OFFSETS.len() {
+            return None;
+        }
+        let (start, end) = (
+            LEGAL_HOLIDAY_YEAR_OFFSETS[i] as usize,
+            LEGAL_HOLIDAY_YEAR_OFFSETS[i + 1] as usize,
+        );
+        LEGAL_HOLIDAY_TABLE[start..end]
+            .binary_search_by_key(&(month, day), |entry| {
+                (entry.month as usize, entry.day as usize)
             })
+            .ok()
+            .map(|j| start + j)
+    }
+
+    /// 内置数据的最后一年
+    pub fn get_last_year() -> isize {
+        LEGAL_HOLIDAY_TABLE
+            .last()
+            .map_or(0, |entry| entry.year as isize)
+    }
+
+    /// 内置数据年份内同from_ymd，之后的年份按现行放假办法推算法定假日（元旦1天，春节除夕至初三4天，清明、端午、中秋各1天，劳动节2天，国庆节3天；与国庆重叠的中秋按国庆计），不含调休、补休
+    pub fn from_ymd_with_projection(year: isize, month: usize, day: usize) -> Option<Self> {
+        if year <= Self::get_last_year() {
+            return Self::from_ymd(year, month, day);
+        }
+        let solar_day: SolarDay = SolarDay::new(year, month, day).ok()?;
+        let (index, day_index, day_count) = Self::project(solar_day)?;
+        Some(Self {
+            day: solar_day,
+            index,
+            work: false,
+            period: 0,
+            day_index,
+            day_count,
+            projection: Projection::PROJECTED,
+        })
+    }
+
+    /// 推算的（名称索引，假期第几天，假期共几天）
+    fn project(day: SolarDay) -> Option<(usize, usize, usize)> {
+        match (day.get_month(), day.get_day()) {
+            (1, 1) => return Some((0, 1, 1)),
+            (5, d @ 1..=2) => return Some((3, d, 2)),
+            (10, d @ 1..=3) => return Some((6, d, 3)),
+            _ => {}
+        }
+        let term_day: SolarTermDay = day.get_term_day();
+        if term_day.get_day_index() == 0 && term_day.get_name() == "清明" {
+            return Some((2, 1, 1));
+        }
+        let next: LunarDay = day.next(1).get_lunar_day();
+        if next.get_lunar_month().get_month_with_leap() == 1 && next.get_day() == 1 {
+            return Some((1, 1, 4));
+        }
+        let lunar: LunarDay = day.get_lunar_day();
+        match (
+            lunar.get_lunar_month().get_month_with_leap(),
+            lunar.get_day(),
+        ) {
+            (1, d @ 1..=3) => Some((1, d + 1, 4)),
+            (5, 5) => Some((4, 1, 1)),
+            (8, 15) => Some((5, 1, 1)),
+            _ => None,
+        }
     }
 
     pub fn get_day(&self) -> SolarDay {
@@ -51,17 +159,62 @@ impl LegalHoliday {
         self.work
     }
 
+    /// 假期第几天（从1开始，调休上班为0）
+    pub fn get_day_index(&self) -> usize {
+        self.day_index
+    }
+
+    /// 假期共几天
+    pub fn get_day_count(&self) -> usize {
+        self.day_count
+    }
+
+    /// 数据来源
+    pub fn get_projection(&self) -> Projection {
+        self.projection
+    }
+
+    /// 所属假期的起止日（含）及天数，调休上班日归入对应的假期
+    pub fn get_period(&self) -> (SolarDay, SolarDay, usize) {
+        if self.projection == Projection::PROJECTED {
+            let start: SolarDay = self.day.next(1 - self.day_index as isize);
+            return (
+                start,
+                start.next(self.day_count as isize - 1),
+                self.day_count,
+            );
+        }
+        let mut days = LEGAL_HOLIDAY_TABLE
+            .iter()
+            .filter(|entry| entry.period as usize == self.period && !entry.work)
+            .map(|entry| {
+                SolarDay::from_ymd(
+                    entry.year as isize,
+                    entry.month as usize,
+                    entry.day as usize,
+                )
+            });
+        match days.next() {
+            Some(start) => {
+                let end: SolarDay = days.next_back().unwrap_or(start);
+                (start, end, self.day_count)
+            }
+            None => (self.day, self.day, 0),
+        }
+    }
+
+    /// 内置数据中的后n个条目，推算的假日返回None
     pub fn next(&self, n: isize) -> Option<Self> {
         if n == 0 {
             return Some(*self);
         }
 
         // 找到当前条目在表中的位置
-        let current_index = LEGAL_HOLIDAY_TABLE.iter().position(|entry| {
-            entry.year == self.day.get_year() as u16
-                && entry.month == self.day.get_month() as u8
-                && entry.day == self.day.get_day() as u8
-        })?;
+        let current_index = Self::find_index(
+            self.day.get_year(),
+            self.day.get_month(),
+            self.day.get_day(),
+        )?;
 
         let target_index = (current_index as isize) + n;
 
@@ -98,11 +251,85 @@ impl PartialEq for LegalHoliday {
 
 impl Eq for LegalHoliday {}
 
+/// 交易日历（法定假日及周末休市，另可追加休市日；调休上班的周末不开市）
+#[derive(Debug, Clone)]
+pub struct BusinessCalendar {
+    /// 额外休市日
+    extra_closures: Vec<SolarDay>,
+}
+
+impl BusinessCalendar {
+    pub fn new(extra_closures: Vec<SolarDay>) -> Self {
+        Self { extra_closures }
+    }
+
+    /// 额外休市日
+    pub fn get_extra_closures(&self) -> Vec<SolarDay> {
+        self.extra_closures.clone()
+    }
+
+    /// 是否交易日
+    pub fn is_business_day(&self, day: SolarDay) -> bool {
+        let week: usize = day.get_week().get_index();
+        if week == 0 || week == 6 {
+            return false;
+        }
+        if let Some(holiday) = day.get_legal_holiday()
+            && !holiday.is_work()
+        {
+            return false;
+        }
+        !self.extra_closures.contains(&day)
+    }
+
+    /// 推移n个交易日，n为0时返回当天或其后最近的交易日
+    pub fn add_business_days(&self, day: SolarDay, n: isize) -> SolarDay {
+        let step: isize = if n < 0 { -1 } else { 1 };
+        let mut d: SolarDay = day;
+        if n == 0 {
+            while !self.is_business_day(d) {
+                d = d.next(1);
+            }
+            return d;
+        }
+        let mut count: isize = n.abs();
+        while count > 0 {
+            d = d.next(step);
+            if self.is_business_day(d) {
+                count -= 1;
+            }
+        }
+        d
+    }
+
+    /// 下一个交易日
+    pub fn next_business_day(&self, day: SolarDay) -> SolarDay {
+        self.add_business_days(day, 1)
+    }
+
+    /// 上一个交易日
+    pub fn previous_business_day(&self, day: SolarDay) -> SolarDay {
+        self.add_business_days(day, -1)
+    }
+}
+
+impl Default for BusinessCalendar {
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::string::ToString;
 
-    use crate::holiday::LegalHoliday;
+    use alloc::vec;
+
+    use crate::enums::Projection;
+    use crate::generated_holidays_data::LEGAL_HOLIDAY_TABLE;
+    use crate::holiday::{BusinessCalendar, LegalHoliday};
+    use crate::solar::SolarDay;
+    use crate::types::Culture;
 
     #[test]
     fn test1() {
@@ -134,4 +361,131 @@ mod tests {
         let d: LegalHoliday = LegalHoliday::from_ymd(2010, 10, 1).unwrap();
         assert_eq!("2010年10月1日 国庆节(休)", d.to_string());
     }
+
+    #[test]
+    fn test5() {
+        let calendar: BusinessCalendar = BusinessCalendar::default();
+        let d: SolarDay = SolarDay::from_ymd(2024, 9, 30);
+        assert_eq!("2024年10月8日", calendar.next_business_day(d).to_string());
+        assert_eq!(
+            "2024年9月27日",
+            calendar.previous_business_day(d).to_string()
+        );
+        // 调休上班的周六不开市
+        assert_eq!(
+            false,
+            calendar.is_business_day(SolarDay::from_ymd(2024, 10, 12))
+        );
+        assert_eq!(
+            "2024年10月14日",
+            calendar
+                .add_business_days(SolarDay::from_ymd(2024, 10, 12), 0)
+                .to_string()
+        );
+        assert_eq!(
+            "2024年10月15日",
+            calendar.add_business_days(d, 6).to_string()
+        );
+    }
+
+    #[test]
+    fn test6() {
+        let calendar: BusinessCalendar =
+            BusinessCalendar::new(vec![SolarDay::from_ymd(2024, 10, 8)]);
+        let d: SolarDay = SolarDay::from_ymd(2024, 9, 30);
+        assert_eq!("2024年10月9日", calendar.next_business_day(d).to_string());
+        assert_eq!(
+            "2024年9月30日",
+            calendar
+                .add_business_days(SolarDay::from_ymd(2024, 10, 9), -1)
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test7() {
+        let d: LegalHoliday = LegalHoliday::from_ymd(2024, 10, 3).unwrap();
+        assert_eq!(3, d.get_day_index());
+        assert_eq!(7, d.get_day_count());
+        let (start, end, count) = d.get_period();
+        assert_eq!("2024年10月1日", start.to_string());
+        assert_eq!("2024年10月7日", end.to_string());
+        assert_eq!(7, count);
+
+        // 调休上班
+        let d: LegalHoliday = LegalHoliday::from_ymd(2024, 10, 12).unwrap();
+        assert_eq!(0, d.get_day_index());
+        assert_eq!("2024年10月1日", d.get_period().0.to_string());
+    }
+
+    #[test]
+    fn test8() {
+        // 与国庆相连的中秋合为一次假期
+        let d: LegalHoliday = LegalHoliday::from_ymd(2017, 10, 4).unwrap();
+        assert_eq!("2017年10月4日 中秋节(休)", d.to_string());
+        assert_eq!(4, d.get_day_index());
+        let (start, end, count) = d.get_period();
+        assert_eq!("2017年10月1日", start.to_string());
+        assert_eq!("2017年10月8日", end.to_string());
+        assert_eq!(8, count);
+    }
+
+    #[test]
+    fn test9() {
+        assert_eq!(2026, LegalHoliday::get_last_year());
+        let d: LegalHoliday = LegalHoliday::from_ymd_with_projection(2024, 10, 1).unwrap();
+        assert_eq!(Projection::EXACT, d.get_projection());
+        assert_eq!(7, d.get_day_count());
+
+        // 2030年春节为2月3日
+        let d: LegalHoliday = LegalHoliday::from_ymd_with_projection(2030, 2, 2).unwrap();
+        assert_eq!(Projection::PROJECTED, d.get_projection());
+        assert_eq!("春节", d.get_name());
+        assert_eq!(1, d.get_day_index());
+        let (start, end, count) = d.get_period();
+        assert_eq!("2030年2月2日", start.to_string());
+        assert_eq!("2030年2月5日", end.to_string());
+        assert_eq!(4, count);
+        assert!(d.next(1).is_none());
+
+        assert_eq!(
+            "国庆节",
+            LegalHoliday::from_ymd_with_projection(2030, 10, 3)
+                .unwrap()
+                .get_name()
+        );
+        assert_eq!(
+            "清明节",
+            LegalHoliday::from_ymd_with_projection(2030, 4, 5)
+                .unwrap()
+                .get_name()
+        );
+        assert!(LegalHoliday::from_ymd_with_projection(2030, 10, 4).is_none());
+        assert!(LegalHoliday::from_ymd(2030, 10, 1).is_none());
+    }
+
+    #[test]
+    fn test10() {
+        for (i, entry) in LEGAL_HOLIDAY_TABLE.iter().enumerate() {
+            assert_eq!(
+                Some(i),
+                LegalHoliday::find_index(
+                    entry.year as isize,
+                    entry.month as usize,
+                    entry.day as usize
+                )
+            );
+        }
+        assert_eq!(None, LegalHoliday::find_index(2024, 3, 1));
+        assert_eq!(None, LegalHoliday::find_index(1999, 1, 1));
+        assert_eq!(None, LegalHoliday::find_index(2100, 1, 1));
+        assert_eq!(
+            "2025年1月1日 元旦节(休)",
+            LegalHoliday::from_ymd(2024, 10, 12)
+                .unwrap()
+                .next(1)
+                .unwrap()
+                .to_string()
+        );
+    }
 }
@@ -0,0 +1,269 @@
+//! iCalendar（RFC 5545）导出，便于在常用日历客户端订阅
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::clock::Clock;
+use crate::enums::CalendarLayer;
+use crate::lunar::LunarDay;
+use crate::solar::{SolarDay, SolarTerm, SolarTime};
+use crate::types::{Culture, Tyme};
+
+/// 内容行的最大长度（八位组，不含CRLF），超出时折行（RFC 5545 §3.1）
+const MAX_LINE_OCTETS: usize = 75;
+
+/// iCalendar导出器，按选定的日历图层生成全天事件（VEVENT）
+#[derive(Debug, Clone)]
+pub struct IcsExporter {
+    /// 日历图层
+    layers: Vec<CalendarLayer>,
+}
+
+impl IcsExporter {
+    /// 指定日历图层，未启用对应特性的图层（节日需festival，法定假日需holiday）返回错误
+    pub fn new(layers: Vec<CalendarLayer>) -> Result<Self, String> {
+        for layer in layers.iter() {
+            match layer {
+                #[cfg(not(feature = "festival"))]
+                CalendarLayer::FESTIVAL => {
+                    return Err(format!("{} requires feature festival", layer));
+                }
+                #[cfg(not(feature = "holiday"))]
+                CalendarLayer::HOLIDAY => {
+                    return Err(format!("{} requires feature holiday", layer));
+                }
+                _ => {}
+            }
+        }
+        Ok(Self { layers })
+    }
+
+    /// 日历图层
+    pub fn get_layers(&self) -> Vec<CalendarLayer> {
+        self.layers.clone()
+    }
+
+    /// 导出起止公历年（含）的日历，各事件的DTSTAMP取时钟的当前UTC时刻（生成时间）
+    pub fn export(
+        &self,
+        start_year: isize,
+        end_year: isize,
+        clock: &impl Clock,
+    ) -> Result<String, String> {
+        if end_year < start_year {
+            return Err(format!("illegal year range: {}..={}", start_year, end_year));
+        }
+        let stamp: String = Self::format_utc_time(clock.now_utc());
+        let mut s: String = String::new();
+        Self::push_line(&mut s, "BEGIN:VCALENDAR");
+        Self::push_line(&mut s, "VERSION:2.0");
+        Self::push_line(&mut s, "PRODID:-//sxtwl-rs//ICS//ZH");
+        Self::push_line(&mut s, "CALSCALE:GREGORIAN");
+        let mut day: SolarDay = SolarDay::new(start_year, 1, 1)?;
+        let end: SolarDay = SolarDay::new(end_year, 12, 31)?;
+        loop {
+            for (tag, summary, description) in self.get_events(day) {
+                Self::push_event(&mut s, day, &stamp, tag, &summary, description.as_deref());
+            }
+            if day == end {
+                break;
+            }
+            day = day.next(1);
+        }
+        Self::push_line(&mut s, "END:VCALENDAR");
+        Ok(s)
+    }
+
+    /// 某日的事件：（UID标记、标题、描述）
+    fn get_events(&self, day: SolarDay) -> Vec<(&'static str, String, Option<String>)> {
+        let mut l: Vec<(&'static str, String, Option<String>)> = Vec::new();
+        for layer in self.layers.iter() {
+            match layer {
+                CalendarLayer::TERM => {
+                    let term_day = day.get_term_day();
+                    if term_day.get_day_index() == 0 {
+                        let term: SolarTerm = term_day.get_solar_term();
+                        let description: String =
+                            format!("交节 {}", term.get_julian_day().get_solar_time());
+                        l.push(("term", term.get_name(), Some(description)));
+                    }
+                }
+                CalendarLayer::LUNAR => {
+                    let lunar: LunarDay = day.get_lunar_day();
+                    if lunar.get_day() == 1 {
+                        l.push((
+                            "lunar",
+                            format!("{}{}", lunar.get_lunar_month().get_name(), lunar.get_name()),
+                            None,
+                        ));
+                    }
+                }
+                #[cfg(feature = "festival")]
+                CalendarLayer::FESTIVAL => {
+                    if let Some(f) = day.get_festival() {
+                        l.push(("festival", f.get_name(), None));
+                    }
+                    if let Some(f) = day.get_lunar_day().get_festival() {
+                        l.push(("lunar-festival", f.get_name(), None));
+                    }
+                }
+                #[cfg(feature = "holiday")]
+                CalendarLayer::HOLIDAY => {
+                    if let Some(h) = day.get_legal_holiday() {
+                        let summary: String = format!(
+                            "{}（{}）",
+                            h.get_name(),
+                            if h.is_work() { "班" } else { "休" }
+                        );
+                        l.push(("holiday", summary, None));
+                    }
+                }
+                #[allow(unreachable_patterns)]
+                _ => {}
+            }
+        }
+        l
+    }
+
+    fn push_event(
+        s: &mut String,
+        day: SolarDay,
+        stamp: &str,
+        tag: &str,
+        summary: &str,
+        description: Option<&str>,
+    ) {
+        let date: String = Self::format_date(day);
+        Self::push_line(s, "BEGIN:VEVENT");
+        Self::push_line(s, &format!("UID:{}-{}@sxtwl-rs", date, tag));
+        Self::push_line(s, &format!("DTSTAMP:{}", stamp));
+        Self::push_line(s, &format!("DTSTART;VALUE=DATE:{}", date));
+        Self::push_line(
+            s,
+            &format!("DTEND;VALUE=DATE:{}", Self::format_date(day.next(1))),
+        );
+        Self::push_line(s, &format!("SUMMARY:{}", Self::escape(summary)));
+        if let Some(d) = description {
+            Self::push_line(s, &format!("DESCRIPTION:{}", Self::escape(d)));
+        }
+        Self::push_line(s, "TRANSP:TRANSPARENT");
+        Self::push_line(s, "END:VEVENT");
+    }
+
+    /// 写入一个内容行：超过75个八位组时在字符边界处折行，续行以一个空格开头，行尾为CRLF
+    fn push_line(s: &mut String, line: &str) {
+        let mut octets: usize = 0;
+        for c in line.chars() {
+            if octets + c.len_utf8() > MAX_LINE_OCTETS {
+                s.push_str("\r\n ");
+                octets = 1;
+            }
+            s.push(c);
+            octets += c.len_utf8();
+        }
+        s.push_str("\r\n");
+    }
+
+    /// UTC时刻（如20240204T082707Z）
+    fn format_utc_time(time: SolarTime) -> String {
+        format!(
+            "{}T{:02}{:02}{:02}Z",
+            Self::format_date(time.get_solar_day()),
+            time.get_hour(),
+            time.get_minute(),
+            time.get_second()
+        )
+    }
+
+    fn format_date(day: SolarDay) -> String {
+        format!(
+            "{:04}{:02}{:02}",
+            day.get_year(),
+            day.get_month(),
+            day.get_day()
+        )
+    }
+
+    /// 转义文本值中的反斜杠、分号、逗号及换行
+    fn escape(text: &str) -> String {
+        let mut s: String = String::with_capacity(text.len());
+        for c in text.chars() {
+            match c {
+                '\\' | ';' | ',' => {
+                    s.push('\\');
+                    s.push(c);
+                }
+                '\n' => s.push_str("\\n"),
+                _ => s.push(c),
+            }
+        }
+        s
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+    use alloc::string::String;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use crate::clock::FixedClock;
+    use crate::enums::CalendarLayer;
+    use crate::ics::IcsExporter;
+    use crate::solar::SolarTime;
+
+    #[test]
+    fn test1() {
+        let exporter: IcsExporter =
+            IcsExporter::new(vec![CalendarLayer::TERM, CalendarLayer::LUNAR]).unwrap();
+        let clock: FixedClock = FixedClock::new(SolarTime::from_ymd_hms(2025, 1, 2, 3, 4, 5));
+        let s: String = exporter.export(2024, 2024, &clock).unwrap();
+        assert!(s.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(s.ends_with("END:VCALENDAR\r\n"));
+        // 2024年有13个农历初一（1月11日至12月31日）
+        assert_eq!(24 + 13, s.matches("BEGIN:VEVENT").count());
+        assert!(s.contains(
+            "UID:20240204-term@sxtwl-rs\r\nDTSTAMP:20250102T030405Z\r\nDTSTART;VALUE=DATE:20240204\r\nDTEND;VALUE=DATE:20240205\r\nSUMMARY:立春\r\nDESCRIPTION:交节 2024年2月4日 16:27:07\r\n"
+        ));
+        assert!(s.contains(
+            "DTSTART;VALUE=DATE:20240210\r\nDTEND;VALUE=DATE:20240211\r\nSUMMARY:正月初一\r\n"
+        ));
+        assert!(exporter.export(2025, 2024, &clock).is_err());
+    }
+
+    #[cfg(all(feature = "festival", feature = "holiday"))]
+    #[test]
+    fn test2() {
+        let exporter: IcsExporter =
+            IcsExporter::new(vec![CalendarLayer::FESTIVAL, CalendarLayer::HOLIDAY]).unwrap();
+        let clock: FixedClock = FixedClock::new(SolarTime::from_ymd_hms(2025, 1, 2, 3, 4, 5));
+        let s: String = exporter.export(2024, 2024, &clock).unwrap();
+        assert!(s.contains("UID:20241001-festival@sxtwl-rs\r\n"));
+        assert!(s.contains("UID:20240210-lunar-festival@sxtwl-rs\r\n"));
+        assert!(s.contains("SUMMARY:国庆节（休）\r\n"));
+        assert!(s.contains(
+            "DTSTART;VALUE=DATE:20241012\r\nDTEND;VALUE=DATE:20241013\r\nSUMMARY:国庆节（班）\r\n"
+        ));
+    }
+
+    #[test]
+    fn test3() {
+        // 超过75个八位组的内容行折行，续行以空格开头，不拆开多字节字符
+        let mut s: String = String::new();
+        IcsExporter::push_line(&mut s, &format!("DESCRIPTION:{}", "节气".repeat(30)));
+        let lines: Vec<&str> = s.trim_end_matches("\r\n").split("\r\n").collect();
+        assert_eq!(3, lines.len());
+        assert!(lines.iter().all(|l| l.len() <= 75));
+        assert!(lines[1..].iter().all(|l| l.starts_with(' ')));
+        assert_eq!(
+            format!("DESCRIPTION:{}\r\n", "节气".repeat(30)),
+            s.replace("\r\n ", "")
+        );
+
+        let mut s: String = String::new();
+        IcsExporter::push_line(&mut s, "SUMMARY:立春");
+        assert_eq!("SUMMARY:立春\r\n", s);
+    }
+}
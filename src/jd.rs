@@ -1,6 +1,6 @@
 use alloc::format;
 use alloc::string::String;
-use libm::round;
+use libm::{floor, round};
 
 use crate::culture::Week;
 use crate::solar::{SolarDay, SolarTime};
@@ -34,6 +34,7 @@ impl JulianDay {
         Self { day }
     }
 
+    /// 公历年月日时分秒转儒略日（1582年10月15日之前按儒略历）
     pub fn from_ymd_hms(
         year: isize,
         month: usize,
@@ -70,16 +71,17 @@ impl JulianDay {
     }
 
     pub fn get_week(&self) -> Week {
-        Week::from_index((self.day + 0.5) as isize + 7000001)
+        Week::from_index(floor(self.day + 0.5) as isize + 7000001)
     }
 
     pub fn get_solar_day(&self) -> SolarDay {
         self.get_solar_time().get_solar_day()
     }
 
+    /// 公历时刻（1582年10月15日之前按儒略历）
     pub fn get_solar_time(&self) -> SolarTime {
-        let mut d: isize = (self.day + 0.5) as isize;
-        let mut f: f64 = self.day + 0.5 - (d as f64);
+        let mut d: isize = floor(self.day + 0.5) as isize;
+        let f: f64 = self.day + 0.5 - (d as f64);
 
         if d >= 2299161 {
             let c: isize = (((d as f64) - 1867216.25) / 36524.25) as isize;
@@ -98,35 +100,9 @@ impl JulianDay {
         }
         month -= 1;
         year -= 4715;
-        f *= 24.0;
-        let hour: isize = f as isize;
-
-        f -= hour as f64;
-        f *= 60.0;
-        let minute: isize = f as isize;
-
-        f -= minute as f64;
-        f *= 60.0;
-        let second: isize = round(f) as isize;
-        if second < 60 {
-            return SolarTime::from_ymd_hms(
-                year,
-                month as usize,
-                day as usize,
-                hour as usize,
-                minute as usize,
-                second as usize,
-            );
-        }
-        SolarTime::from_ymd_hms(
-            year,
-            month as usize,
-            day as usize,
-            hour as usize,
-            minute as usize,
-            second as usize - 60,
-        )
-        .next(60)
+        // 当日时长四舍五入到秒，满24时由next进位到次日
+        SolarTime::from_ymd_hms(year, month as usize, day as usize, 0, 0, 0)
+            .next(round(f * 86400.0) as isize)
     }
 
     /// 儒略日相减
@@ -153,7 +129,9 @@ impl Eq for JulianDay {}
 mod tests {
     use alloc::string::ToString;
 
+    use crate::jd::JulianDay;
     use crate::solar::SolarDay;
+    use crate::types::Culture;
 
     #[test]
     fn test1() {
@@ -165,4 +143,61 @@ mod tests {
                 .to_string()
         );
     }
+
+    #[test]
+    fn test2() {
+        // 格里高利历改历：1582年10月4日的次日为10月15日
+        let before: JulianDay = JulianDay::from_ymd_hms(1582, 10, 4, 0, 0, 0);
+        let after: JulianDay = JulianDay::from_ymd_hms(1582, 10, 15, 0, 0, 0);
+        assert_eq!(2299159.5, before.get_day());
+        assert_eq!(2299160.5, after.get_day());
+        assert_eq!("1582年10月4日", before.get_solar_day().to_string());
+        assert_eq!("1582年10月15日", after.get_solar_day().to_string());
+        assert_eq!(
+            1,
+            SolarDay::from_ymd(1582, 10, 15).subtract(SolarDay::from_ymd(1582, 10, 4))
+        );
+    }
+
+    #[test]
+    fn test3() {
+        // 儒略历段
+        assert_eq!(
+            0.0,
+            JulianDay::from_ymd_hms(-4712, 1, 1, 12, 0, 0).get_day()
+        );
+        assert_eq!(
+            1721423.5,
+            JulianDay::from_ymd_hms(1, 1, 1, 0, 0, 0).get_day()
+        );
+        assert_eq!(
+            1721422.5,
+            JulianDay::from_ymd_hms(0, 12, 31, 0, 0, 0).get_day()
+        );
+        assert_eq!(
+            "1年1月1日 00:00:00",
+            JulianDay::from_julian_day(1721423.5)
+                .get_solar_time()
+                .to_string()
+        );
+        assert_eq!(
+            "1000年2月29日",
+            SolarDay::from_ymd(1000, 2, 29)
+                .get_julian_day()
+                .get_solar_day()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test4() {
+        // 支持范围两端
+        let first: JulianDay = JulianDay::from_ymd_hms(1, 1, 1, 0, 0, 0);
+        let last: JulianDay = JulianDay::from_ymd_hms(9999, 12, 31, 23, 59, 59);
+        assert_eq!(1721423.5, first.get_day());
+        assert_eq!("1年1月1日 00:00:00", first.get_solar_time().to_string());
+        assert_eq!("9999年12月31日 23:59:59", last.get_solar_time().to_string());
+        assert_eq!("六", first.get_week().get_name());
+        assert_eq!("五", last.get_week().get_name());
+    }
 }
@@ -2,28 +2,74 @@
 
 extern crate alloc;
 extern crate core;
+#[cfg(feature = "reference")]
+extern crate std;
 
+pub mod almanac;
+pub mod calendar;
+pub mod clock;
+pub mod context;
 pub mod culture;
+pub mod diagnostics;
 #[cfg(feature = "eight-char")]
 pub mod eightchar;
+pub mod en;
 pub mod enums;
+#[cfg(feature = "era")]
+pub mod era;
+pub mod event;
+pub mod extension;
+#[cfg(feature = "farming")]
+pub mod farming;
+pub mod features;
 #[cfg(feature = "festival")]
 pub mod festival;
 #[cfg(feature = "holiday")]
 pub mod holiday;
+#[cfg(feature = "ics")]
+pub mod ics;
 pub mod jd;
 pub mod lunar;
+pub mod moon;
+#[cfg(feature = "plain-date")]
+pub mod plain_date;
 #[cfg(feature = "rabbyung")]
 pub mod rabbyung;
+#[cfg(feature = "reference")]
+pub mod reference;
+#[cfg(feature = "cli-render")]
+pub mod render;
+pub mod selection;
 pub mod sixtycycle;
 pub mod solar;
+pub mod statistics;
+pub mod sun;
 pub mod sxtwl;
+pub mod tables;
+#[cfg(feature = "debug-trace")]
+pub mod trace;
 pub mod types;
 
 mod cache;
 
+#[cfg(feature = "era")]
+mod generated_era_data {
+    include!(concat!(env!("OUT_DIR"), "/generated_era_data.rs"));
+}
+#[cfg(feature = "farming")]
+mod generated_farming_data {
+    include!(concat!(env!("OUT_DIR"), "/generated_farming_data.rs"));
+}
 #[cfg(feature = "holiday")]
-mod generated_holidays_data;
-mod generated_leap_year_data;
+mod generated_holidays_data {
+    include!(concat!(env!("OUT_DIR"), "/generated_holidays_data.rs"));
+}
+mod generated_leap_year_data {
+    include!(concat!(env!("OUT_DIR"), "/generated_leap_year_data.rs"));
+}
 #[cfg(feature = "rabbyung")]
-mod generated_rab_byung;
+mod generated_rab_byung {
+    include!(concat!(env!("OUT_DIR"), "/generated_rab_byung.rs"));
+}
+#[cfg(test)]
+mod generated_test_vectors;
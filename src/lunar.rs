@@ -1,12 +1,15 @@
 use core::cell::RefCell;
 
+use core::cmp::Ordering;
 use core::fmt::{Display, Formatter};
+use core::ops::{RangeInclusive, Sub};
 
 use alloc::format;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use libm::{ceil, floor};
 
+use crate::context::CalendarContext;
 use crate::create_cache;
 #[cfg(feature = "god")]
 use crate::culture::God;
@@ -28,7 +31,9 @@ use crate::culture::{
 #[cfg(feature = "eight-char")]
 use crate::eightchar::EightChar;
 #[cfg(feature = "eight-char")]
-use crate::eightchar::provider::EIGHT_CHAR_PROVIDER;
+use crate::eightchar::provider::{ContextEightCharProvider, EIGHT_CHAR_PROVIDER, EightCharService};
+use crate::enums::LeapFallbackPolicy;
+use crate::enums::ZiShiPolicy;
 #[cfg(feature = "festival")]
 use crate::festival::LunarFestival;
 use crate::jd::{J2000, JulianDay};
@@ -41,6 +46,77 @@ use crate::types::{AbstractCulture, AbstractTyme, Culture, LoopTyme, Tyme};
 
 use crate::generated_leap_year_data::LEAP_MONTH_YEAR_DATA;
 
+/// 农历年的取值范围（含首尾）
+pub const LUNAR_YEARS: RangeInclusive<isize> = -1..=9999;
+
+/// 推荐使用的农历年范围（含首尾）：ΔT表止于2050年，其后100年内逐渐过渡到抛物线外推，再往后朔、气时刻误差可达数分钟以上，临近子夜的朔日、节气日可能相差一天
+pub const RECOMMENDED_LUNAR_RANGE: RangeInclusive<isize> = -1..=2150;
+
+const _: () = {
+    assert!(*RECOMMENDED_LUNAR_RANGE.start() >= *LUNAR_YEARS.start());
+    assert!(*RECOMMENDED_LUNAR_RANGE.end() <= *LUNAR_YEARS.end());
+    // 闰月表按月份分12组，每组年份升序且不超出农历年范围
+    assert!(LEAP_MONTH_YEAR_DATA.len() == 12);
+    let mut i: usize = 0;
+    while i < LEAP_MONTH_YEAR_DATA.len() {
+        let years: &[isize] = LEAP_MONTH_YEAR_DATA[i];
+        let mut j: usize = 0;
+        while j < years.len() {
+            assert!(years[j] > *LUNAR_YEARS.start() && years[j] <= *LUNAR_YEARS.end());
+            assert!(j == 0 || years[j - 1] < years[j]);
+            j += 1;
+        }
+        i += 1;
+    }
+};
+
+/// 区分精度等级的换算结果：农历年超出推荐范围（RECOMMENDED_LUNAR_RANGE）时ΔT为外推值，朔、气时刻误差可达数分钟以上，结果为估算
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Resolution<T> {
+    /// 推荐范围内的结果
+    Exact(T),
+    /// 超出推荐范围的估算结果，临近子夜的朔日、节气日可能相差一天
+    Approximate(T),
+}
+
+impl<T> Resolution<T> {
+    /// 按农历年是否在推荐范围内确定精度等级，超出时（启用debug-trace）报告回退
+    pub(crate) fn for_lunar_year(year: isize, value: T) -> Self {
+        if RECOMMENDED_LUNAR_RANGE.contains(&year) {
+            return Self::Exact(value);
+        }
+        #[cfg(feature = "debug-trace")]
+        crate::trace::report_fallback(&format!(
+            "lunar year {} outside recommended range {:?}, delta T extrapolated",
+            year, RECOMMENDED_LUNAR_RANGE
+        ));
+        Self::Approximate(value)
+    }
+
+    /// 是否为推荐范围内的结果
+    pub fn is_exact(&self) -> bool {
+        matches!(self, Self::Exact(_))
+    }
+
+    /// 结果（不区分精度等级）
+    pub fn get_value(self) -> T {
+        match self {
+            Self::Exact(v) | Self::Approximate(v) => v,
+        }
+    }
+
+    /// 推荐范围内的结果，估算结果返回Err
+    pub fn exact(self) -> Result<T, String> {
+        match self {
+            Self::Exact(v) => Ok(v),
+            Self::Approximate(_) => Err(format!(
+                "approximate result outside recommended lunar range {:?}",
+                RECOMMENDED_LUNAR_RANGE
+            )),
+        }
+    }
+}
+
 /// 农历年
 #[derive(Debug, Copy, Clone)]
 pub struct LunarYear {
@@ -62,7 +138,7 @@ impl Culture for LunarYear {
 
 impl LunarYear {
     pub fn new(year: isize) -> Result<Self, String> {
-        if year < -1 || year > 9999 {
+        if !LUNAR_YEARS.contains(&year) {
             Err(format!("illegal lunar year: {}", year))
         } else {
             Ok(Self { year })
@@ -104,6 +180,18 @@ impl LunarYear {
         l
     }
 
+    /// 月列表（含闰月），写入定长容器
+    #[cfg(feature = "heapless")]
+    pub fn get_months_into(&self, out: &mut heapless::Vec<LunarMonth, 13>) {
+        out.clear();
+        let mut m: LunarMonth = LunarMonth::from_ym(self.year, 1);
+        while m.get_year() == self.year {
+            // 每年至多13个月，不会溢出
+            let _ = out.push(m);
+            m = m.next(1);
+        }
+    }
+
     pub fn get_leap_month(&self) -> usize {
         if self.year == -1 {
             return 11;
@@ -116,6 +204,37 @@ impl LunarYear {
         0
     }
 
+    /// 除夕（农历年的最后一天，腊月为小月时是廿九）
+    pub fn get_new_year_eve(&self) -> LunarDay {
+        let m: LunarMonth = *self.get_months().last().unwrap();
+        LunarDay::from_lunar_month(m, m.get_day_count()).unwrap()
+    }
+
+    /// 是否有大年三十（腊月是否为大月）
+    pub fn has_new_year_eve_30(&self) -> bool {
+        self.get_new_year_eve().get_day() == 30
+    }
+
+    /// 指定年份范围内（含首尾）的闰月，返回(年, 闰月)，按年升序
+    pub fn leap_months_between(start: isize, end: isize) -> Vec<(isize, usize)> {
+        let start: isize = start.max(*LUNAR_YEARS.start());
+        let end: isize = end.min(*LUNAR_YEARS.end());
+        let mut l: Vec<(isize, usize)> = Vec::new();
+        if start > end {
+            return l;
+        }
+        if start == -1 {
+            l.push((-1, 11));
+        }
+        for (i, years) in LEAP_MONTH_YEAR_DATA.iter().enumerate() {
+            let from: usize = years.partition_point(|y| *y < start);
+            let to: usize = years.partition_point(|y| *y <= end);
+            l.extend(years[from..to].iter().map(|y| (*y, i + 1)));
+        }
+        l.sort();
+        l
+    }
+
     pub fn get_sixty_cycle(&self) -> SixtyCycle {
         SixtyCycle::from_index(self.year - 4)
     }
@@ -219,7 +338,7 @@ impl Culture for LunarMonth {
 
 impl LunarMonth {
     pub fn new(year: isize, month: isize) -> Result<Self, String> {
-        let current_year: LunarYear = LunarYear::from_year(year);
+        let current_year: LunarYear = LunarYear::new(year)?;
         let current_leap_month: usize = current_year.get_leap_month();
         if month == 0 || month > 12 || month < -12 {
             return Err(format!("illegal lunar month: {}", month));
@@ -240,8 +359,10 @@ impl LunarMonth {
         }
 
         // 计算正月初一的偏移
-        let prev_year: LunarYear = LunarYear::from_year(year - 1);
-        let prev_leap_month: usize = prev_year.get_leap_month();
+        // 支持范围之外的上一年按无闰月处理
+        let prev_leap_month: usize = LunarYear::new(year - 1)
+            .map(|y| y.get_leap_month())
+            .unwrap_or(0);
 
         // 正常情况正月初一为第3个朔日，但有些特殊的
         let mut offset: f64 = 2.0;
@@ -275,6 +396,42 @@ impl LunarMonth {
         })
     }
 
+    /// 从农历年月创建，请求的闰月在该年不存在时按policy处理
+    pub fn new_with_leap_fallback(
+        year: isize,
+        month: isize,
+        policy: LeapFallbackPolicy,
+    ) -> Result<Self, String> {
+        let err: String = match Self::new(year, month) {
+            Ok(m) => return Ok(m),
+            Err(e) => e,
+        };
+        if !(-12..0).contains(&month) || !LUNAR_YEARS.contains(&year) {
+            return Err(err);
+        }
+        match policy {
+            LeapFallbackPolicy::ERROR => Err(err),
+            LeapFallbackPolicy::REGULAR => Self::new(year, -month),
+            LeapFallbackPolicy::NEXT => {
+                // 相邻两个闰月至多相隔约3年
+                let mut m: LunarMonth = Self::new(year, -month)?;
+                for _ in 0..48 {
+                    if m.get_year() == *LUNAR_YEARS.end() && m.get_month() == 12 {
+                        break;
+                    }
+                    m = m.next(1);
+                    if m.is_leap() {
+                        return Ok(m);
+                    }
+                }
+                Err(format!(
+                    "no leap month after {} in lunar year {}",
+                    -month, year
+                ))
+            }
+        }
+    }
+
     fn from_cache_data(cache: [f64; 5]) -> Self {
         let m: isize = cache[1] as isize;
         Self {
@@ -306,6 +463,12 @@ impl LunarMonth {
         self.year
     }
 
+    /// 英文名称，如：Month 6 (leap)
+    pub fn name_en(&self) -> String {
+        let leap: &str = if self.leap { " (leap)" } else { "" };
+        format!("Month {}{}", self.month, leap)
+    }
+
     pub fn get_year(&self) -> isize {
         self.year.get_year()
     }
@@ -362,6 +525,35 @@ impl LunarMonth {
         l
     }
 
+    /// 农历日及对应的公历日列表，自月首朔日依次推算，不逐日换算
+    pub fn get_days_with_solar(&self) -> Vec<(LunarDay, SolarDay)> {
+        let first: SolarDay = self.get_first_julian_day().get_solar_day();
+        let mut l: Vec<(LunarDay, SolarDay)> = Vec::new();
+        for i in 0..self.get_day_count() {
+            let solar: SolarDay = first.next(i as isize);
+            let lunar: LunarDay = LunarDay {
+                month: *self,
+                day: i + 1,
+                solar_day: RefCell::new(Some(solar)),
+                sixty_cycle_day: RefCell::new(None),
+            };
+            l.push((lunar, solar));
+        }
+        l
+    }
+
+    /// 农历日列表，写入定长容器
+    #[cfg(feature = "heapless")]
+    pub fn get_days_into(&self, out: &mut heapless::Vec<LunarDay, 30>) {
+        out.clear();
+        let y: isize = self.get_year();
+        let m: isize = self.get_month_with_leap();
+        for i in 0..self.get_day_count() {
+            // 每月至多30天，不会溢出
+            let _ = out.push(LunarDay::from_ymd(y, m, i + 1));
+        }
+    }
+
     pub fn get_weeks(&self, start: usize) -> Vec<LunarWeek> {
         let mut l: Vec<LunarWeek> = Vec::new();
         let size: usize = self.get_week_count(start);
@@ -439,6 +631,22 @@ impl PartialEq for LunarMonth {
 
 impl Eq for LunarMonth {}
 
+/// 稳定编码：月份1至12，闰月为负数（-1至-12），各版本保持不变，可用于持久化；所属年份需另行保存
+impl From<LunarMonth> for i8 {
+    fn from(month: LunarMonth) -> Self {
+        month.get_month_with_leap() as i8
+    }
+}
+
+/// 由（年，编码）还原农历月，月份不合法或当年无此闰月时报错
+impl TryFrom<(isize, i8)> for LunarMonth {
+    type Error = String;
+
+    fn try_from((year, code): (isize, i8)) -> Result<Self, Self::Error> {
+        Self::new(year, code as isize)
+    }
+}
+
 pub static LUNAR_SEASON_NAMES: [&str; 12] = [
     "孟春", "仲春", "季春", "孟夏", "仲夏", "季夏", "孟秋", "仲秋", "季秋", "孟冬", "仲冬", "季冬",
 ];
@@ -587,7 +795,7 @@ impl LunarWeek {
         } else if start > 6 {
             Err(format!("illegal lunar week start: {}", start))
         } else {
-            let m: LunarMonth = LunarMonth::from_ym(year, month);
+            let m: LunarMonth = LunarMonth::new(year, month)?;
             if index >= m.get_week_count(start) {
                 Err(format!(
                     "illegal lunar week index: {} in month: {}",
@@ -711,9 +919,33 @@ impl Culture for LunarDay {
 
 impl LunarDay {
     pub fn new(year: isize, month: isize, day: usize) -> Result<Self, String> {
-        let m: LunarMonth = LunarMonth::from_ym(year, month);
+        Self::from_lunar_month(LunarMonth::new(year, month)?, day)
+    }
+
+    /// 从农历年月日创建，请求的闰月在该年不存在时按policy处理
+    pub fn new_with_leap_fallback(
+        year: isize,
+        month: isize,
+        day: usize,
+        policy: LeapFallbackPolicy,
+    ) -> Result<Self, String> {
+        Self::from_lunar_month(
+            LunarMonth::new_with_leap_fallback(year, month, policy)?,
+            day,
+        )
+    }
+
+    /// 从农历月创建
+    pub fn from_lunar_month(m: LunarMonth, day: usize) -> Result<Self, String> {
         if day < 1 || day > m.get_day_count() {
-            Err(format!("illegal day {} in {}", day, m))
+            return Err(format!("illegal day {} in {}", day, m));
+        }
+        // 对应的公历日须在公元1年至9999年之间
+        let jd: f64 = m.get_first_julian_day().get_day() + day as f64 - 1.0;
+        if jd < JulianDay::from_ymd_hms(1, 1, 1, 0, 0, 0).get_day()
+            || jd >= JulianDay::from_ymd_hms(10000, 1, 1, 0, 0, 0).get_day()
+        {
+            Err(format!("lunar day {} in {} is out of solar range", day, m))
         } else {
             Ok(Self {
                 month: m,
@@ -728,6 +960,127 @@ impl LunarDay {
         Self::new(year, month, day).unwrap()
     }
 
+    /// 儒略日所在的农历日
+    pub fn from_julian_day(julian_day: JulianDay) -> Self {
+        julian_day.get_solar_day().get_lunar_day()
+    }
+
+    /// 儒略日（当天0时）
+    pub fn get_julian_day(&self) -> JulianDay {
+        self.get_solar_day().get_julian_day()
+    }
+
+    /// 农历年、月（闰月为负数）、日的组合是否存在
+    pub fn is_valid(year: isize, month: isize, day: usize) -> bool {
+        LunarYearEphemeris::new(year).is_ok_and(|e| e.is_valid(month, day))
+    }
+
+    /// 将农历日期规范为存在的日期（见LunarYearEphemeris::normalize），年份不支持时报错
+    pub fn normalize(
+        year: isize,
+        month: isize,
+        day: usize,
+    ) -> Result<LunarDayNormalization, String> {
+        LunarYearEphemeris::new(year)?.normalize(month, day)
+    }
+
+    /// 使用预先计算的农历年历表创建，年份不符时按常规计算
+    pub fn new_with_ephemeris(
+        year: isize,
+        month: isize,
+        day: usize,
+        ephemeris: Option<&LunarYearEphemeris>,
+    ) -> Result<Self, String> {
+        match ephemeris {
+            Some(e) if e.get_year() == year => e.get_lunar_day(month, day),
+            _ => Self::new(year, month, day),
+        }
+    }
+
+    /// 公历年份范围内，农历某月某日恰逢公历某月某日的公历年（如生日双历重合），按年升序
+    pub fn find_years_where(
+        lunar_month: isize,
+        lunar_day: usize,
+        solar_month: usize,
+        solar_day: usize,
+        range: RangeInclusive<isize>,
+    ) -> Vec<isize> {
+        let mut l: Vec<isize> = Vec::new();
+        if range.is_empty() {
+            return l;
+        }
+        // 农历年末的日子可能落在下一公历年
+        let start: isize = (*range.start() - 1).max(-1);
+        let end: isize = (*range.end()).min(9999);
+        for year in start..=end {
+            let d: SolarDay = match Self::new(year, lunar_month, lunar_day) {
+                Ok(d) => d.get_solar_day(),
+                Err(_) => continue,
+            };
+            if d.get_month() == solar_month
+                && d.get_day() == solar_day
+                && range.contains(&d.get_year())
+            {
+                l.push(d.get_year());
+            }
+        }
+        l
+    }
+
+    /// 在指定农历年的周年日（如生日、忌日）
+    ///
+    /// 闰月所生者，当年有同一闰月时取闰月，否则取同名的非闰月；
+    /// 日数超过当月天数时（如三十生而当年为小月）取当月最后一天。
+    pub fn get_anniversary(&self, year: isize) -> Result<LunarDay, String> {
+        let month: isize = self.get_month().abs();
+        let m: LunarMonth =
+            if self.month.is_leap() && LunarYear::new(year)?.get_leap_month() == month as usize {
+                LunarMonth::new(year, -month)?
+            } else {
+                LunarMonth::new(year, month)?
+            };
+        Self::from_lunar_month(m, self.day.min(m.get_day_count()))
+    }
+
+    /// 推移n天，按各月（含闰月）实际天数跨月，超出支持范围时返回错误
+    pub fn try_next(&self, n: isize) -> Result<Self, String> {
+        let mut m: LunarMonth = self.month;
+        let mut d: isize = self.day as isize - 1 + n;
+        while d < 0 {
+            m = Self::step_month(m, -1)?;
+            d += m.get_day_count() as isize;
+        }
+        while d >= m.get_day_count() as isize {
+            d -= m.get_day_count() as isize;
+            m = Self::step_month(m, 1)?;
+        }
+        Self::from_lunar_month(m, d as usize + 1)
+    }
+
+    /// 往前推n天，超出支持范围时返回错误
+    pub fn try_previous(&self, n: isize) -> Result<Self, String> {
+        self.try_next(-n)
+    }
+
+    /// 相邻农历月，跨出支持的年份范围时返回错误
+    fn step_month(m: LunarMonth, n: isize) -> Result<LunarMonth, String> {
+        let index: usize = m.get_index_in_year();
+        if (n > 0 && index + 1 == m.get_lunar_year().get_month_count()) || (n < 0 && index == 0) {
+            LunarYear::new(m.get_year() + n)?;
+        }
+        Ok(m.next(n))
+    }
+
+    /// 本月初一
+    pub fn first_day_of_month(&self) -> Self {
+        Self::from_lunar_month(self.month, 1).unwrap()
+    }
+
+    /// 本月最后一天（廿九或三十）
+    pub fn last_day_of_month(&self) -> Self {
+        Self::from_lunar_month(self.month, self.month.get_day_count()).unwrap()
+    }
+
     /// 农历月
     pub fn get_lunar_month(&self) -> LunarMonth {
         self.month
@@ -792,20 +1145,12 @@ impl LunarDay {
         self.get_sixty_cycle_day().get_month()
     }
 
-    /// 干支
+    /// 日柱（不依赖八字功能）
     pub fn get_sixty_cycle(&self) -> SixtyCycle {
-        let offset: isize = self
-            .month
-            .get_first_julian_day()
-            .next((self.day as isize) - 12)
-            .get_day() as isize;
-        SixtyCycle::from_name(
-            format!(
-                "{}{}",
-                HeavenStem::from_index(offset).get_name(),
-                EarthBranch::from_index(offset).get_name()
-            )
-            .as_str(),
+        SixtyCycle::of_julian_day(
+            self.month
+                .get_first_julian_day()
+                .next(self.day as isize - 1),
         )
     }
 
@@ -841,12 +1186,48 @@ impl LunarDay {
         self.get_phase_day().get_phase()
     }
 
+    /// 当天（北京时间）交的主要月相（新月即朔、上弦月、满月即望、下弦月），不交返回None
+    pub fn get_quarter(&self) -> Option<Phase> {
+        self.get_quarter_with_context(&CalendarContext::default())
+    }
+
+    /// 按上下文时区，当天交的主要月相。时区不同时朔可能落在上月末日或本月初二
+    pub fn get_quarter_with_context(&self, context: &CalendarContext) -> Option<Phase> {
+        let today: SolarDay = self.get_solar_day();
+        let year: isize = self.month.get_year();
+        let month: isize = self.month.get_month_with_leap();
+        [0, 2, 4, 6, 8]
+            .iter()
+            .map(|i| Phase::from_index(year, month, *i))
+            .find(|p| context.to_local(p.get_solar_time()).get_solar_day() == today)
+    }
+
+    /// 是否朔日（北京时间）
+    pub fn is_shuo(&self) -> bool {
+        self.get_quarter().is_some_and(|p| p.get_index() == 0)
+    }
+
+    /// 是否望日（北京时间）
+    pub fn is_wang(&self) -> bool {
+        self.get_quarter().is_some_and(|p| p.get_index() == 4)
+    }
+
     /// 六曜
     #[cfg(feature = "star-six")]
     pub fn get_six_star(&self) -> SixStar {
         SixStar::from_index((self.get_month() + self.day as isize - 2) % 6)
     }
 
+    /// 当天交节的节气及交节时刻，当天不交节返回None
+    pub fn get_jie_qi(&self) -> Option<(SolarTerm, SolarTime)> {
+        self.get_solar_day().get_jie_qi()
+    }
+
+    /// 公历日，并区分是否为超出推荐范围的估算结果
+    pub fn resolve_solar_day(&self) -> Resolution<SolarDay> {
+        Resolution::for_lunar_year(self.get_year(), self.get_solar_day())
+    }
+
     /// 公历日
     pub fn get_solar_day(&self) -> SolarDay {
         if self.solar_day.borrow().is_none() {
@@ -997,6 +1378,36 @@ impl PartialEq for LunarDay {
 
 impl Eq for LunarDay {}
 
+impl PartialOrd for LunarDay {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for LunarDay {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (
+            self.get_year(),
+            self.month.get_index_in_year(),
+            self.get_day(),
+        )
+            .cmp(&(
+                other.get_year(),
+                other.month.get_index_in_year(),
+                other.get_day(),
+            ))
+    }
+}
+
+/// 相差的天数
+impl Sub for LunarDay {
+    type Output = isize;
+
+    fn sub(self, rhs: Self) -> isize {
+        self.get_solar_day() - rhs.get_solar_day()
+    }
+}
+
 /// 农历时辰
 #[derive(Debug, Clone)]
 pub struct LunarHour {
@@ -1043,10 +1454,7 @@ impl Tyme for LunarHour {
 
 impl Culture for LunarHour {
     fn get_name(&self) -> String {
-        format!(
-            "{}时",
-            EarthBranch::from_index(self.get_index_in_day() as isize).get_name()
-        )
+        format!("{}时", EarthBranch::from_hour(self.hour).get_name())
     }
 }
 
@@ -1067,7 +1475,7 @@ impl LunarHour {
             Err(format!("illegal second: {}", second))
         } else {
             Ok(Self {
-                day: LunarDay::from_ymd(year, month, day),
+                day: LunarDay::new(year, month, day)?,
                 hour,
                 minute,
                 second,
@@ -1088,10 +1496,75 @@ impl LunarHour {
         Self::new(year, month, day, hour, minute, second).unwrap()
     }
 
+    /// 公历时刻所在的农历时辰
+    pub fn from_solar_time(time: SolarTime) -> Self {
+        time.get_lunar_hour()
+    }
+
+    /// 按晚子时取法，农历日第index个时辰的起始时刻。
+    /// 晚子时算次日时index为0-11，子时始于前一日23:00；晚子时算当日时index为0-12，0为早子时（0:00），12为晚子时（23:00）
+    pub fn from_index_in_day(
+        day: LunarDay,
+        index: usize,
+        zi_shi: ZiShiPolicy,
+    ) -> Result<Self, String> {
+        let max: usize = match zi_shi {
+            ZiShiPolicy::NEXT => 11,
+            ZiShiPolicy::CURRENT => 12,
+        };
+        if index > max {
+            return Err(format!("illegal hour index: {}", index));
+        }
+        let (d, hour) = match (zi_shi, index) {
+            (ZiShiPolicy::NEXT, 0) => (day.next(-1), 23),
+            (_, 0) => (day, 0),
+            _ => (day, index * 2 - 1),
+        };
+        Self::new(d.get_year(), d.get_month(), d.get_day(), hour, 0, 0)
+    }
+
     pub fn get_lunar_day(&self) -> LunarDay {
         self.day.clone()
     }
 
+    /// 按晚子时取法，时辰所属的农历日（晚子时算次日时，23点起属下一天）
+    pub fn get_lunar_day_with_zi_shi(&self, zi_shi: ZiShiPolicy) -> LunarDay {
+        if self.hour == 23 && zi_shi == ZiShiPolicy::NEXT {
+            self.day.next(1)
+        } else {
+            self.day.clone()
+        }
+    }
+
+    /// 按晚子时取法，时辰在所属农历日中的索引（见from_index_in_day）
+    pub fn get_index_in_day_with_zi_shi(&self, zi_shi: ZiShiPolicy) -> usize {
+        match zi_shi {
+            ZiShiPolicy::NEXT => self.get_index_in_day() % 12,
+            ZiShiPolicy::CURRENT => self.get_index_in_day(),
+        }
+    }
+
+    /// 按晚子时取法，时辰的起始时刻。晚子时算次日时子时为23:00至次日0:59:59，晚子时算当日时分为早子时（0:00起）、晚子时（23:00起）
+    pub fn get_start_solar_time(&self, zi_shi: ZiShiPolicy) -> SolarTime {
+        // 回溯到时辰起始的小时数
+        let back: usize = match (zi_shi, self.hour) {
+            (ZiShiPolicy::NEXT, 0) => 1,
+            (_, 0) | (_, 23) => 0,
+            (_, h) => (h + 1) % 2,
+        };
+        self.get_solar_time()
+            .next(-((back * 3600 + self.minute * 60 + self.second) as isize))
+    }
+
+    /// 按晚子时取法，时辰的结束时刻（含），即下一时辰起始的前1秒
+    pub fn get_end_solar_time(&self, zi_shi: ZiShiPolicy) -> SolarTime {
+        let seconds: isize = match (zi_shi, self.hour) {
+            (ZiShiPolicy::CURRENT, 0) | (ZiShiPolicy::CURRENT, 23) => 3600,
+            _ => 7200,
+        };
+        self.get_start_solar_time(zi_shi).next(seconds - 1)
+    }
+
     pub fn get_year(&self) -> isize {
         self.day.get_year()
     }
@@ -1166,8 +1639,9 @@ impl LunarHour {
         self.clone().get_sixty_cycle_hour().get_day()
     }
 
+    /// 时柱（不依赖八字功能）
     pub fn get_sixty_cycle(&self) -> SixtyCycle {
-        let earth_branch_index: isize = self.get_index_in_day() as isize % 12;
+        let earth_branch_index: isize = EarthBranch::from_hour(self.hour).get_index() as isize;
         let mut d: SixtyCycle = self.day.get_sixty_cycle();
         if self.hour >= 23 {
             d = d.next(1);
@@ -1214,11 +1688,27 @@ impl LunarHour {
         EIGHT_CHAR_PROVIDER.get_eight_char(self.clone())
     }
 
+    /// 按上下文的晚子时取法、年柱分界及立春当天换年取法计算八字；取法与全局八字服务相同时（如默认上下文）由其提供器计算
+    #[cfg(feature = "eight-char")]
+    pub fn get_eight_char_with_context(&self, context: &CalendarContext) -> EightChar {
+        if EIGHT_CHAR_PROVIDER
+            .get_context()
+            .is_some_and(|c| c.is_eight_char_equivalent(context))
+        {
+            return EIGHT_CHAR_PROVIDER.get_eight_char(self.clone());
+        }
+        EightCharService::with_year_boundary(
+            ContextEightCharProvider::new(*context),
+            context.get_year_boundary(),
+        )
+        .get_eight_char(self.clone())
+    }
+
     #[cfg(feature = "star-nine")]
     pub fn get_nine_star(&self) -> NineStar {
         let solar: SolarDay = self.day.get_solar_day();
         let dong_zhi: SolarTerm = SolarTerm::from_index(solar.get_year(), 0);
-        let earth_branch_index: isize = self.get_index_in_day() as isize % 12;
+        let earth_branch_index: isize = EarthBranch::from_hour(self.hour).get_index() as isize;
         let mut index = [8, 5, 2][self.day.get_sixty_cycle().get_earth_branch().get_index() % 3];
         if !solar.is_before(dong_zhi.get_julian_day().get_solar_day())
             && solar.is_before(dong_zhi.next(12).get_julian_day().get_solar_day())
@@ -1289,14 +1779,184 @@ impl PartialEq for LunarHour {
 
 impl Eq for LunarHour {}
 
+/// 农历年历表（各月初一及天数、闰月、节气时刻的只读快照，可重复使用以避免重复计算）
+#[derive(Debug, Clone)]
+pub struct LunarYearEphemeris {
+    /// 农历年
+    year: LunarYear,
+    /// 各月（含闰月）
+    months: Vec<LunarMonth>,
+    /// 自当年冬至起的24个节气时刻
+    term_julian_days: Vec<JulianDay>,
+}
+
+impl LunarYearEphemeris {
+    pub fn new(year: isize) -> Result<Self, String> {
+        Ok(Self::from_lunar_year(LunarYear::new(year)?))
+    }
+
+    pub fn from_year(year: isize) -> Self {
+        Self::new(year).unwrap()
+    }
+
+    /// 从农历年计算
+    pub fn from_lunar_year(year: LunarYear) -> Self {
+        Self {
+            year,
+            months: year.get_months(),
+            term_julian_days: (0..24)
+                .map(|i| SolarTerm::from_index(year.get_year(), i).get_julian_day())
+                .collect(),
+        }
+    }
+
+    /// 年
+    pub fn get_year(&self) -> isize {
+        self.year.get_year()
+    }
+
+    /// 闰月，0代表无闰月
+    pub fn get_leap_month(&self) -> usize {
+        self.year.get_leap_month()
+    }
+
+    /// 各月（含闰月）
+    pub fn get_months(&self) -> Vec<LunarMonth> {
+        self.months.clone()
+    }
+
+    /// 月，闰月为负数
+    pub fn get_month(&self, month: isize) -> Option<LunarMonth> {
+        self.months
+            .iter()
+            .find(|m| m.get_month_with_leap() == month)
+            .copied()
+    }
+
+    /// 自当年冬至起的24个节气时刻
+    pub fn get_term_julian_days(&self) -> Vec<JulianDay> {
+        self.term_julian_days.clone()
+    }
+
+    /// 农历日
+    pub fn get_lunar_day(&self, month: isize, day: usize) -> Result<LunarDay, String> {
+        match self.get_month(month) {
+            Some(m) => LunarDay::from_lunar_month(m, day),
+            None => Err(format!("illegal lunar month {} in {}", month, self.year)),
+        }
+    }
+
+    /// 农历月（闰月为负数）、日的组合在本年是否存在
+    pub fn is_valid(&self, month: isize, day: usize) -> bool {
+        self.get_lunar_day(month, day).is_ok()
+    }
+
+    /// 将农历月（闰月为负数）、日规范为本年存在的日期：不存在的闰月改为同名的常规月，超出当月天数的日改为月末
+    pub fn normalize(&self, month: isize, day: usize) -> Result<LunarDayNormalization, String> {
+        if month == 0 || month.abs() > 12 {
+            return Err(format!("illegal lunar month: {}", month));
+        }
+        if !(1..=30).contains(&day) {
+            return Err(format!("illegal lunar day: {}", day));
+        }
+        let m: LunarMonth = match self.get_month(month) {
+            Some(m) => m,
+            None => self.get_month(month.abs()).unwrap(),
+        };
+        Ok(LunarDayNormalization {
+            day: LunarDay::from_lunar_month(m, day.min(m.get_day_count()))?,
+            month,
+            day_of_month: day,
+        })
+    }
+
+    /// 公历日对应的农历日，不在本农历年内返回None
+    pub fn find_lunar_day(&self, solar_day: SolarDay) -> Option<LunarDay> {
+        self.months.iter().find_map(|m| {
+            let days: isize = solar_day.subtract(m.get_first_julian_day().get_solar_day());
+            if days >= 0 && days < m.get_day_count() as isize {
+                LunarDay::from_lunar_month(*m, days as usize + 1).ok()
+            } else {
+                None
+            }
+        })
+    }
+}
+
+/// 农历日期的规范结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LunarDayNormalization {
+    /// 规范后的农历日
+    day: LunarDay,
+    /// 原农历月，闰月为负数
+    month: isize,
+    /// 原农历日
+    day_of_month: usize,
+}
+
+impl LunarDayNormalization {
+    /// 规范后的农历日
+    pub fn get_lunar_day(&self) -> LunarDay {
+        self.day.clone()
+    }
+
+    /// 是否因闰月不存在而改为常规月
+    pub fn is_leap_dropped(&self) -> bool {
+        self.month != self.day.get_lunar_month().get_month_with_leap()
+    }
+
+    /// 是否因超出当月天数而改为月末
+    pub fn is_day_clamped(&self) -> bool {
+        self.day_of_month != self.day.get_day()
+    }
+
+    /// 是否有调整
+    pub fn is_changed(&self) -> bool {
+        self.is_leap_dropped() || self.is_day_clamped()
+    }
+}
+
+impl Display for LunarDayNormalization {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let mut l: Vec<String> = Vec::new();
+        if self.is_leap_dropped() {
+            l.push(format!(
+                "闰{}不存在，改为{}",
+                LUNAR_MONTH_NAMES[self.month.unsigned_abs() - 1],
+                LUNAR_MONTH_NAMES[self.month.unsigned_abs() - 1]
+            ));
+        }
+        if self.is_day_clamped() {
+            l.push(format!(
+                "{}不存在，改为{}",
+                LUNAR_DAY_NAMES[self.day_of_month - 1],
+                LUNAR_DAY_NAMES[self.day.get_day() - 1]
+            ));
+        }
+        if l.is_empty() {
+            write!(f, "无需调整")
+        } else {
+            write!(f, "{}", l.join("；"))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::string::ToString;
+    use alloc::vec;
+    use alloc::vec::Vec;
 
+    use crate::context::CalendarContext;
     #[cfg(feature = "star-twenty-eight")]
     use crate::culture::star::twenty_eight::TwentyEightStar;
-    use crate::lunar::{LunarDay, LunarHour, LunarMonth, LunarYear};
-    use crate::solar::SolarDay;
+    use crate::enums::{LeapFallbackPolicy, ZiShiPolicy};
+    use crate::jd::JulianDay;
+    use crate::lunar::{
+        LunarDay, LunarDayNormalization, LunarHour, LunarMonth, LunarYear, LunarYearEphemeris,
+        Resolution,
+    };
+    use crate::solar::{SolarDay, SolarTerm, SolarTime};
     use crate::types::{Culture, Tyme};
 
     #[test]
@@ -2152,4 +2812,455 @@ mod tests {
         assert_eq!("九运", year.get_twenty().get_name());
         assert_eq!("下元", year.get_twenty().get_sixty().get_name());
     }
+
+    #[test]
+    fn test101() {
+        // 日柱、时柱不依赖八字功能
+        let hour: LunarHour = LunarHour::from_ymd_hms(2024, 1, 1, 23, 30, 0);
+        assert_eq!("甲辰", hour.get_lunar_day().get_sixty_cycle().to_string());
+        assert_eq!("丙子", hour.get_sixty_cycle().to_string());
+        assert_eq!("乙巳", hour.get_sixty_cycle_hour().get_day().to_string());
+        assert_eq!(
+            "丙子",
+            hour.get_sixty_cycle_hour().get_sixty_cycle().to_string()
+        );
+    }
+
+    #[test]
+    fn test102() {
+        // 非法参数返回错误而不是崩溃
+        assert!(LunarMonth::new(-2, 1).is_err());
+        assert!(LunarMonth::new(10000, 1).is_err());
+        assert!(LunarMonth::new(-1, 1).is_ok());
+        assert!(LunarDay::new(2023, -12, 1).is_err());
+        assert!(LunarDay::new(2023, 13, 1).is_err());
+        // 公历公元1年之前
+        assert!(LunarDay::new(0, 1, 1).is_err());
+        assert_eq!(
+            "1年1月1日",
+            LunarDay::from_ymd(0, 11, 18).get_solar_day().to_string()
+        );
+    }
+
+    #[test]
+    fn test103() {
+        let e: LunarYearEphemeris = LunarYearEphemeris::from_year(2023);
+        assert_eq!(2, e.get_leap_month());
+        assert_eq!(13, e.get_months().len());
+        assert_eq!(
+            LunarDay::from_ymd(2023, -2, 1),
+            e.get_lunar_day(-2, 1).unwrap()
+        );
+        assert!(e.get_lunar_day(-3, 1).is_err());
+        assert_eq!(
+            "闰二月初一",
+            e.find_lunar_day(SolarDay::from_ymd(2023, 3, 22))
+                .unwrap()
+                .to_string()
+        );
+        assert!(e.find_lunar_day(SolarDay::from_ymd(2024, 3, 1)).is_none());
+        assert_eq!(
+            SolarTerm::from_index(2023, 3).get_julian_day().get_day(),
+            e.get_term_julian_days()[3].get_day()
+        );
+    }
+
+    #[test]
+    fn test104() {
+        let e: LunarYearEphemeris = LunarYearEphemeris::from_year(2023);
+        // 不在年历表范围内时按常规计算
+        assert_eq!(
+            "正月廿一",
+            SolarDay::from_ymd(2024, 3, 1)
+                .get_lunar_day_with_ephemeris(Some(&e))
+                .to_string()
+        );
+        assert_eq!(
+            "腊月三十",
+            SolarDay::from_ymd(2024, 2, 9)
+                .get_lunar_day_with_ephemeris(Some(&e))
+                .to_string()
+        );
+        assert_eq!(
+            LunarDay::from_ymd(2024, 1, 1),
+            LunarDay::new_with_ephemeris(2024, 1, 1, Some(&e)).unwrap()
+        );
+        assert_eq!(
+            LunarDay::from_ymd(2023, 12, 30),
+            LunarDay::new_with_ephemeris(2023, 12, 30, None).unwrap()
+        );
+    }
+
+    #[test]
+    fn test105() {
+        let a: LunarDay = LunarDay::from_ymd(2023, 2, 30);
+        let b: LunarDay = LunarDay::from_ymd(2023, -2, 1);
+        let c: LunarDay = LunarDay::from_ymd(2023, 3, 1);
+        assert!(a < b);
+        assert!(b < c);
+        assert_eq!(1, b.clone() - a.clone());
+        assert_eq!(29, c.clone() - b.clone());
+        assert!(LunarDay::from_ymd(2022, 12, 1) < a);
+        assert_eq!(c, [a, b, c.clone()].iter().max().unwrap().clone());
+    }
+
+    #[test]
+    fn test106() {
+        assert_eq!(
+            vec![(2020, 4), (2023, 2), (2025, 6)],
+            LunarYear::leap_months_between(2020, 2025)
+        );
+        let first: (isize, usize) = LunarYear::leap_months_between(2026, 9999)
+            .into_iter()
+            .find(|(_, m)| *m == 1)
+            .unwrap();
+        assert_eq!((2262, 1), first);
+        assert_eq!(1, LunarYear::from_year(first.0).get_leap_month());
+        assert!(LunarYear::leap_months_between(2025, 2020).is_empty());
+    }
+
+    #[test]
+    fn test107() {
+        let years: Vec<isize> = LunarDay::find_years_where(8, 15, 10, 1, 1949..=2100);
+        assert_eq!(vec![1982, 2001, 2020, 2031, 2077], years);
+        for year in years {
+            assert_eq!(
+                "八月十五",
+                SolarDay::from_ymd(year, 10, 1).get_lunar_day().to_string()
+            );
+        }
+        assert_eq!(
+            vec![2025],
+            LunarDay::find_years_where(12, 2, 1, 1, 2025..=2025)
+        );
+    }
+
+    #[cfg(feature = "heapless")]
+    #[test]
+    fn test108() {
+        let mut months: heapless::Vec<LunarMonth, 13> = heapless::Vec::new();
+        LunarYear::from_year(2023).get_months_into(&mut months);
+        assert_eq!(13, months.len());
+        assert_eq!("闰二月", months[2].get_name());
+        let mut days: heapless::Vec<LunarDay, 30> = heapless::Vec::new();
+        months[2].get_days_into(&mut days);
+        assert_eq!(months[2].get_day_count(), days.len());
+        assert_eq!("闰二月初一", days[0].to_string());
+    }
+
+    #[test]
+    fn test109() {
+        let eves: Vec<bool> = (2020..2031)
+            .map(|y| LunarYear::from_year(y).has_new_year_eve_30())
+            .collect();
+        // 2025年至2029年春节前连续五年没有大年三十
+        assert_eq!(
+            vec![
+                true, false, true, true, false, false, false, false, false, true, false
+            ],
+            eves
+        );
+        assert_eq!(
+            "腊月廿九",
+            LunarYear::from_year(2024).get_new_year_eve().to_string()
+        );
+        assert_eq!(
+            SolarDay::from_ymd(2025, 1, 28),
+            LunarYear::from_year(2024)
+                .get_new_year_eve()
+                .get_solar_day()
+        );
+    }
+
+    #[test]
+    fn test110() {
+        // 闰二月初十生，有闰二月之年过闰月，其余年份过二月
+        let birthday: LunarDay = LunarDay::from_ymd(2023, -2, 10);
+        assert_eq!(
+            "二月初十",
+            birthday.get_anniversary(2024).unwrap().to_string()
+        );
+        assert_eq!(
+            "闰二月初十",
+            birthday.get_anniversary(2042).unwrap().to_string()
+        );
+        // 腊月三十生，无大年三十之年过廿九
+        let birthday: LunarDay = LunarDay::from_ymd(2022, 12, 30);
+        assert_eq!(
+            "腊月廿九",
+            birthday.get_anniversary(2024).unwrap().to_string()
+        );
+        assert!(birthday.get_anniversary(10000).is_err());
+    }
+
+    #[cfg(feature = "eight-char")]
+    #[test]
+    fn test111() {
+        use crate::context::CalendarContext;
+        use crate::enums::{YearBoundary, ZiShiPolicy};
+        use crate::solar::SolarTime;
+
+        // 立春后、春节前的晚子时
+        let hour: LunarHour = SolarTime::from_ymd_hms(2024, 2, 5, 23, 30, 0).get_lunar_hour();
+        // 默认上下文与全局八字服务（所选提供器及年柱分界）一致
+        assert_eq!(
+            hour.get_eight_char(),
+            hour.get_eight_char_with_context(&CalendarContext::default())
+        );
+        let context: CalendarContext = CalendarContext::default()
+            .with_zi_shi(ZiShiPolicy::NEXT)
+            .with_year_boundary(YearBoundary::SPRING);
+        let next_day = hour.get_eight_char_with_context(&context);
+        assert_eq!("甲辰", next_day.get_year().get_name());
+        assert_eq!(hour.get_sixty_cycle_hour().get_day(), next_day.get_day());
+
+        let same_day = hour.get_eight_char_with_context(
+            &context
+                .with_zi_shi(ZiShiPolicy::CURRENT)
+                .with_year_boundary(YearBoundary::LUNAR),
+        );
+        assert_eq!("癸卯", same_day.get_year().get_name());
+        assert_eq!(next_day.get_day().next(-1), same_day.get_day());
+        assert_eq!(next_day.get_hour(), same_day.get_hour());
+    }
+
+    #[test]
+    fn test112() {
+        // 2023年闰二月
+        let day: LunarDay = LunarDay::from_ymd(2023, 2, 28);
+        assert_eq!("闰二月初二", day.try_next(4).unwrap().to_string());
+        assert_eq!(day, day.try_next(4).unwrap().try_previous(4).unwrap());
+        assert_eq!(
+            "二月三十",
+            LunarDay::from_ymd(2023, -2, 1)
+                .try_previous(1)
+                .unwrap()
+                .to_string()
+        );
+        for n in [-400, -31, -1, 1, 29, 30, 400] {
+            assert_eq!(day.next(n), day.try_next(n).unwrap());
+        }
+
+        let leap: LunarDay = LunarDay::from_ymd(2023, -2, 15);
+        assert_eq!("闰二月初一", leap.first_day_of_month().to_string());
+        assert_eq!("闰二月廿九", leap.last_day_of_month().to_string());
+
+        assert!(LunarDay::from_ymd(1, 1, 1).try_next(-400).is_err());
+        assert!(LunarDay::from_ymd(9999, 1, 1).try_next(400).is_err());
+    }
+
+    #[test]
+    fn test113() {
+        let month: LunarMonth = LunarMonth::from_ym(2023, -2);
+        let days: Vec<(LunarDay, SolarDay)> = month.get_days_with_solar();
+        assert_eq!(29, days.len());
+        assert_eq!("2023年3月22日", days[0].1.to_string());
+        assert_eq!("2023年4月19日", days[28].1.to_string());
+        for (lunar, solar) in days.iter() {
+            assert_eq!(*solar, lunar.get_solar_day());
+            assert_eq!(*lunar, solar.get_lunar_day());
+        }
+    }
+
+    #[test]
+    fn test114() {
+        // 2024年正月：朔2月10日，上弦2月16日，望2月24日，下弦3月3日
+        let day: LunarDay = LunarDay::from_ymd(2024, 1, 1);
+        assert!(day.is_shuo());
+        assert!(!day.is_wang());
+        assert_eq!(
+            "满月",
+            LunarDay::from_ymd(2024, 1, 15)
+                .get_quarter()
+                .unwrap()
+                .get_name()
+        );
+        assert!(LunarDay::from_ymd(2024, 1, 15).is_wang());
+        assert_eq!(
+            "上弦月",
+            LunarDay::from_ymd(2024, 1, 7)
+                .get_quarter()
+                .unwrap()
+                .get_name()
+        );
+        assert_eq!(
+            "下弦月",
+            LunarDay::from_ymd(2024, 1, 23)
+                .get_quarter()
+                .unwrap()
+                .get_name()
+        );
+        assert!(LunarDay::from_ymd(2024, 1, 2).get_quarter().is_none());
+    }
+
+    #[test]
+    fn test115() {
+        // 2024年4月9日02:20（北京时间）朔，UTC时为4月8日（二月末日）
+        let context: CalendarContext = CalendarContext::default().with_utc_offset(0).unwrap();
+        let last: LunarDay = SolarDay::from_ymd(2024, 4, 8).get_lunar_day();
+        assert!(last.get_quarter().is_none());
+        assert_eq!(
+            "新月",
+            last.get_quarter_with_context(&context).unwrap().get_name()
+        );
+        let first: LunarDay = SolarDay::from_ymd(2024, 4, 9).get_lunar_day();
+        assert_eq!(1, first.get_day());
+        assert!(first.is_shuo());
+        assert!(first.get_quarter_with_context(&context).is_none());
+    }
+
+    #[test]
+    fn test116() {
+        // 2024年2月9日（腊月三十）23:30至2月10日（正月初一）00:30
+        let late: LunarHour =
+            LunarHour::from_solar_time(SolarTime::from_ymd_hms(2024, 2, 9, 23, 30, 0));
+        let early: LunarHour =
+            LunarHour::from_solar_time(SolarTime::from_ymd_hms(2024, 2, 10, 0, 30, 0));
+        for h in [&late, &early] {
+            assert_eq!(
+                "2024年2月9日 23:00:00",
+                h.get_start_solar_time(ZiShiPolicy::NEXT).to_string()
+            );
+            assert_eq!(
+                "2024年2月10日 00:59:59",
+                h.get_end_solar_time(ZiShiPolicy::NEXT).to_string()
+            );
+            assert_eq!(
+                LunarDay::from_ymd(2024, 1, 1),
+                h.get_lunar_day_with_zi_shi(ZiShiPolicy::NEXT)
+            );
+            assert_eq!(0, h.get_index_in_day_with_zi_shi(ZiShiPolicy::NEXT));
+        }
+        assert_eq!(
+            "2024年2月9日 23:59:59",
+            late.get_end_solar_time(ZiShiPolicy::CURRENT).to_string()
+        );
+        assert_eq!(
+            "2024年2月10日 00:00:00",
+            early.get_start_solar_time(ZiShiPolicy::CURRENT).to_string()
+        );
+        assert_eq!(12, late.get_index_in_day_with_zi_shi(ZiShiPolicy::CURRENT));
+        assert_eq!(
+            LunarDay::from_ymd(2023, 12, 30),
+            late.get_lunar_day_with_zi_shi(ZiShiPolicy::CURRENT)
+        );
+        assert!(
+            LunarHour::from_index_in_day(early.get_lunar_day(), 12, ZiShiPolicy::NEXT).is_err()
+        );
+    }
+
+    #[test]
+    fn test117() {
+        // 每7分钟取一个时刻，时刻落在所在时辰的起止范围内，且由所属农历日及索引可还原时辰起始
+        let mut time: SolarTime = SolarTime::from_ymd_hms(2024, 2, 8, 21, 0, 0);
+        let end: SolarTime = SolarTime::from_ymd_hms(2024, 2, 11, 3, 0, 0);
+        while time.is_before(end) {
+            let h: LunarHour = LunarHour::from_solar_time(time);
+            for zi_shi in [ZiShiPolicy::NEXT, ZiShiPolicy::CURRENT] {
+                let start: SolarTime = h.get_start_solar_time(zi_shi);
+                assert!(!start.is_after(time) && !h.get_end_solar_time(zi_shi).is_before(time));
+                let restored: LunarHour = LunarHour::from_index_in_day(
+                    h.get_lunar_day_with_zi_shi(zi_shi),
+                    h.get_index_in_day_with_zi_shi(zi_shi),
+                    zi_shi,
+                )
+                .unwrap();
+                assert_eq!(start, restored.get_solar_time());
+            }
+            time = time.next(420);
+        }
+    }
+
+    #[test]
+    fn test118() {
+        // 2023年闰二月，2024年无闰月
+        assert!(LunarDay::is_valid(2023, -2, 29));
+        assert!(!LunarDay::is_valid(2024, -2, 1));
+        assert!(!LunarDay::is_valid(2024, 1, 30));
+        assert!(!LunarDay::is_valid(10000, 1, 1));
+
+        let n: LunarDayNormalization = LunarDay::normalize(2024, -2, 30).unwrap();
+        assert!(n.is_leap_dropped());
+        assert_eq!(LunarDay::from_ymd(2024, 2, 30), n.get_lunar_day());
+        assert_eq!("闰二月不存在，改为二月", n.to_string());
+
+        let n: LunarDayNormalization = LunarDay::normalize(2024, 1, 30).unwrap();
+        assert!(n.is_day_clamped() && !n.is_leap_dropped());
+        assert_eq!(LunarDay::from_ymd(2024, 1, 29), n.get_lunar_day());
+        assert_eq!("三十不存在，改为廿九", n.to_string());
+
+        assert!(!LunarDay::normalize(2023, -2, 1).unwrap().is_changed());
+        assert!(LunarDay::normalize(2024, 13, 1).is_err());
+        assert!(LunarDay::normalize(2024, 1, 31).is_err());
+    }
+
+    #[test]
+    fn test119() {
+        let month: LunarMonth = LunarMonth::from_ym(2023, -2);
+        let code: i8 = month.into();
+        assert_eq!(-2, code);
+        assert_eq!(month, LunarMonth::try_from((2023, code)).unwrap());
+        assert_eq!(12, i8::from(LunarMonth::from_ym(2023, 12)));
+        assert!(LunarMonth::try_from((2024, -2)).is_err());
+        assert!(LunarMonth::try_from((2024, 13)).is_err());
+    }
+
+    #[test]
+    fn test120() {
+        let day: LunarDay = LunarDay::from_ymd(2023, -2, 11);
+        let jd: JulianDay = day.get_julian_day();
+        assert_eq!(SolarDay::from_ymd(2023, 4, 1).get_julian_day(), jd);
+        assert_eq!(day, LunarDay::from_julian_day(jd));
+        assert_eq!(
+            day,
+            LunarDay::from_julian_day(JulianDay::from_julian_day(jd.get_day() + 0.75))
+        );
+    }
+
+    #[test]
+    fn test121() {
+        // 2023年闰二月，2024年无闰月，2025年闰六月
+        let f = |year: isize, month: isize, policy: LeapFallbackPolicy| {
+            LunarMonth::new_with_leap_fallback(year, month, policy)
+                .map(|m| (m.get_year(), m.get_month_with_leap()))
+        };
+        assert!(f(2024, -2, LeapFallbackPolicy::ERROR).is_err());
+        assert_eq!((2024, 2), f(2024, -2, LeapFallbackPolicy::REGULAR).unwrap());
+        assert_eq!((2025, -6), f(2024, -2, LeapFallbackPolicy::NEXT).unwrap());
+        assert_eq!((2023, -2), f(2023, -2, LeapFallbackPolicy::ERROR).unwrap());
+        assert_eq!((2025, -6), f(2023, -5, LeapFallbackPolicy::NEXT).unwrap());
+        assert_eq!((2025, -6), f(2025, -4, LeapFallbackPolicy::NEXT).unwrap());
+        assert!(f(2024, -13, LeapFallbackPolicy::REGULAR).is_err());
+        assert!(f(2024, 13, LeapFallbackPolicy::REGULAR).is_err());
+        assert!(f(10000, -2, LeapFallbackPolicy::REGULAR).is_err());
+
+        let day: LunarDay =
+            LunarDay::new_with_leap_fallback(2024, -2, 1, LeapFallbackPolicy::REGULAR).unwrap();
+        assert_eq!(LunarDay::from_ymd(2024, 2, 1), day);
+        assert!(LunarDay::new_with_leap_fallback(2024, -2, 1, LeapFallbackPolicy::ERROR).is_err());
+        assert_eq!(
+            LeapFallbackPolicy::NEXT,
+            LeapFallbackPolicy::from_name("取后续闰月").unwrap()
+        );
+    }
+
+    #[test]
+    fn test122() {
+        let d: Resolution<SolarDay> = LunarDay::from_ymd(2150, 12, 1).resolve_solar_day();
+        assert!(d.is_exact());
+        assert_eq!(d.get_value(), d.exact().unwrap());
+
+        // 超出推荐范围：仍给出估算结果，但可区分
+        let d: Resolution<SolarDay> = LunarDay::from_ymd(2151, 1, 1).resolve_solar_day();
+        assert!(!d.is_exact());
+        assert_eq!(
+            LunarDay::from_ymd(2151, 1, 1).get_solar_day(),
+            d.get_value()
+        );
+        assert!(d.exact().is_err());
+        assert!(matches!(
+            SolarDay::from_ymd(2200, 6, 1).resolve_lunar_day(),
+            Resolution::Approximate(_)
+        ));
+    }
 }
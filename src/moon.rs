@@ -0,0 +1,224 @@
+//! 月出月落、月亮站心坐标
+
+use libm::{acos, atan2, cos, floor, sin};
+
+use crate::jd::{J2000, JulianDay};
+use crate::solar::{SolarDay, SolarTime};
+use crate::sun::{Observer, RiseSetOptions};
+use crate::sxtwl::units::{Days, Degrees, Radians};
+use crate::sxtwl::{ONE_THIRD, PI_2, Sxtwl};
+
+/// 月亮相继两次上中天的平均间隔（日）
+static MOON_TRANSIT_INTERVAL: f64 = 1.035;
+
+/// 月亮站心视赤经、赤纬（弧度）：地心视赤经、赤纬经周日视差改正到海平面的观测者处，参数为J2000起算的世界时儒略日数
+pub fn topocentric_equatorial(jd: Days, observer: Observer) -> (Radians, Radians) {
+    let tt: Days = Days(jd.0 + Sxtwl::dtt(jd.0));
+    let (ra, dec) = Sxtwl::moon_equatorial(tt);
+    let sin_parallax: f64 = sin(Sxtwl::moon_horizontal_parallax(tt).0);
+    let (rho_cos, rho_sin) = observer.geocentric_position();
+    let longitude: Radians = Degrees(observer.get_longitude()).into();
+    let h: f64 = Sxtwl::mean_sidereal_time(jd).0 + longitude.0 - ra.0;
+    let x: f64 = cos(dec.0) - rho_cos * sin_parallax * cos(h);
+    let d_ra: f64 = atan2(-rho_cos * sin_parallax * sin(h), x);
+    let dec: f64 = atan2((sin(dec.0) - rho_sin * sin_parallax) * cos(d_ra), x);
+    (Radians(ra.0 + d_ra), Radians(dec))
+}
+
+/// 月出月落
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct MoonTimes {
+    /// 公历日
+    day: SolarDay,
+    /// 观测者位置
+    observer: Observer,
+    /// 升没计算参数（站心高度角）
+    options: RiseSetOptions,
+}
+
+impl MoonTimes {
+    pub fn from_observer(day: SolarDay, observer: Observer) -> Self {
+        Self {
+            day,
+            observer,
+            options: RiseSetOptions::moon(),
+        }
+    }
+
+    /// 公历日
+    pub fn get_solar_day(&self) -> SolarDay {
+        self.day
+    }
+
+    /// 观测者位置
+    pub fn get_observer(&self) -> Observer {
+        self.observer
+    }
+
+    /// 升没计算参数，默认为RiseSetOptions::moon()
+    pub fn get_options(&self) -> RiseSetOptions {
+        self.options
+    }
+
+    /// 使用指定升没计算参数
+    pub fn with_options(&self, options: RiseSetOptions) -> Self {
+        Self { options, ..*self }
+    }
+
+    /// 月出，北京时间；当天无月出（约每月一次）或终日不升、不没时为None
+    pub fn moonrise(&self) -> Option<SolarTime> {
+        self.rise_set(-1.0)
+    }
+
+    /// 月落，北京时间；当天无月落（约每月一次）或终日不升、不没时为None
+    pub fn moonset(&self) -> Option<SolarTime> {
+        self.rise_set(1.0)
+    }
+
+    /// 月出（sign为-1）或月落（sign为1）：分别自当天6时、18时起迭代，取落在当天的较早者
+    fn rise_set(&self, sign: f64) -> Option<SolarTime> {
+        // 北京时间当天0时，J2000起算的世界时儒略日数
+        let start: f64 = self.day.get_julian_day().get_day() - J2000 - ONE_THIRD;
+        let mut found: Option<f64> = None;
+        for offset in [0.25, 0.75] {
+            if let Some(t) = self.converge(start + offset, sign)
+                && t >= start
+                && t < start + 1.0
+                && found.is_none_or(|f| t < f)
+            {
+                found = Some(t);
+            }
+        }
+        found.map(|t| JulianDay::from_julian_day(t + ONE_THIRD + J2000).get_solar_time())
+    }
+
+    /// 自世界时t起迭代，使月亮站心时角等于升没时角，收敛到最近的一次月出或月落
+    fn converge(&self, mut t: f64, sign: f64) -> Option<f64> {
+        let longitude: Radians = Degrees(self.observer.get_longitude()).into();
+        let altitude: Degrees = self.options.get_altitude();
+        for _ in 0..10 {
+            let (ra, dec) = topocentric_equatorial(Days(t), self.observer);
+            let c: f64 = self.observer.hour_angle_cos(dec, altitude);
+            if !(-1.0 < c && c < 1.0) {
+                return None;
+            }
+            let h: f64 = Sxtwl::mean_sidereal_time(Days(t)).0 + longitude.0 - ra.0;
+            let mut dh: f64 = sign * acos(c) - h;
+            dh -= floor(dh / PI_2 + 0.5) * PI_2;
+            t += dh / PI_2 * MOON_TRANSIT_INTERVAL;
+            if dh.abs() < 1e-6 {
+                break;
+            }
+        }
+        Some(t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use libm::{asin, cos, sin};
+
+    use crate::types::Tyme;
+
+    use crate::moon::{MoonTimes, topocentric_equatorial};
+    use crate::solar::SolarDay;
+    use crate::sun::{Observer, RiseSetOptions};
+    use crate::sxtwl::Sxtwl;
+    use crate::sxtwl::units::{Days, Degrees, Radians};
+
+    #[test]
+    fn test1() {
+        let t: MoonTimes = MoonTimes::from_observer(
+            SolarDay::from_ymd(2024, 9, 17),
+            Observer::from_degrees(116.4, 39.9),
+        );
+        assert_eq!("2024年9月17日 18:02:21", t.moonrise().unwrap().to_string());
+        assert_eq!("2024年9月17日 04:26:55", t.moonset().unwrap().to_string());
+    }
+
+    #[test]
+    fn test2() {
+        let beijing: Observer = Observer::from_degrees(116.4, 39.9);
+        let polar: Observer = Observer::from_degrees(25.0, 85.0);
+        let mut day: SolarDay = SolarDay::from_ymd(2024, 9, 1);
+        let (mut rises, mut sets, mut polar_rises) = (0, 0, 0);
+        for _ in 0..30 {
+            let t: MoonTimes = MoonTimes::from_observer(day, beijing);
+            if let Some(rise) = t.moonrise() {
+                assert_eq!(day, rise.get_solar_day());
+                rises += 1;
+            }
+            if t.moonset().is_some() {
+                sets += 1;
+            }
+            if MoonTimes::from_observer(day, polar).moonrise().is_some() {
+                polar_rises += 1;
+            }
+            day = day.next(1);
+        }
+        assert_eq!(29, rises);
+        assert_eq!(29, sets);
+        assert!(polar_rises < 10);
+    }
+
+    #[test]
+    fn test3() {
+        // 1992年4月12日0时（力学时）月地距离368409.7千米、地平视差0.991990°（Meeus例47.a，取主要周期项），
+        // XL1全表为368405.3千米
+        let jd: Days = Days(-2820.5);
+        let d: f64 = Sxtwl::moon_distance(jd);
+        assert!((d - 368409.7).abs() < 5.0);
+        let p: Degrees = Sxtwl::moon_horizontal_parallax(jd).into();
+        assert!((p.0 - 0.991990).abs() < 5e-5);
+        // 截断项数与黄经、黄纬一致
+        let t: f64 = jd.0 / 36525.0;
+        assert_eq!(d, Sxtwl::mdist(t, -1));
+        assert!((Sxtwl::mdist(t, 20) - d).abs() < 100.0);
+        assert_ne!(d, Sxtwl::mdist(t, 20));
+    }
+
+    #[test]
+    fn test4() {
+        // 站心高度比地心高度低，差值约为地平视差乘以高度角余弦
+        let observer: Observer = Observer::from_degrees(116.4, 39.9);
+        let phi: Radians = Degrees(observer.get_latitude()).into();
+        let longitude: Radians = Degrees(observer.get_longitude()).into();
+        let altitude = |jd: f64, ra: Radians, dec: Radians| -> f64 {
+            let h: f64 = Sxtwl::mean_sidereal_time(Days(jd)).0 + longitude.0 - ra.0;
+            asin(sin(phi.0) * sin(dec.0) + cos(phi.0) * cos(dec.0) * cos(h))
+        };
+        let mut jd: f64 = 9000.0;
+        while jd < 9030.0 {
+            let tt: Days = Days(jd + Sxtwl::dtt(jd));
+            let (ra, dec) = Sxtwl::moon_equatorial(tt);
+            let geocentric: f64 = altitude(jd, ra, dec);
+            let (ra, dec) = topocentric_equatorial(Days(jd), observer);
+            let topocentric: f64 = altitude(jd, ra, dec);
+            let p: f64 = Sxtwl::moon_horizontal_parallax(tt).0;
+            assert!((geocentric - topocentric - p * cos(geocentric)).abs() < 3e-4);
+            jd += 0.37;
+        }
+    }
+    #[test]
+    fn test5() {
+        let t: MoonTimes = MoonTimes::from_observer(
+            SolarDay::from_ymd(2024, 9, 17),
+            Observer::from_degrees(116.4, 39.9),
+        );
+        assert_eq!(RiseSetOptions::moon(), t.get_options());
+        assert!((-49.5 / 60.0 - t.get_options().get_altitude().0).abs() < 1e-12);
+        // 几何升没（月心过地平）：月出较晚、月落较早
+        let geometric: MoonTimes = t.with_options(RiseSetOptions::geometric());
+        let rise: i64 = geometric
+            .moonrise()
+            .unwrap()
+            .subtract(t.moonrise().unwrap());
+        let set: i64 = t.moonset().unwrap().subtract(geometric.moonset().unwrap());
+        assert!((120..400).contains(&rise), "{}", rise);
+        assert!((120..400).contains(&set), "{}", set);
+        // 海拔越高，月出越早
+        let high: MoonTimes = t.with_options(RiseSetOptions::new(34.0, 15.5, 1000.0).unwrap());
+        assert!(high.moonrise().unwrap().is_before(t.moonrise().unwrap()));
+    }
+}
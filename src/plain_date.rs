@@ -0,0 +1,103 @@
+//! 整数形式的农历日期：以(年, 月, 日, 是否闰月)的i32、u32收发，便于与以整数存储农历日期的调用方对接；并非其他农历库的替代实现，接口不保证与之相同
+
+use alloc::string::String;
+use core::fmt::{Display, Formatter};
+
+use crate::lunar::LunarDay;
+use crate::solar::SolarDay;
+
+/// 农历日期（年、月、日、是否闰月），内部以LunarDay计算
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlainLunarDate {
+    /// 农历日
+    day: LunarDay,
+}
+
+impl PlainLunarDate {
+    /// 由农历年、月、日及是否闰月创建，日期不存在时报错
+    pub fn new(year: i32, month: u32, day: u32, is_leap_month: bool) -> Result<Self, String> {
+        let month: isize = if is_leap_month {
+            -(month as isize)
+        } else {
+            month as isize
+        };
+        Ok(Self {
+            day: LunarDay::new(year as isize, month, day as usize)?,
+        })
+    }
+
+    /// 由公历年、月、日创建
+    pub fn from_solar_date(year: i32, month: u32, day: u32) -> Result<Self, String> {
+        Ok(Self {
+            day: SolarDay::new(year as isize, month as usize, day as usize)?.get_lunar_day(),
+        })
+    }
+
+    /// 公历年、月、日
+    pub fn to_solar_date(&self) -> (i32, u32, u32) {
+        let d: SolarDay = self.day.get_solar_day();
+        (
+            d.get_year() as i32,
+            d.get_month() as u32,
+            d.get_day() as u32,
+        )
+    }
+
+    /// 农历年
+    pub fn year(&self) -> i32 {
+        self.day.get_lunar_month().get_year() as i32
+    }
+
+    /// 农历月（不含闰月标记）
+    pub fn month(&self) -> u32 {
+        self.day.get_lunar_month().get_month() as u32
+    }
+
+    /// 农历日
+    pub fn day(&self) -> u32 {
+        self.day.get_day() as u32
+    }
+
+    /// 是否闰月
+    pub fn is_leap_month(&self) -> bool {
+        self.day.get_lunar_month().is_leap()
+    }
+
+    /// 农历日
+    pub fn get_lunar_day(&self) -> LunarDay {
+        self.day.clone()
+    }
+}
+
+impl From<LunarDay> for PlainLunarDate {
+    fn from(day: LunarDay) -> Self {
+        Self { day }
+    }
+}
+
+impl Display for PlainLunarDate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "农历{}年{}", self.year(), self.day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::plain_date::PlainLunarDate;
+
+    #[test]
+    fn test1() {
+        let date: PlainLunarDate = PlainLunarDate::from_solar_date(2023, 4, 1).unwrap();
+        assert_eq!(2023, date.year());
+        assert_eq!(2, date.month());
+        assert_eq!(11, date.day());
+        assert!(date.is_leap_month());
+        assert_eq!((2023, 4, 1), date.to_solar_date());
+        assert_eq!(date, PlainLunarDate::new(2023, 2, 11, true).unwrap());
+        assert!(PlainLunarDate::new(2024, 2, 11, true).is_err());
+        assert!(PlainLunarDate::from_solar_date(2023, 2, 30).is_err());
+        assert_eq!("农历2023年闰二月十一", date.to_string());
+    }
+}
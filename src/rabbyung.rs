@@ -1,15 +1,32 @@
 use core::fmt::{Display, Formatter};
+use core::ops::RangeInclusive;
+use libm::round;
 
 use alloc::format;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 
-use crate::culture::{Direction, Element, Zodiac};
-use crate::generated_rab_byung::get_rab_byung_month_days;
+use crate::culture::{Direction, Element, Week, Zodiac};
+use crate::enums::Projection;
+use crate::generated_rab_byung::{
+    RAB_BYUNG_DATA, RabByungMonthData, find_rab_byung_month, get_rab_byung_month_days,
+    get_rab_byung_month_offset,
+};
+use crate::jd::J2000;
 use crate::sixtycycle::SixtyCycle;
 use crate::solar::{SolarDay, SolarYear};
+use crate::sxtwl::{PI_2, Sxtwl};
 use crate::types::{Culture, Tyme};
 
+/// 内置藏历数据覆盖的藏历年（含首尾，首年仅有12月），更新数据时须同步修改，否则编译失败
+pub const RABBYUNG_YEARS: RangeInclusive<isize> = 1950..=2050;
+
+const _: () = {
+    assert!(RAB_BYUNG_DATA[0].year as isize == *RABBYUNG_YEARS.start());
+    assert!(RAB_BYUNG_DATA[0].month == 11 && RAB_BYUNG_DATA[0].offset == 0);
+    assert!(RAB_BYUNG_DATA[RAB_BYUNG_DATA.len() - 1].year as isize == *RABBYUNG_YEARS.end());
+};
+
 /// 藏历五行
 #[derive(Debug, Clone)]
 pub struct RabByungElement {
@@ -314,12 +331,17 @@ impl RabByungMonth {
         }
 
         let y: isize = year.get_year();
-        if y < 1950 || y > 2050 {
-            return Err(format!("rab-byung year {} must between 1950 and 2050", y));
+        if !RABBYUNG_YEARS.contains(&y) {
+            return Err(format!(
+                "rab-byung year {} must between {} and {}",
+                y,
+                RABBYUNG_YEARS.start(),
+                RABBYUNG_YEARS.end()
+            ));
         }
 
         let m: usize = month.abs() as usize;
-        if y == 1950 && m < 12 {
+        if y == *RABBYUNG_YEARS.start() && m < 12 {
             return Err(format!(
                 "month {} must be 12 in rab-byung year {}",
                 month, y
@@ -429,8 +451,14 @@ impl RabByungMonth {
             }
         }
 
+        Self::from_index_in_year(y, (m - 1) as usize)
+    }
+
+    /// 从藏历年及年中索引（0-12）创建
+    fn from_index_in_year(year: RabByungYear, index: usize) -> Result<Self, String> {
+        let mut m: isize = index as isize + 1;
         let mut leap: bool = false;
-        let leap_month: isize = y.get_leap_month() as isize;
+        let leap_month: isize = year.get_leap_month() as isize;
         if leap_month > 0 {
             if m == leap_month + 1 {
                 leap = true;
@@ -440,7 +468,12 @@ impl RabByungMonth {
             }
         }
 
-        Self::new(y, if leap { -m } else { m })
+        Self::new(year, if leap { -m } else { m })
+    }
+
+    /// 自1950年12月初一（公历1951年1月8日）至本月首日的天数
+    fn get_offset(&self) -> usize {
+        get_rab_byung_month_offset(self.year.get_year() as usize, self.index_in_year).unwrap()
     }
 
     /// 特殊日子列表 (闰日为正，缺日为负)
@@ -584,13 +617,17 @@ impl RabByungDay {
 
     pub fn from_solar_day(solar_day: SolarDay) -> Result<Self, String> {
         let mut days: isize = solar_day.subtract(SolarDay::from_ymd(1951, 1, 8));
-        let mut m: RabByungMonth = RabByungMonth::from_ym(1950, 12)?;
-        let mut count: isize = m.get_day_count() as isize;
-        while days >= count {
-            days -= count;
-            m = m.next(1)?;
-            count = m.get_day_count() as isize;
+        if days < 0 {
+            return Err(format!(
+                "solar day {} is before rab-byung 1950-12-1",
+                solar_day
+            ));
         }
+        let (year, index, offset) = find_rab_byung_month(days as usize)
+            .ok_or_else(|| format!("solar day {} is after rab-byung 2050", solar_day))?;
+        let m: RabByungMonth =
+            RabByungMonth::from_index_in_year(RabByungYear::from_year(year as isize)?, index)?;
+        days -= offset as isize;
         let mut day: isize = days + 1;
         for &d in m.get_special_days().iter() {
             if d < 0 {
@@ -648,21 +685,17 @@ impl RabByungDay {
 
     /// 转换为公历日
     pub fn get_solar_day(&self) -> SolarDay {
-        let mut m: RabByungMonth =
-            RabByungMonth::new(RabByungYear::from_year(1950).unwrap(), 12).unwrap();
-        let mut n: isize = 0;
-        while m != self.month {
-            n += m.get_day_count() as isize;
-            m = m.next(1).unwrap();
-        }
-        let mut t: isize = self.day as isize;
+        let n: isize = self.month.get_offset() as isize;
+        // 按日序号（而非逐步修正后的值）判断缺日、重日
+        let day: isize = self.day as isize;
+        let mut t: isize = day;
         for &d in self.month.get_special_days().iter() {
             if d < 0 {
-                if t > -d {
+                if day > -d {
                     t -= 1;
                 }
             } else if d > 0 {
-                if t > d {
+                if day > d {
                     t += 1;
                 }
             }
@@ -676,6 +709,11 @@ impl RabByungDay {
     pub fn subtract(&self, other: Self) -> isize {
         self.get_solar_day().subtract(other.get_solar_day())
     }
+
+    /// 曜（星期）。藏历的九宫（sme ba）、八卦（spar kha）及宿（rgyu skar）须按时轮历推算，不同于中历的九星、二十八宿，暂未提供
+    pub fn get_week(&self) -> Week {
+        self.get_solar_day().get_week()
+    }
 }
 
 impl Display for RabByungDay {
@@ -693,14 +731,156 @@ impl PartialEq for RabByungDay {
 
 impl Eq for RabByungDay {}
 
+/// 推算藏历日所取的时刻（距公历日0时的天数），对照1951—2050年的内置数据取得
+static RAB_BYUNG_PROJECTION_TIME: f64 = 0.122;
+
+/// 朔望月平均长度（天）
+static SYNODIC_MONTH: f64 = 29.530589;
+
+/// 藏历日期及数据来源。内置数据（1950年12月至2050年）之后的日期按真太阴日（月日黄经差每12°为一日）及置闰规则推算，日序偏差通常不超过1日，不区分闰日、缺日
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct RabByungDate {
+    /// 年
+    year: isize,
+    /// 月，闰月为负数
+    month: isize,
+    /// 日，闰日为负数
+    day: isize,
+    /// 数据来源
+    projection: Projection,
+}
+
+impl RabByungDate {
+    /// 从公历日创建，早于内置数据时报错
+    pub fn from_solar_day(solar_day: SolarDay) -> Result<Self, String> {
+        let err: String = match RabByungDay::from_solar_day(solar_day) {
+            Ok(d) => {
+                return Ok(Self {
+                    year: d.get_year(),
+                    month: d.get_month(),
+                    day: d.get_day_with_leap(),
+                    projection: Projection::EXACT,
+                });
+            }
+            Err(e) => e,
+        };
+        let last: &RabByungMonthData = RAB_BYUNG_DATA.last().unwrap();
+        let count: usize = 30 + last.days.iter().filter(|&&d| d > 0).count()
+            - last.days.iter().filter(|&&d| d < 0).count();
+        let start: SolarDay =
+            SolarDay::from_ymd(1951, 1, 8).next((last.offset as usize + count) as isize);
+        if solar_day.is_before(start) {
+            return Err(err);
+        }
+        let m: RabByungMonth = RabByungMonth::from_index_in_year(
+            RabByungYear::from_year(last.year as isize)?,
+            last.month as usize,
+        )?;
+        let leap_month: isize = m.get_rab_byung_year().get_leap_month() as isize;
+        let (year, month) = Self::next_month(m.get_year(), m.get_month_with_leap(), leap_month);
+        Self::project(solar_day, start, year, month)
+    }
+
+    /// 以某藏历月首日为起点推算
+    fn project(
+        solar_day: SolarDay,
+        start: SolarDay,
+        year: isize,
+        month: isize,
+    ) -> Result<Self, String> {
+        let day: isize = Self::lunar_day(solar_day);
+        let first: SolarDay = solar_day.next(1 - day);
+        let n: isize = round(first.subtract(start) as f64 / SYNODIC_MONTH) as isize;
+        let (mut y, mut m) = (year, month);
+        let mut leap_month: isize = RabByungYear::from_year(y)?.get_leap_month() as isize;
+        for _ in 0..n {
+            let (next_y, next_m) = Self::next_month(y, m, leap_month);
+            if next_y != y {
+                leap_month = RabByungYear::from_year(next_y)?.get_leap_month() as isize;
+            }
+            (y, m) = (next_y, next_m);
+        }
+        Ok(Self {
+            year: y,
+            month: m,
+            day,
+            projection: Projection::PROJECTED,
+        })
+    }
+
+    /// 下一月，闰月在同名常规月之后
+    fn next_month(year: isize, month: isize, leap_month: isize) -> (isize, isize) {
+        if month == leap_month {
+            (year, -month)
+        } else if month.abs() == 12 {
+            (year + 1, 1)
+        } else {
+            (year, month.abs() + 1)
+        }
+    }
+
+    /// 真太阴日（1至30）
+    fn lunar_day(solar_day: SolarDay) -> isize {
+        let t: f64 =
+            (solar_day.get_julian_day().get_day() + RAB_BYUNG_PROJECTION_TIME - J2000) / 36525.0;
+        let w: f64 = (Sxtwl::m_sa_lon(t, -1, 60) % PI_2 + PI_2) % PI_2;
+        (w / (PI_2 / 30.0)) as isize + 1
+    }
+
+    /// 年
+    pub fn get_year(&self) -> isize {
+        self.year
+    }
+
+    /// 月，闰月为负数
+    pub fn get_month(&self) -> isize {
+        self.month
+    }
+
+    /// 日，闰日为负数
+    pub fn get_day(&self) -> isize {
+        self.day
+    }
+
+    /// 数据来源
+    pub fn get_projection(&self) -> Projection {
+        self.projection
+    }
+
+    /// 藏历日，推算的日期返回None
+    pub fn get_rab_byung_day(&self) -> Option<RabByungDay> {
+        match self.projection {
+            Projection::EXACT => RabByungDay::from_ymd(self.year, self.month, self.day).ok(),
+            Projection::PROJECTED => None,
+        }
+    }
+}
+
+impl Display for RabByungDate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{}{}{}{}{}",
+            RabByungYear::from_year(self.year).map_err(|_| core::fmt::Error)?,
+            if self.month < 0 { "闰" } else { "" },
+            RabByungMonth::NAMES[self.month.unsigned_abs() - 1],
+            if self.day < 0 { "闰" } else { "" },
+            RabByungDay::NAMES[self.day.unsigned_abs() - 1]
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::string::ToString;
 
     use crate::culture::Zodiac;
-    use crate::rabbyung::{RabByungDay, RabByungElement, RabByungMonth, RabByungYear};
+    use crate::enums::Projection;
+    use crate::rabbyung::{
+        RabByungDate, RabByungDay, RabByungElement, RabByungMonth, RabByungYear,
+    };
     use crate::solar::SolarDay;
-    use crate::types::Culture;
+    use crate::types::{Culture, Tyme};
 
     #[test]
     fn test0() {
@@ -943,4 +1123,73 @@ mod tests {
                 .to_string()
         );
     }
+
+    #[test]
+    fn test15() {
+        assert!(SolarDay::from_ymd(1951, 1, 7).get_rab_byung_day().is_err());
+        assert!(SolarDay::from_ymd(1, 1, 1).get_rab_byung_day().is_err());
+        assert!(SolarDay::from_ymd(2052, 1, 1).get_rab_byung_day().is_err());
+        assert!(RabByungDay::from_ymd(1950, 11, 1).is_err());
+        assert!(RabByungDay::from_ymd(2025, 13, 1).is_err());
+        assert!(RabByungDay::from_ymd(2025, 1, -31).is_err());
+    }
+
+    #[test]
+    fn test16() {
+        for (month, day) in [(1, 1), (1, 12), (1, 16), (2, 1), (2, 2), (2, 3), (2, 9)] {
+            let d: SolarDay = SolarDay::from_ymd(2024, month, day);
+            let r: RabByungDay = d.get_rab_byung_day().unwrap();
+            assert_eq!(d, r.get_solar_day());
+            assert_eq!(d.get_week(), r.get_week());
+        }
+    }
+
+    #[test]
+    fn test18() {
+        let first: SolarDay = SolarDay::from_ymd(1951, 1, 8);
+        let mut solar: SolarDay = first;
+        while solar.get_year() < 2051 {
+            let d: RabByungDay = solar.get_rab_byung_day().unwrap();
+            assert_eq!(solar, d.get_solar_day());
+            solar = solar.next(97);
+        }
+        let last: RabByungDay = RabByungMonth::from_ym(2050, 12)
+            .unwrap()
+            .get_days()
+            .pop()
+            .unwrap();
+        let after: SolarDay = last.get_solar_day().next(1);
+        assert!(after.get_rab_byung_day().is_err());
+        assert!(first.next(-1).get_rab_byung_day().is_err());
+    }
+
+    #[test]
+    fn test19() {
+        let date: RabByungDate =
+            RabByungDate::from_solar_day(SolarDay::from_ymd(2050, 6, 1)).unwrap();
+        assert_eq!(Projection::EXACT, date.get_projection());
+        assert!(date.get_rab_byung_day().is_some());
+
+        let date: RabByungDate =
+            RabByungDate::from_solar_day(SolarDay::from_ymd(2100, 6, 1)).unwrap();
+        assert_eq!(Projection::PROJECTED, date.get_projection());
+        assert!(date.get_rab_byung_day().is_none());
+        assert_eq!("第十八饶迥铁猴年四月廿四", date.to_string());
+        assert!(RabByungDate::from_solar_day(SolarDay::from_ymd(1950, 1, 1)).is_err());
+
+        // 以2040年正月为起点推算，与内置数据比较
+        let month: RabByungMonth = RabByungMonth::from_ym(2040, 1).unwrap();
+        let start: SolarDay = SolarDay::from_ymd(1951, 1, 8).next(month.get_offset() as isize);
+        let mut day: SolarDay = SolarDay::from_ymd(2045, 6, 1);
+        for _ in 0..60 {
+            let exact: RabByungDate = RabByungDate::from_solar_day(day).unwrap();
+            let projected: RabByungDate = RabByungDate::project(day, start, 2040, 1).unwrap();
+            if exact.get_month() == projected.get_month() {
+                assert!((exact.get_day().abs() - projected.get_day()).abs() <= 1);
+            } else {
+                assert!(exact.get_day().abs() >= 29 || exact.get_day().abs() <= 2);
+            }
+            day = day.next(1);
+        }
+    }
 }
@@ -0,0 +1,332 @@
+//! 与C++版寿星万年历（sxtwl）导出的参考数据逐日对比
+//!
+//! 参考数据为CSV，每行：公历日期,农历年,农历月（闰月为负）,农历日,年干支,月干支,日干支,节气（当日无节气留空），
+//! 例如 `2024-02-04,2023,12,25,甲辰,丙寅,戊戌,立春`。以`#`开头的行及空行忽略。
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use std::io::BufRead;
+
+use crate::jd::JulianDay;
+use crate::solar::{SolarDay, SolarTerm};
+use crate::types::Culture;
+
+/// 生成测试向量的公历日范围（1900-01-01至2100-12-31，含）
+static TEST_VECTOR_RANGE: (f64, f64) = (2415020.5, 2488433.5);
+
+/// 生成测试向量：以种子确定的伪随机公历日，按当前（全精度）算法计算农历、干支、所在节气及其时刻，
+/// 输出为可直接作为`src/generated_test_vectors.rs`的Rust源码（每行格式同参考数据，另加节气儒略日），在截断星历等低精度特性下复核。
+/// 须在全精度构建（全表、未启用trig-lut）下调用，结果才可作为测试向量
+pub fn generate_test_vectors(seed: u64, count: usize) -> String {
+    let mut state: u64 = seed;
+    let span: u64 = (TEST_VECTOR_RANGE.1 - TEST_VECTOR_RANGE.0) as u64 + 1;
+    let mut rows: Vec<String> = Vec::new();
+    for _ in 0..count {
+        let offset: u64 = split_mix64(&mut state) % span;
+        let day: SolarDay = SolarDay::from_julian_day(JulianDay::from_julian_day(
+            TEST_VECTOR_RANGE.0 + offset as f64,
+        ));
+        let lunar = day.get_lunar_day();
+        let cycle = day.get_sixty_cycle_day();
+        let term: SolarTerm = day.get_term();
+        rows.push(format!(
+            "{:04}-{:02}-{:02},{},{},{},{},{},{},{},{:.8}",
+            day.get_year(),
+            day.get_month(),
+            day.get_day(),
+            lunar.get_year(),
+            lunar.get_month(),
+            lunar.get_day(),
+            cycle.get_year().get_name(),
+            cycle.get_month().get_name(),
+            cycle.get_sixty_cycle().get_name(),
+            term.get_name(),
+            term.get_julian_day().get_day()
+        ));
+    }
+    format!(
+        "// 种子: {seed}\n\
+         // 自动生成的测试向量（reference::generate_test_vectors，全精度星历计算），请勿手工修改\n\
+         // 重新生成：SXTWL_TEST_VECTORS={seed},{count} cargo test --features reference\n\
+         \n\
+         /// 每行：公历日期,农历年,农历月（闰月为负）,农历日,年干支,月干支,日干支,所在节气,节气儒略日\n\
+         pub static TEST_VECTORS: &str = \"\\\n{}\n\";\n",
+        rows.join("\n")
+    )
+}
+
+/// SplitMix64伪随机数
+fn split_mix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z: u64 = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// 对比字段
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ReferenceField {
+    /// 农历日期
+    LunarDay,
+    /// 年干支
+    Year,
+    /// 月干支
+    Month,
+    /// 日干支
+    Day,
+    /// 节气
+    Term,
+}
+
+/// 不一致的条目
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReferenceMismatch {
+    /// 公历日
+    day: SolarDay,
+    /// 字段
+    field: ReferenceField,
+    /// 参考值
+    expected: String,
+    /// 本库计算值
+    actual: String,
+}
+
+impl ReferenceMismatch {
+    pub fn get_solar_day(&self) -> SolarDay {
+        self.day
+    }
+
+    pub fn get_field(&self) -> ReferenceField {
+        self.field
+    }
+
+    pub fn get_expected(&self) -> String {
+        self.expected.clone()
+    }
+
+    pub fn get_actual(&self) -> String {
+        self.actual.clone()
+    }
+}
+
+/// 对比结果统计
+#[derive(Debug, Clone, Default)]
+pub struct ReferenceReport {
+    /// 已对比天数
+    total: usize,
+    /// 不一致的条目
+    mismatches: Vec<ReferenceMismatch>,
+}
+
+impl ReferenceReport {
+    /// 已对比天数
+    pub fn get_total(&self) -> usize {
+        self.total
+    }
+
+    /// 不一致的条目
+    pub fn get_mismatches(&self) -> Vec<ReferenceMismatch> {
+        self.mismatches.clone()
+    }
+
+    /// 某字段不一致的天数
+    pub fn get_mismatch_count(&self, field: ReferenceField) -> usize {
+        self.mismatches.iter().filter(|m| m.field == field).count()
+    }
+
+    /// 是否全部一致
+    pub fn is_clean(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+
+    /// 对比一行参考数据
+    pub fn compare_line(&mut self, line: &str) -> Result<(), String> {
+        let line: &str = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            return Ok(());
+        }
+        let fields: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
+        if fields.len() != 8 {
+            return Err(format!("illegal reference line: {}", line));
+        }
+        let ymd: Vec<isize> = fields[0]
+            .split('-')
+            .map(|s| s.parse::<isize>())
+            .collect::<Result<Vec<isize>, _>>()
+            .map_err(|_| format!("illegal solar date: {}", fields[0]))?;
+        if ymd.len() != 3 || ymd[1] < 1 || ymd[2] < 1 {
+            return Err(format!("illegal solar date: {}", fields[0]));
+        }
+        let day: SolarDay = SolarDay::new(ymd[0], ymd[1] as usize, ymd[2] as usize)?;
+
+        let lunar = day.get_lunar_day();
+        let actual: String = format!(
+            "{},{},{}",
+            lunar.get_year(),
+            lunar.get_month(),
+            lunar.get_day()
+        );
+        self.check(
+            day,
+            ReferenceField::LunarDay,
+            &format!("{},{},{}", fields[1], fields[2], fields[3]),
+            actual,
+        );
+
+        let cycle = day.get_sixty_cycle_day();
+        self.check(
+            day,
+            ReferenceField::Year,
+            fields[4],
+            cycle.get_year().get_name(),
+        );
+        self.check(
+            day,
+            ReferenceField::Month,
+            fields[5],
+            cycle.get_month().get_name(),
+        );
+        self.check(
+            day,
+            ReferenceField::Day,
+            fields[6],
+            cycle.get_sixty_cycle().get_name(),
+        );
+        let term: String = day
+            .get_jie_qi()
+            .map(|(term, _)| term.get_name())
+            .unwrap_or_default();
+        self.check(day, ReferenceField::Term, fields[7], term);
+
+        self.total += 1;
+        Ok(())
+    }
+
+    /// 逐行读取并对比参考数据
+    pub fn compare_reader<R: BufRead>(&mut self, reader: R) -> Result<(), String> {
+        for line in reader.lines() {
+            self.compare_line(&line.map_err(|e| e.to_string())?)?;
+        }
+        Ok(())
+    }
+
+    fn check(&mut self, day: SolarDay, field: ReferenceField, expected: &str, actual: String) {
+        if expected != actual {
+            self.mismatches.push(ReferenceMismatch {
+                day,
+                field,
+                expected: expected.to_string(),
+                actual,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs::File;
+    use std::io::BufReader;
+
+    use crate::reference::{ReferenceField, ReferenceReport, generate_test_vectors};
+    use crate::sxtwl::ephemeris::KEEP_PERMILLE;
+
+    #[test]
+    fn test1() {
+        let mut report: ReferenceReport = ReferenceReport::default();
+        report
+            .compare_reader(
+                "# solar,lunar year,lunar month,lunar day,year,month,day,term\n\
+                 2024-02-04,2023,12,25,甲辰,丙寅,戊戌,立春\n\
+                 2024-02-10,2024,1,1,甲辰,丙寅,甲辰,\n\
+                 2023-03-22,2023,-2,1,癸卯,乙卯,己卯,\n"
+                    .as_bytes(),
+            )
+            .unwrap();
+        assert_eq!(3, report.get_total());
+        assert!(report.is_clean());
+
+        report
+            .compare_line("2024-02-11,2024,1,2,甲辰,丙寅,乙巳,雨水")
+            .unwrap();
+        assert_eq!(4, report.get_total());
+        assert_eq!(1, report.get_mismatch_count(ReferenceField::Term));
+        assert!(report.compare_line("2024-02-11,2024,1,2").is_err());
+    }
+
+    /// 设置环境变量SXTWL_REFERENCE_CSV指向C++ sxtwl导出的参考数据时逐日对比
+    #[test]
+    fn test2() {
+        let path = match env::var("SXTWL_REFERENCE_CSV") {
+            Ok(path) => path,
+            Err(_) => return,
+        };
+        let mut report: ReferenceReport = ReferenceReport::default();
+        report
+            .compare_reader(BufReader::new(File::open(path).unwrap()))
+            .unwrap();
+        for field in [
+            ReferenceField::LunarDay,
+            ReferenceField::Year,
+            ReferenceField::Month,
+            ReferenceField::Day,
+            ReferenceField::Term,
+        ] {
+            std::println!("{:?}: {}", field, report.get_mismatch_count(field));
+        }
+        for m in report.get_mismatches().iter().take(20) {
+            std::println!(
+                "{} {:?} expected {} actual {}",
+                m.get_solar_day(),
+                m.get_field(),
+                m.get_expected(),
+                m.get_actual()
+            );
+        }
+        assert!(report.is_clean(), "{} days compared", report.get_total());
+    }
+
+    #[test]
+    fn test3() {
+        let s = generate_test_vectors(2024, 3);
+        assert_eq!(s, generate_test_vectors(2024, 3));
+        assert_ne!(s, generate_test_vectors(2025, 3));
+        assert_eq!(
+            3,
+            s.lines()
+                .filter(|l| l.starts_with(|c: char| c.is_ascii_digit()))
+                .count()
+        );
+    }
+
+    /// 设置环境变量SXTWL_TEST_VECTORS=种子,条数时重新生成src/generated_test_vectors.rs；
+    /// 测试向量须按全精度计算，截断星历、trig-lut或外部星历表未加载时拒绝写入
+    #[test]
+    fn test4() {
+        let spec = match env::var("SXTWL_TEST_VECTORS") {
+            Ok(spec) => spec,
+            Err(_) => return,
+        };
+        assert_eq!(
+            1000, KEEP_PERMILLE,
+            "test vectors must be generated with the full ephemeris tables"
+        );
+        assert!(
+            !cfg!(feature = "trig-lut"),
+            "test vectors must not be generated with trig-lut"
+        );
+        #[cfg(feature = "external-ephemeris")]
+        assert!(
+            crate::sxtwl::ephemeris::is_initialized(),
+            "test vectors must not be generated from the fallback ephemeris tables"
+        );
+        let (seed, count) = spec.split_once(',').unwrap();
+        std::fs::write(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/src/generated_test_vectors.rs"),
+            generate_test_vectors(seed.trim().parse().unwrap(), count.trim().parse().unwrap()),
+        )
+        .unwrap();
+    }
+}
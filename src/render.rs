@@ -0,0 +1,198 @@
+//! 终端万年历文本：按月排成定宽网格，每格为公历日与农历标注
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::culture::Week;
+use crate::lunar::LunarDay;
+use crate::solar::{SolarDay, SolarMonth, SolarTermDay, SolarWeek};
+use crate::types::Culture;
+
+/// 每格显示宽度（半角字符数，汉字计2）
+pub static CELL_WIDTH: usize = 9;
+
+/// 月视图：首行为年月，次行为星期，其后每行一周；每格为公历日、标记（*交节，+节日）与农历标注（节气、节日、月首为月名，其余为日名），非本月的格留空
+pub fn month_view(month: SolarMonth, start: usize) -> String {
+    let mut lines: Vec<String> = Vec::new();
+    lines.push(format!("{}", month));
+    let mut header: Vec<String> = Vec::new();
+    for i in 0..7 {
+        header.push(pad_right(
+            &format!("  {}", Week::from_index((start + i) as isize).get_name()),
+            CELL_WIDTH,
+        ));
+    }
+    lines.push(header.join(" "));
+    for week in month.get_weeks(start) {
+        lines.push(week_row(month, week));
+    }
+    lines.join("\n")
+}
+
+fn week_row(month: SolarMonth, week: SolarWeek) -> String {
+    let mut cells: Vec<String> = Vec::new();
+    for day in week.get_days() {
+        if day.get_solar_month() == month {
+            cells.push(cell(day));
+        } else {
+            cells.push(pad_right("", CELL_WIDTH));
+        }
+    }
+    cells.join(" ")
+}
+
+fn cell(day: SolarDay) -> String {
+    let (mark, label) = label(day);
+    pad_right(
+        &format!("{:>2}{}{}", day.get_day(), mark, label),
+        CELL_WIDTH,
+    )
+}
+
+/// 标记与农历标注
+fn label(day: SolarDay) -> (char, String) {
+    let term_day: SolarTermDay = day.get_term_day();
+    if term_day.get_day_index() == 0 {
+        return ('*', term_day.get_name());
+    }
+    if let Some(name) = festival_name(day) {
+        return ('+', name);
+    }
+    let lunar: LunarDay = day.get_lunar_day();
+    if lunar.get_day() == 1 {
+        (' ', lunar.get_lunar_month().get_name())
+    } else {
+        (' ', lunar.get_name())
+    }
+}
+
+#[cfg(feature = "festival")]
+fn festival_name(day: SolarDay) -> Option<String> {
+    if let Some(f) = day.get_lunar_day().get_festival() {
+        return Some(f.get_name());
+    }
+    day.get_festival().map(|f| f.get_name())
+}
+
+#[cfg(not(feature = "festival"))]
+fn festival_name(_day: SolarDay) -> Option<String> {
+    None
+}
+
+/// 字符的终端显示宽度：东亚宽字符（汉字、假名、谚文、全角符号等）计2，组合附加符号及零宽字符计0，其余计1
+pub fn char_width(c: char) -> usize {
+    match c as u32 {
+        0x0300..=0x036F | 0x200B..=0x200F | 0xFE00..=0xFE0F => 0,
+        0x1100..=0x115F
+        | 0x2E80..=0x303E
+        | 0x3041..=0x33FF
+        | 0x3400..=0x4DBF
+        | 0x4E00..=0x9FFF
+        | 0xA000..=0xA4CF
+        | 0xAC00..=0xD7A3
+        | 0xF900..=0xFAFF
+        | 0xFE30..=0xFE4F
+        | 0xFF00..=0xFF60
+        | 0xFFE0..=0xFFE6
+        | 0x1F300..=0x1F64F
+        | 0x1F900..=0x1F9FF
+        | 0x20000..=0x3FFFD => 2,
+        _ => 1,
+    }
+}
+
+/// 字符串的终端显示宽度
+pub fn display_width(s: &str) -> usize {
+    s.chars().map(char_width).sum()
+}
+
+/// 截取不超过指定显示宽度的前缀，放不下的宽字符整个舍去
+pub fn truncate(s: &str, width: usize) -> String {
+    let mut w: usize = 0;
+    let mut r: String = String::new();
+    for c in s.chars() {
+        w += char_width(c);
+        if w > width {
+            break;
+        }
+        r.push(c);
+    }
+    r
+}
+
+/// 截取后在右侧补空格至指定显示宽度（左对齐）
+pub fn pad_right(s: &str, width: usize) -> String {
+    let mut r: String = truncate(s, width);
+    for _ in display_width(&r)..width {
+        r.push(' ');
+    }
+    r
+}
+
+/// 截取后在左侧补空格至指定显示宽度（右对齐）
+pub fn pad_left(s: &str, width: usize) -> String {
+    let r: String = truncate(s, width);
+    let mut l: String = String::new();
+    for _ in display_width(&r)..width {
+        l.push(' ');
+    }
+    l + &r
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    use crate::render::{
+        CELL_WIDTH, char_width, display_width, month_view, pad_left, pad_right, truncate,
+    };
+    use crate::solar::SolarMonth;
+
+    #[test]
+    fn test1() {
+        let s: String = month_view(SolarMonth::from_ym(2024, 2), 1);
+        let lines: Vec<&str> = s.split('\n').collect();
+        assert_eq!("2024年2月", lines[0]);
+        assert_eq!(
+            "  一        二        三        四        五        六        日     ",
+            lines[1]
+        );
+        // 2024年2月1日为星期四
+        assert_eq!(7, lines.len());
+        assert_eq!(
+            "                               1 廿二    2 廿三    3 廿四    4*立春  ",
+            lines[2]
+        );
+        assert!(lines[5].contains("19*雨水"));
+        for line in lines.iter().skip(1) {
+            assert_eq!(CELL_WIDTH * 7 + 6, display_width(line));
+        }
+        assert!(month_view(SolarMonth::from_ym(2024, 3), 0).contains("10 二月"));
+    }
+
+    #[cfg(feature = "festival")]
+    #[test]
+    fn test2() {
+        let s: String = month_view(SolarMonth::from_ym(2024, 9), 0);
+        assert!(s.contains("17+中秋节"));
+        assert!(s.contains("10+教师节"));
+        assert!(s.contains(" 7*白露"));
+    }
+
+    #[test]
+    fn test3() {
+        assert_eq!(1, char_width('a'));
+        assert_eq!(2, char_width('闰'));
+        assert_eq!(2, char_width('，'));
+        assert_eq!(0, char_width('\u{0301}'));
+        assert_eq!(12, display_width("2024年闰二月"));
+        assert_eq!("廿二", truncate("廿二日", 5));
+        assert_eq!("1 廿", truncate("1 廿二", 5));
+        assert_eq!("立春  ", pad_right("立春", 6));
+        assert_eq!("  立春", pad_left("立春", 6));
+        assert_eq!("腊八 ", pad_right("腊八节", 5));
+        assert_eq!(String::from("ab"), pad_left("abc", 2));
+    }
+}
@@ -0,0 +1,389 @@
+//! 择日：按星期、冲煞、建除十二值神、神煞、工作日等条件筛选公历日
+
+use core::fmt::{Display, Formatter};
+use core::ops::RangeInclusive;
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "god")]
+use crate::culture::God;
+use crate::culture::{Duty, Week, Zodiac};
+use crate::sixtycycle::SixtyCycle;
+use crate::solar::{SolarDay, SolarTerm};
+#[cfg(feature = "god")]
+use crate::types::Culture;
+use crate::types::Tyme;
+
+/// 按节令月分段扫描的公历日迭代器：同一节令月内月支不变，日干支逐日递增，
+/// 由（月支，日干支）的命中表直接跳到下一个命中日，不必逐日构造干支日
+#[derive(Debug, Clone)]
+pub struct MatchingDays {
+    /// 下一个待查的公历日，结束时为None
+    day: Option<SolarDay>,
+    /// 结束公历日（含）
+    end: SolarDay,
+    /// 命中表：[月支索引][日干支索引]
+    table: [[bool; 60]; 12],
+    /// 当前节令月的月支索引
+    branch: usize,
+    /// 当前节令月的最后一天，尚未定位时为None
+    month_end: Option<SolarDay>,
+}
+
+impl MatchingDays {
+    fn new(range: RangeInclusive<SolarDay>, table: [[bool; 60]; 12]) -> Self {
+        let (start, end) = range.into_inner();
+        Self {
+            day: if start > end { None } else { Some(start) },
+            end,
+            table,
+            branch: 0,
+            month_end: None,
+        }
+    }
+
+    /// 定位公历日所在的节令月
+    fn locate(&mut self, day: SolarDay) {
+        let term: SolarTerm = day.get_term();
+        let jie: SolarTerm = if term.is_jie() { term } else { term.next(-1) };
+        self.branch = jie.get_month_branch().get_index();
+        self.month_end = Some(jie.next(2).get_solar_day().next(-1));
+    }
+}
+
+impl Iterator for MatchingDays {
+    type Item = SolarDay;
+
+    fn next(&mut self) -> Option<SolarDay> {
+        while let Some(day) = self.day {
+            if day > self.end {
+                break;
+            }
+            match self.month_end {
+                Some(month_end) if day <= month_end => {}
+                _ => self.locate(day),
+            }
+            let month_end: SolarDay = self.month_end.unwrap();
+            let cycle: usize = SixtyCycle::of_julian_day(day.get_julian_day()).get_index();
+            let row: &[bool; 60] = &self.table[self.branch];
+            match (0..60).find(|k| row[(cycle + k) % 60]) {
+                Some(k) => {
+                    let found: SolarDay = day.next(k as isize);
+                    if found <= month_end && found <= self.end {
+                        self.day = Some(found.next(1));
+                        return Some(found);
+                    }
+                    self.day = Some(month_end.next(1));
+                }
+                None => self.day = Some(month_end.next(1)),
+            }
+        }
+        self.day = None;
+        None
+    }
+}
+
+/// 范围内值神为指定建除的公历日
+pub fn days_with(duty: Duty, range: RangeInclusive<SolarDay>) -> MatchingDays {
+    let mut table: [[bool; 60]; 12] = [[false; 60]; 12];
+    for (branch, row) in table.iter_mut().enumerate() {
+        for (cycle, hit) in row.iter_mut().enumerate() {
+            *hit = (cycle % 12 + 12 - branch) % 12 == duty.get_index();
+        }
+    }
+    MatchingDays::new(range, table)
+}
+
+/// 范围内有指定神煞的公历日
+#[cfg(feature = "god")]
+pub fn days_with_god(god: God, range: RangeInclusive<SolarDay>) -> MatchingDays {
+    let mut table: [[bool; 60]; 12] = [[false; 60]; 12];
+    for (branch, row) in table.iter_mut().enumerate() {
+        let month: SixtyCycle = SixtyCycle::from_index(branch as isize);
+        for (cycle, hit) in row.iter_mut().enumerate() {
+            *hit = God::get_day_gods_slice(month.clone(), SixtyCycle::from_index(cycle as isize))
+                .contains(&(god.get_index() as u8));
+        }
+    }
+    MatchingDays::new(range, table)
+}
+
+/// 择日条件
+#[derive(Debug, Clone)]
+pub enum DateFilter {
+    /// 星期为其中之一
+    WeekdayIn(Vec<Week>),
+    /// 日支不冲指定生肖
+    NoClashWith(Zodiac),
+    /// 建除十二值神为其中之一
+    DutyIn(Vec<Duty>),
+    /// 神煞含其中之一
+    #[cfg(feature = "god")]
+    GodIn(Vec<God>),
+    /// 工作日（启用holiday时计法定假日及调休）
+    LegalWorkday,
+}
+
+impl DateFilter {
+    /// 公历日满足条件时返回依据
+    pub fn matches(&self, day: SolarDay) -> Option<String> {
+        match self {
+            Self::WeekdayIn(weeks) => {
+                let week: Week = day.get_week();
+                weeks.contains(&week).then(|| format!("星期{}", week))
+            }
+            Self::NoClashWith(zodiac) => {
+                let clash: Zodiac = SixtyCycle::of_julian_day(day.get_julian_day())
+                    .get_earth_branch()
+                    .get_opposite()
+                    .get_zodiac();
+                (clash != *zodiac).then(|| format!("日冲{}", clash))
+            }
+            Self::DutyIn(duties) => {
+                let duty: Duty = day.get_sixty_cycle_day().get_duty();
+                duties.contains(&duty).then(|| format!("{}日", duty))
+            }
+            #[cfg(feature = "god")]
+            Self::GodIn(gods) => {
+                let names: Vec<String> = day
+                    .get_sixty_cycle_day()
+                    .get_gods()
+                    .into_iter()
+                    .filter(|g| gods.contains(g))
+                    .map(|g| g.get_name())
+                    .collect();
+                (!names.is_empty()).then(|| names.join("、"))
+            }
+            Self::LegalWorkday => Self::is_workday(day).then(|| String::from("工作日")),
+        }
+    }
+
+    #[cfg(feature = "holiday")]
+    fn is_workday(day: SolarDay) -> bool {
+        match day.get_legal_holiday() {
+            Some(holiday) => holiday.is_work(),
+            None => !matches!(day.get_week().get_index(), 0 | 6),
+        }
+    }
+
+    #[cfg(not(feature = "holiday"))]
+    fn is_workday(day: SolarDay) -> bool {
+        !matches!(day.get_week().get_index(), 0 | 6)
+    }
+}
+
+impl Display for DateFilter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::WeekdayIn(weeks) => {
+                let names: Vec<String> = weeks.iter().map(|w| format!("星期{}", w)).collect();
+                write!(f, "{}", names.join("、"))
+            }
+            Self::NoClashWith(zodiac) => write!(f, "不冲{}", zodiac),
+            Self::DutyIn(duties) => {
+                let names: Vec<String> = duties.iter().map(|d| format!("{}日", d)).collect();
+                write!(f, "{}", names.join("、"))
+            }
+            #[cfg(feature = "god")]
+            Self::GodIn(gods) => {
+                let names: Vec<String> = gods.iter().map(|g| g.get_name()).collect();
+                write!(f, "{}", names.join("、"))
+            }
+            Self::LegalWorkday => write!(f, "工作日"),
+        }
+    }
+}
+
+/// 择日候选日及各条件的依据
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateCandidate {
+    /// 公历日
+    day: SolarDay,
+    /// 依据，与条件（先必须满足的，后须排除的）一一对应
+    reasons: Vec<String>,
+}
+
+impl DateCandidate {
+    /// 公历日
+    pub fn get_solar_day(&self) -> SolarDay {
+        self.day
+    }
+
+    /// 依据
+    pub fn get_reasons(&self) -> &[String] {
+        &self.reasons
+    }
+}
+
+impl Display for DateCandidate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}：{}", self.day, self.reasons.join("；"))
+    }
+}
+
+/// 择日查询：在公历日范围内按条件组合筛选
+#[derive(Debug, Clone)]
+pub struct DatePicker {
+    /// 起止公历日（含）
+    range: RangeInclusive<SolarDay>,
+    /// 必须满足的条件
+    requires: Vec<DateFilter>,
+    /// 须排除的条件
+    excludes: Vec<DateFilter>,
+}
+
+impl DatePicker {
+    pub fn new(range: RangeInclusive<SolarDay>) -> Self {
+        Self {
+            range,
+            requires: Vec::new(),
+            excludes: Vec::new(),
+        }
+    }
+
+    /// 增加必须满足的条件
+    pub fn require(mut self, filter: DateFilter) -> Self {
+        self.requires.push(filter);
+        self
+    }
+
+    /// 增加须排除的条件
+    pub fn exclude(mut self, filter: DateFilter) -> Self {
+        self.excludes.push(filter);
+        self
+    }
+
+    /// 判定某公历日，入选时返回候选日
+    pub fn evaluate(&self, day: SolarDay) -> Option<DateCandidate> {
+        let mut reasons: Vec<String> = Vec::new();
+        for filter in self.requires.iter() {
+            reasons.push(filter.matches(day)?);
+        }
+        for filter in self.excludes.iter() {
+            if filter.matches(day).is_some() {
+                return None;
+            }
+            reasons.push(format!("非{}", filter));
+        }
+        Some(DateCandidate { day, reasons })
+    }
+
+    /// 按日期先后逐个返回候选日
+    pub fn iter(&self) -> impl Iterator<Item = DateCandidate> + '_ {
+        let (start, end) = (*self.range.start(), *self.range.end());
+        let count: isize = if end < start {
+            0
+        } else {
+            end.subtract(start) + 1
+        };
+        (0..count).filter_map(move |i| self.evaluate(start.next(i)))
+    }
+
+    /// 最早的n个候选日
+    pub fn take(&self, n: usize) -> Vec<DateCandidate> {
+        self.iter().take(n).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use crate::culture::Duty;
+    use crate::selection::days_with;
+    use crate::solar::SolarDay;
+    use crate::types::{Culture, Tyme};
+
+    #[test]
+    fn test1() {
+        let start: SolarDay = SolarDay::from_ymd(2024, 1, 1);
+        let end: SolarDay = SolarDay::from_ymd(2024, 12, 31);
+        let days: Vec<SolarDay> = days_with(Duty::from_name("除"), start..=end).collect();
+        let mut expected: Vec<SolarDay> = Vec::new();
+        let mut day: SolarDay = start;
+        while day <= end {
+            if day.get_sixty_cycle_day().get_duty().get_name() == "除" {
+                expected.push(day);
+            }
+            day = day.next(1);
+        }
+        assert_eq!(expected, days);
+        assert_eq!(0, days_with(Duty::from_index(0), end..=start).count());
+    }
+
+    #[cfg(feature = "god")]
+    #[test]
+    fn test2() {
+        use crate::culture::God;
+        use crate::selection::days_with_god;
+
+        let start: SolarDay = SolarDay::from_ymd(2024, 1, 1);
+        let end: SolarDay = SolarDay::from_ymd(2024, 6, 30);
+        let days: Vec<SolarDay> = days_with_god(God::from_name("天德"), start..=end).collect();
+        let mut expected: Vec<SolarDay> = Vec::new();
+        let mut day: SolarDay = start;
+        while day <= end {
+            if day
+                .get_sixty_cycle_day()
+                .get_gods()
+                .iter()
+                .any(|g| g.get_name() == "天德")
+            {
+                expected.push(day);
+            }
+            day = day.next(1);
+        }
+        assert!(!days.is_empty());
+        assert_eq!(expected, days);
+    }
+
+    #[test]
+    fn test3() {
+        use alloc::string::ToString;
+
+        use crate::culture::{Week, Zodiac};
+        use crate::selection::{DateCandidate, DateFilter, DatePicker};
+
+        let picker: DatePicker =
+            DatePicker::new(SolarDay::from_ymd(2024, 6, 1)..=SolarDay::from_ymd(2024, 6, 30))
+                .require(DateFilter::WeekdayIn(vec![
+                    Week::from_index(0),
+                    Week::from_index(6),
+                ]))
+                .require(DateFilter::NoClashWith(Zodiac::from_name("鼠")))
+                .require(DateFilter::DutyIn(vec![
+                    Duty::from_name("成"),
+                    Duty::from_name("开"),
+                ]))
+                .exclude(DateFilter::LegalWorkday);
+        let l: Vec<DateCandidate> = picker.take(10);
+        assert!(!l.is_empty());
+        for c in l.iter() {
+            let day: SolarDay = c.get_solar_day();
+            assert!(matches!(day.get_week().get_index(), 0 | 6));
+            let duty: String = day.get_sixty_cycle_day().get_duty().get_name();
+            assert!(duty == "成" || duty == "开");
+            assert_ne!(
+                "午",
+                day.get_sixty_cycle_day()
+                    .get_sixty_cycle()
+                    .get_earth_branch()
+                    .get_name()
+            );
+            assert_eq!(4, c.get_reasons().len());
+            assert_eq!("非工作日", c.get_reasons()[3]);
+        }
+        assert_eq!(
+            "2024年6月9日：星期日；日冲狗；开日；非工作日",
+            l[0].to_string()
+        );
+        assert_eq!(
+            "不冲鼠",
+            DateFilter::NoClashWith(Zodiac::from_name("鼠")).to_string()
+        );
+    }
+}
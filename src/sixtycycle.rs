@@ -5,6 +5,7 @@ use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use libm::{ceil, floor};
 
+use crate::context::CalendarContext;
 #[cfg(feature = "god")]
 use crate::culture::God;
 #[cfg(feature = "fetus")]
@@ -21,14 +22,20 @@ use crate::culture::{Direction, Duty, Element, Sound, Taboo, Ten, Terrain, Twent
 #[cfg(feature = "eight-char")]
 use crate::eightchar::EightChar;
 use crate::enums::HideHeavenStemType;
-use crate::enums::YinYang;
+use crate::enums::{SpringSwitch, YinYang};
+use crate::jd::JulianDay;
 use crate::lunar::{LunarDay, LunarHour, LunarMonth, LunarYear};
-use crate::solar::{SolarDay, SolarTerm, SolarTime};
+use crate::solar::{SolarDay, SolarTerm, SolarTermDay, SolarTime};
 use crate::types::{AbstractCulture, AbstractCultureDay, Culture, LoopTyme, Tyme};
 
 pub static HEAVEN_STEM_NAMES: [&str; 10] =
     ["甲", "乙", "丙", "丁", "戊", "己", "庚", "辛", "壬", "癸"];
 
+/// 天干拼音
+pub static HEAVEN_STEM_NAMES_EN: [&str; 10] = [
+    "Jia", "Yi", "Bing", "Ding", "Wu", "Ji", "Geng", "Xin", "Ren", "Gui",
+];
+
 /// 天干（天元）
 #[derive(Debug, Clone)]
 pub struct HeavenStem {
@@ -74,6 +81,11 @@ impl HeavenStem {
         }
     }
 
+    /// 英文名称（拼音）
+    pub fn name_en(&self) -> String {
+        HEAVEN_STEM_NAMES_EN[self.get_index()].to_string()
+    }
+
     pub fn get_index(&self) -> usize {
         self.parent.get_index()
     }
@@ -187,6 +199,11 @@ pub static EARTH_BRANCH_NAMES: [&str; 12] = [
     "子", "丑", "寅", "卯", "辰", "巳", "午", "未", "申", "酉", "戌", "亥",
 ];
 
+/// 地支拼音
+pub static EARTH_BRANCH_NAMES_EN: [&str; 12] = [
+    "Zi", "Chou", "Yin", "Mao", "Chen", "Si", "Wu", "Wei", "Shen", "You", "Xu", "Hai",
+];
+
 /// 地支（地元）
 #[derive(Debug, Clone)]
 pub struct EarthBranch {
@@ -232,6 +249,17 @@ impl EarthBranch {
         }
     }
 
+    /// 从时刻的小时数（0-23）创建时辰地支，23点属子时；小时数须先经校验（如SolarTime），超出范围时调试构建下panic
+    pub fn from_hour(hour: usize) -> Self {
+        debug_assert!(hour < 24, "illegal hour: {}", hour);
+        Self::from_index(hour.div_ceil(2) as isize)
+    }
+
+    /// 英文名称（拼音）
+    pub fn name_en(&self) -> String {
+        EARTH_BRANCH_NAMES_EN[self.get_index()].to_string()
+    }
+
     pub fn get_index(&self) -> usize {
         self.parent.get_index()
     }
@@ -240,6 +268,12 @@ impl EarthBranch {
         self.parent.get_size()
     }
 
+    /// 时辰对应的钟点范围（起始小时，结束小时），如子时为(23, 1)
+    pub fn hour_range(&self) -> (usize, usize) {
+        let i: usize = self.get_index();
+        ((i * 2 + 23) % 24, i * 2 + 1)
+    }
+
     /// 五行
     pub fn get_element(&self) -> Element {
         Element::from_index([4, 2, 0, 0, 2, 1, 1, 2, 3, 3, 2, 4][self.get_index()])
@@ -413,6 +447,38 @@ impl SixtyCycle {
         }
     }
 
+    /// 由天干、地支组成干支，天干地支阴阳不同（如甲丑）时报错
+    pub fn from_stem_branch(
+        heaven_stem: HeavenStem,
+        earth_branch: EarthBranch,
+    ) -> Result<Self, String> {
+        let stem: isize = heaven_stem.get_index() as isize;
+        let branch: isize = earth_branch.get_index() as isize;
+        if (stem - branch) % 2 != 0 {
+            return Err(format!(
+                "illegal sixty cycle: {}{}",
+                heaven_stem.get_name(),
+                earth_branch.get_name()
+            ));
+        }
+        // 干支索引i满足i%10=stem且i%12=branch，即i=6*stem-5*branch（模60）
+        Ok(Self::from_index(6 * stem - 5 * branch))
+    }
+
+    /// 儒略日所在日（0时为日界）的日柱，各模块共用的干支纪日公式，跨1582年历法改革连续
+    pub fn of_julian_day(jd: JulianDay) -> Self {
+        Self::from_index(floor(jd.get_day() + 0.5) as isize + 9000049)
+    }
+
+    /// 英文名称（拼音），如：Jia-Zi
+    pub fn name_en(&self) -> String {
+        format!(
+            "{}-{}",
+            self.get_heaven_stem().name_en(),
+            self.get_earth_branch().name_en()
+        )
+    }
+
     pub fn get_index(&self) -> usize {
         self.parent.get_index()
     }
@@ -468,6 +534,24 @@ impl PartialEq for SixtyCycle {
 
 impl Eq for SixtyCycle {}
 
+/// 稳定编码：索引0（甲子）至59（癸亥），各版本保持不变，可用于持久化
+impl From<SixtyCycle> for u8 {
+    fn from(cycle: SixtyCycle) -> Self {
+        cycle.get_index() as u8
+    }
+}
+
+impl TryFrom<u8> for SixtyCycle {
+    type Error = String;
+
+    fn try_from(code: u8) -> Result<Self, Self::Error> {
+        if code >= 60 {
+            return Err(format!("illegal sixty cycle code: {}", code));
+        }
+        Ok(Self::from_index(code as isize))
+    }
+}
+
 impl Into<LoopTyme> for SixtyCycle {
     fn into(self) -> LoopTyme {
         self.parent
@@ -669,6 +753,28 @@ impl SixtyCycleYear {
         Twenty::from_index(floor((self.year as f64 - 1864.0) / 20.0) as isize)
     }
 
+    /// 上朔日干支
+    pub fn get_shang_shuo(&self) -> SixtyCycle {
+        SixtyCycle::from_index(
+            self.get_sixty_cycle().get_heaven_stem().get_index() as isize * 6 - 1,
+        )
+    }
+
+    /// 本干支年（立春至次年立春）内的上朔日
+    pub fn get_shang_shuo_days(&self) -> Vec<SolarDay> {
+        let start: SolarDay = SolarTerm::from_index(self.year, 3).get_solar_day();
+        let end: SolarDay = SolarTerm::from_index(self.year + 1, 3).get_solar_day();
+        let target: isize = self.get_shang_shuo().get_index() as isize;
+        let current: isize = start.get_lunar_day().get_sixty_cycle().get_index() as isize;
+        let mut d: SolarDay = start.next((target - current).rem_euclid(60));
+        let mut l: Vec<SolarDay> = Vec::new();
+        while d.is_before(end) {
+            l.push(d);
+            d = d.next(60);
+        }
+        l
+    }
+
     pub fn get_jupiter_direction(&self) -> Direction {
         Direction::from_index(
             [0, 7, 7, 2, 3, 3, 8, 1, 1, 6, 0, 0]
@@ -858,6 +964,49 @@ impl SixtyCycleDay {
         }
     }
 
+    /// 校验起止公历日（含）之间的日柱逐日连续（含1582年历法改革的日期跳变），
+    /// 且与按农历月朔日推算的日柱（朔日儒略日加日序，不经儒略日纪日公式）一致，返回校验的天数
+    pub fn audit_continuity(start: SolarDay, end: SolarDay) -> Result<usize, String> {
+        if end.is_before(start) {
+            return Err(format!("illegal solar day range: {}..={}", start, end));
+        }
+        let mut day: SolarDay = start;
+        let mut previous: Option<SixtyCycle> = None;
+        let mut n: usize = 0;
+        loop {
+            let pillar: SixtyCycle = SixtyCycle::of_julian_day(day.get_julian_day());
+            if let Some(p) = previous
+                && p.next(1) != pillar
+            {
+                return Err(format!(
+                    "day pillar break at {}: {} after {}",
+                    day, pillar, p
+                ));
+            }
+            let lunar_day: LunarDay = day.get_lunar_day();
+            let lunar: SixtyCycle = SixtyCycle::from_index(
+                lunar_day
+                    .get_lunar_month()
+                    .get_first_julian_day()
+                    .next(lunar_day.get_day() as isize - 12)
+                    .get_day() as isize,
+            );
+            if lunar != pillar {
+                return Err(format!(
+                    "day pillar mismatch at {}: {} by lunar day, {} by julian day",
+                    day, lunar, pillar
+                ));
+            }
+            n += 1;
+            if day == end {
+                break;
+            }
+            previous = Some(pillar);
+            day = day.next(1);
+        }
+        Ok(n)
+    }
+
     /// 公历日
     pub fn get_solar_day(&self) -> SolarDay {
         self.solar_day
@@ -891,6 +1040,42 @@ impl SixtyCycleDay {
         )
     }
 
+    /// 是否月建日（日支与月支相同）
+    pub fn is_month_establish(&self) -> bool {
+        self.day.get_earth_branch() == self.get_month().get_earth_branch()
+    }
+
+    /// 是否月破日（日支与月支相冲）
+    pub fn is_month_break(&self) -> bool {
+        self.day.get_earth_branch() == self.get_month().get_earth_branch().get_opposite()
+    }
+
+    /// 是否上朔日（甲年癸亥、乙年己巳、丙年乙亥……癸年丁巳）
+    pub fn is_shang_shuo(&self) -> bool {
+        self.day.get_index()
+            == self
+                .month
+                .get_sixty_cycle_year()
+                .get_shang_shuo()
+                .get_index()
+    }
+
+    /// 是否四离日（春分、夏至、秋分、冬至前一日）
+    pub fn is_si_li(&self) -> bool {
+        self.is_term_eve(0)
+    }
+
+    /// 是否四绝日（立春、立夏、立秋、立冬前一日）
+    pub fn is_si_jue(&self) -> bool {
+        self.is_term_eve(3)
+    }
+
+    /// 次日是否为交节日，且节气索引模6余offset
+    fn is_term_eve(&self, offset: usize) -> bool {
+        let term_day: SolarTermDay = self.solar_day.next(1).get_term_day();
+        term_day.get_day_index() == 0 && term_day.get_solar_term().get_index() % 6 == offset
+    }
+
     /// 太岁方位
     pub fn get_jupiter_direction(&self) -> Direction {
         let index: isize = self.day.get_index() as isize;
@@ -1089,6 +1274,21 @@ impl SixtyCycleHour {
         }
     }
 
+    /// 按上下文的立春当天换年取法计算：取SpringSwitch::DAY时，立春当天交节前的年柱、月柱也按新一年寅月
+    pub fn from_solar_time_with_context(solar_time: SolarTime, context: &CalendarContext) -> Self {
+        let mut h: Self = Self::from_solar_time(solar_time);
+        if context.get_spring_switch() == SpringSwitch::DAY {
+            let spring: SolarTime = SolarTerm::from_index(solar_time.get_year(), 3)
+                .get_julian_day()
+                .get_solar_time();
+            let solar_day: SolarDay = solar_time.get_solar_day();
+            if solar_day == spring.get_solar_day() && solar_time.is_before(spring) {
+                h.day.month = SixtyCycleDay::from_solar_day(solar_day).month;
+            }
+        }
+        h
+    }
+
     pub fn get_year(&self) -> SixtyCycle {
         self.day.get_year()
     }
@@ -1114,11 +1314,7 @@ impl SixtyCycleHour {
     }
 
     pub fn get_index_in_day(&self) -> usize {
-        let h: usize = self.solar_time.get_hour();
-        if h == 23 {
-            return 0;
-        }
-        (h + 1) / 2
+        EarthBranch::from_hour(self.solar_time.get_hour()).get_index()
     }
 
     #[cfg(feature = "eight-char")]
@@ -1135,7 +1331,7 @@ impl SixtyCycleHour {
     pub fn get_nine_star(&self) -> NineStar {
         let solar: SolarDay = self.solar_time.get_solar_day();
         let dong_zhi: SolarTerm = SolarTerm::from_index(solar.get_year(), 0);
-        let earth_branch_index: isize = self.get_index_in_day() as isize % 12;
+        let earth_branch_index: isize = self.get_index_in_day() as isize;
         let mut index: isize = [8, 5, 2][self.get_day().get_earth_branch().get_index() % 3];
         if !solar.is_before(dong_zhi.get_julian_day().get_solar_day())
             && solar.is_before(dong_zhi.next(12).get_julian_day().get_solar_day())
@@ -1280,9 +1476,17 @@ impl Eq for ThreePillars {}
 
 #[cfg(test)]
 mod tests {
-    use crate::sixtycycle::{EarthBranch, HeavenStem, SixtyCycle};
-    use crate::solar::SolarDay;
-    use crate::types::Culture;
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+
+    use crate::context::CalendarContext;
+    use crate::enums::SpringSwitch;
+    use crate::lunar::LunarYear;
+    use crate::sixtycycle::{
+        EarthBranch, HeavenStem, SixtyCycle, SixtyCycleDay, SixtyCycleHour, SixtyCycleYear,
+    };
+    use crate::solar::{SolarDay, SolarTime};
+    use crate::types::{Culture, Tyme};
 
     #[test]
     fn test1() {
@@ -1436,4 +1640,214 @@ mod tests {
                 .get_name()
         );
     }
+
+    #[test]
+    fn test13() {
+        assert_eq!("子", EarthBranch::from_hour(23).get_name());
+        assert_eq!("子", EarthBranch::from_hour(0).get_name());
+        assert_eq!("丑", EarthBranch::from_hour(1).get_name());
+        assert_eq!("午", EarthBranch::from_hour(12).get_name());
+        assert_eq!("亥", EarthBranch::from_hour(22).get_name());
+    }
+
+    #[test]
+    fn test14() {
+        assert_eq!((23, 1), EarthBranch::from_name("子").hour_range());
+        assert_eq!((1, 3), EarthBranch::from_name("丑").hour_range());
+        assert_eq!((21, 23), EarthBranch::from_name("亥").hour_range());
+        assert_eq!(
+            0,
+            SolarTime::from_ymd_hms(2024, 1, 1, 23, 30, 0)
+                .get_sixty_cycle_hour()
+                .get_index_in_day()
+        );
+    }
+
+    #[test]
+    fn test15() {
+        let d: SixtyCycleDay = SolarDay::from_ymd(2024, 2, 8).get_sixty_cycle_day();
+        assert!(d.is_month_establish());
+        assert!(!d.is_month_break());
+        let d: SixtyCycleDay = SolarDay::from_ymd(2024, 2, 14).get_sixty_cycle_day();
+        assert!(!d.is_month_establish());
+        assert!(d.is_month_break());
+        assert_eq!("破", d.get_duty().get_name());
+    }
+
+    #[test]
+    fn test16() {
+        assert_eq!(
+            "癸亥",
+            SixtyCycleYear::from_year(2024).get_shang_shuo().get_name()
+        );
+        assert_eq!(
+            "己巳",
+            SixtyCycleYear::from_year(2025).get_shang_shuo().get_name()
+        );
+        assert_eq!(
+            "丁巳",
+            SixtyCycleYear::from_year(2033).get_shang_shuo().get_name()
+        );
+        let days: Vec<SolarDay> = SixtyCycleYear::from_year(2024).get_shang_shuo_days();
+        assert_eq!(6, days.len());
+        assert_eq!("2024年2月29日", days[0].to_string());
+        assert_eq!("2024年12月25日", days[5].to_string());
+        assert!(days[0].get_sixty_cycle_day().is_shang_shuo());
+        assert!(
+            !SolarDay::from_ymd(2024, 3, 1)
+                .get_sixty_cycle_day()
+                .is_shang_shuo()
+        );
+    }
+
+    #[test]
+    fn test17() {
+        assert!(
+            SolarDay::from_ymd(2024, 2, 3)
+                .get_sixty_cycle_day()
+                .is_si_jue()
+        );
+        assert!(
+            SolarDay::from_ymd(2024, 3, 19)
+                .get_sixty_cycle_day()
+                .is_si_li()
+        );
+        assert!(
+            SolarDay::from_ymd(2024, 12, 20)
+                .get_sixty_cycle_day()
+                .is_si_li()
+        );
+        assert!(
+            !SolarDay::from_ymd(2024, 3, 20)
+                .get_sixty_cycle_day()
+                .is_si_li()
+        );
+        assert!(
+            !SolarDay::from_ymd(2024, 3, 19)
+                .get_sixty_cycle_day()
+                .is_si_jue()
+        );
+    }
+
+    #[test]
+    fn test18() {
+        // 1582年10月4日（儒略历）次日为10月15日（格里历），日柱连续
+        let day: SolarDay = SolarDay::from_ymd(1582, 10, 4);
+        assert_eq!(
+            "癸酉",
+            SixtyCycle::of_julian_day(day.get_julian_day()).get_name()
+        );
+        assert_eq!(
+            "甲戌",
+            SixtyCycle::of_julian_day(day.next(1).get_julian_day()).get_name()
+        );
+        assert_eq!(
+            Ok(5),
+            SixtyCycleDay::audit_continuity(day.next(-2), SolarDay::from_ymd(1582, 10, 16))
+        );
+
+        // 2000年1月7日为甲子日
+        assert_eq!(
+            "甲子",
+            SixtyCycle::of_julian_day(SolarDay::from_ymd(2000, 1, 7).get_julian_day()).get_name()
+        );
+
+        // J2000
+        let day: SolarDay = SolarDay::from_ymd(2000, 1, 1);
+        assert_eq!(
+            "戊午",
+            SixtyCycle::of_julian_day(day.get_julian_day()).get_name()
+        );
+        assert_eq!(
+            SixtyCycle::of_julian_day(day.get_julian_day()),
+            SixtyCycle::of_julian_day(
+                SolarTime::from_ymd_hms(2000, 1, 1, 23, 59, 59).get_julian_day()
+            )
+        );
+        assert_eq!(
+            Ok(366),
+            SixtyCycleDay::audit_continuity(day, SolarDay::from_ymd(2000, 12, 31))
+        );
+        assert!(SixtyCycleDay::audit_continuity(day.next(1), day).is_err());
+    }
+
+    #[test]
+    fn test19() {
+        for i in 0..60 {
+            let cycle: SixtyCycle = SixtyCycle::from_index(i);
+            assert_eq!(
+                cycle,
+                SixtyCycle::from_stem_branch(cycle.get_heaven_stem(), cycle.get_earth_branch())
+                    .unwrap()
+            );
+        }
+        assert!(
+            SixtyCycle::from_stem_branch(HeavenStem::from_name("甲"), EarthBranch::from_name("丑"))
+                .is_err()
+        );
+        // 天文纪年：0年即公元前1年（庚申），-1年即公元前2年（己未）
+        assert_eq!("甲子", LunarYear::from_year(4).get_sixty_cycle().get_name());
+        assert_eq!("庚申", LunarYear::from_year(0).get_sixty_cycle().get_name());
+        assert_eq!(
+            "己未",
+            LunarYear::from_year(-1).get_sixty_cycle().get_name()
+        );
+    }
+
+    #[test]
+    fn test20() {
+        for i in 0..60u8 {
+            let cycle: SixtyCycle = SixtyCycle::try_from(i).unwrap();
+            assert_eq!(i, u8::from(cycle));
+        }
+        assert_eq!(0, u8::from(SixtyCycle::from_name("甲子")));
+        assert_eq!(59, u8::from(SixtyCycle::from_name("癸亥")));
+        assert!(SixtyCycle::try_from(60).is_err());
+    }
+
+    #[test]
+    fn test21() {
+        // 2024年立春交节于2月4日16:27:07，交节前1分钟
+        let time: SolarTime = SolarTime::from_ymd_hms(2024, 2, 4, 16, 26, 7);
+        let context: CalendarContext = CalendarContext::default();
+        let h: SixtyCycleHour = SixtyCycleHour::from_solar_time_with_context(time, &context);
+        assert_eq!("癸卯", h.get_year().get_name());
+        assert_eq!("乙丑", h.get_month().get_name());
+        let h: SixtyCycleHour = SixtyCycleHour::from_solar_time_with_context(
+            time,
+            &context.with_spring_switch(SpringSwitch::DAY),
+        );
+        assert_eq!("甲辰", h.get_year().get_name());
+        assert_eq!("丙寅", h.get_month().get_name());
+        assert_eq!("庚申", h.get_sixty_cycle().get_name());
+        // 与按日计算的干支日一致
+        let d: SixtyCycleDay = SixtyCycleDay::from_solar_day(time.get_solar_day());
+        assert_eq!(d.get_year(), h.get_year());
+        assert_eq!(d.get_month(), h.get_month());
+        // 立春前一日不受影响
+        let h: SixtyCycleHour = SixtyCycleHour::from_solar_time_with_context(
+            SolarTime::from_ymd_hms(2024, 2, 3, 16, 26, 7),
+            &context.with_spring_switch(SpringSwitch::DAY),
+        );
+        assert_eq!("癸卯", h.get_year().get_name());
+    }
+    #[test]
+    fn test22() {
+        // 每个小时都落在所属时辰的钟点范围内
+        for hour in 0..24 {
+            let (start, end) = EarthBranch::from_hour(hour).hour_range();
+            assert!(
+                (start..end).contains(&hour) || (start > end && (hour >= start || hour < end)),
+                "{}",
+                hour
+            );
+        }
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "illegal hour: 24")]
+    fn test23() {
+        EarthBranch::from_hour(24);
+    }
 }
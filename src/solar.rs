@@ -2,30 +2,45 @@ use alloc::format;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use core::str::FromStr;
-use libm::{ceil, floor};
+use libm::{ceil, floor, round};
 
+use crate::context::CalendarContext;
 #[cfg(feature = "dog")]
 use crate::culture::dog::{Dog, DogDay};
+#[cfg(feature = "earthking")]
+use crate::culture::earthking::{EarthKing, EarthKingDay};
 #[cfg(feature = "nine")]
 use crate::culture::nine::{Nine, NineDay};
 #[cfg(feature = "phenology")]
 use crate::culture::phenology::{Phenology, PhenologyDay};
 #[cfg(feature = "plumrain")]
 use crate::culture::plumrain::{PlumRain, PlumRainDay};
-use crate::culture::{Constellation, Phase, PhaseDay, Week};
-use crate::enums::HideHeavenStemType;
+#[cfg(feature = "star-twenty-eight")]
+use crate::culture::star::twenty_eight::TwentyEightStarPosition;
+use crate::culture::{Constellation, MonthGeneral, Phase, PhaseDay, Season, Week};
+use crate::enums::{Hemisphere, HideHeavenStemType, Locale, ParseMode, Precision};
+#[cfg(feature = "farming")]
+use crate::farming::{FarmingAdvisory, FarmingRegistry};
 #[cfg(feature = "festival")]
 use crate::festival::SolarFestival;
 #[cfg(feature = "holiday")]
 use crate::holiday::LegalHoliday;
 use crate::jd::{J2000, JulianDay};
-use crate::lunar::{LunarDay, LunarHour, LunarMonth};
+use crate::lunar::{LunarDay, LunarHour, LunarMonth, LunarYearEphemeris, Resolution};
 #[cfg(feature = "rabbyung")]
 use crate::rabbyung::{RabByungDay, RabByungYear};
-use crate::sixtycycle::{HideHeavenStem, HideHeavenStemDay, SixtyCycleDay, SixtyCycleHour};
+use crate::sixtycycle::{
+    EarthBranch, HideHeavenStem, HideHeavenStemDay, SixtyCycleDay, SixtyCycleHour,
+};
+#[cfg(feature = "star-twenty-eight")]
+use crate::sxtwl::ONE_THIRD;
 use crate::sxtwl::Sxtwl;
+#[cfg(feature = "star-twenty-eight")]
+use crate::sxtwl::units::Days;
 use crate::types::{AbstractCulture, AbstractCultureDay, AbstractTyme, Culture, LoopTyme, Tyme};
+use core::cmp::Ordering;
 use core::fmt::{Display, Formatter};
+use core::ops::Sub;
 
 /// 公历年
 #[derive(Debug, Copy, Clone)]
@@ -109,6 +124,25 @@ impl SolarYear {
         l
     }
 
+    /// 四离四绝日，依次为立春、春分、立夏、夏至、立秋、秋分、立冬、冬至的前一日（按交节精确时刻）
+    pub fn get_si_li_si_jue(&self) -> Vec<SolarDay> {
+        let mut l: Vec<SolarDay> = Vec::new();
+        for i in 1..9 {
+            l.push(SolarTerm::from_index(self.year, i * 3).get_eve_solar_day());
+        }
+        l
+    }
+
+    /// 土王用事列表
+    #[cfg(feature = "earthking")]
+    pub fn get_earth_kings(&self) -> Vec<EarthKing> {
+        let mut l: Vec<EarthKing> = Vec::new();
+        for i in 0..4 {
+            l.push(EarthKing::from_index(self.year, i));
+        }
+        l
+    }
+
     /// 藏历年
     #[cfg(feature = "rabbyung")]
     pub fn get_rab_byung_year(&self) -> Result<RabByungYear, String> {
@@ -164,7 +198,7 @@ impl SolarHalfYear {
             Err(format!("illegal solar half year index: {}", index))
         } else {
             Ok(Self {
-                year: SolarYear::from_year(year),
+                year: SolarYear::new(year)?,
                 index,
             })
         }
@@ -258,7 +292,7 @@ impl SolarSeason {
             Err(format!("illegal solar season index: {}", index))
         } else {
             Ok(Self {
-                year: SolarYear::from_year(year),
+                year: SolarYear::new(year)?,
                 index,
             })
         }
@@ -349,7 +383,7 @@ impl SolarMonth {
         } else {
             Ok(Self {
                 parent: AbstractTyme::new(),
-                year: SolarYear::from_year(year),
+                year: SolarYear::new(year)?,
                 month,
             })
         }
@@ -432,6 +466,17 @@ impl SolarMonth {
         }
         l
     }
+
+    /// 公历日列表，写入定长容器
+    #[cfg(feature = "heapless")]
+    pub fn get_days_into(&self, out: &mut heapless::Vec<SolarDay, 31>) {
+        out.clear();
+        let y: isize = self.get_year();
+        for i in 1..self.get_day_count() + 1 {
+            // 每月至多31天，不会溢出
+            let _ = out.push(SolarDay::from_ymd(y, self.month, i));
+        }
+    }
 }
 
 impl Display for SolarMonth {
@@ -513,7 +558,7 @@ impl SolarWeek {
         } else if start > 6 {
             Err(format!("illegal solar week start: {}", start))
         } else {
-            let m: SolarMonth = SolarMonth::from_ym(year, month);
+            let m: SolarMonth = SolarMonth::new(year, month)?;
             if index >= m.get_week_count(start) {
                 Err(format!(
                     "illegal solar week index: {} in month: {}",
@@ -644,7 +689,7 @@ impl Culture for SolarDay {
 
 impl SolarDay {
     pub fn new(year: isize, month: usize, day: usize) -> Result<Self, String> {
-        let m: SolarMonth = SolarMonth::from_ym(year, month);
+        let m: SolarMonth = SolarMonth::new(year, month)?;
         if day < 1 {
             Err(format!("illegal solar day: {}-{}-{}", year, month, day))
         } else if 1582 == year && 10 == month {
@@ -664,6 +709,33 @@ impl SolarDay {
         Self::new(year, month, day).unwrap()
     }
 
+    /// 儒略日所在的公历日
+    pub fn from_julian_day(julian_day: JulianDay) -> Self {
+        julian_day.get_solar_day()
+    }
+
+    /// 解析公历日，支持：2024-06-15、2024/6/15、2024年6月15日
+    pub fn parse(s: &str, mode: ParseMode) -> Result<Self, String> {
+        let text: &str = match mode {
+            ParseMode::STRICT => s,
+            ParseMode::LENIENT => s.trim(),
+        };
+        let parts: Vec<&str> = if let Some(t) = text.strip_suffix('日') {
+            t.split(['年', '月']).collect()
+        } else if text.contains('/') {
+            text.split('/').collect()
+        } else {
+            text.split('-').collect()
+        };
+        if parts.len() != 3 {
+            return Err(format!("illegal solar day: {}", s));
+        }
+        let year: usize = parse_number(parts[0], mode)?;
+        let month: usize = parse_number(parts[1], mode)?;
+        let day: usize = parse_number(parts[2], mode)?;
+        Self::new(year as isize, month, day)
+    }
+
     /// 公历月
     pub fn get_solar_month(&self) -> SolarMonth {
         self.month
@@ -714,6 +786,30 @@ impl SolarDay {
         self.get_term_day().get_solar_term()
     }
 
+    /// 当天交节的节气及交节时刻，当天不交节返回None
+    pub fn get_jie_qi(&self) -> Option<(SolarTerm, SolarTime)> {
+        let term_day: SolarTermDay = self.get_term_day();
+        if term_day.get_day_index() != 0 {
+            return None;
+        }
+        let term: SolarTerm = term_day.get_solar_term();
+        let time: SolarTime = term.get_julian_day().get_solar_time();
+        Some((term, time))
+    }
+
+    /// 距下一节气的天数，及该节气（如：距离立秋还有N天）
+    pub fn days_until_next_term(&self) -> (SolarTerm, usize) {
+        let term: SolarTerm = self.get_term().next(1);
+        let n: isize = term.get_solar_day().subtract(*self);
+        (term, n as usize)
+    }
+
+    /// 距上一节气（交节当天为0）的天数，及该节气
+    pub fn days_since_previous_term(&self) -> (SolarTerm, usize) {
+        let term_day: SolarTermDay = self.get_term_day();
+        (term_day.get_solar_term(), term_day.get_day_index())
+    }
+
     /// 节气第几天
     pub fn get_term_day(&self) -> SolarTermDay {
         let mut y: isize = self.get_year();
@@ -771,9 +867,10 @@ impl SolarDay {
         self.subtract(Self::from_ymd(self.get_year(), 1, 1)) as usize
     }
 
-    /// 公历日相减
+    /// 公历日相减，返回天数；支持范围内（1—9999年）相差不超过3652060天，32位平台的isize也不会溢出，
+    /// 故仍用isize，可直接传给next()（SolarTime::subtract的秒数会超出i32，改用i64）
     pub fn subtract(&self, target: SolarDay) -> isize {
-        self.get_julian_day().subtract(target.get_julian_day()) as isize
+        round(self.get_julian_day().subtract(target.get_julian_day())) as isize
     }
 
     /// 农历日
@@ -787,6 +884,19 @@ impl SolarDay {
         LunarDay::from_ymd(m.get_year(), m.get_month_with_leap(), (days + 1) as usize)
     }
 
+    /// 农历日，并区分是否为超出推荐范围的估算结果
+    pub fn resolve_lunar_day(&self) -> Resolution<LunarDay> {
+        let d: LunarDay = self.get_lunar_day();
+        Resolution::for_lunar_year(d.get_year(), d)
+    }
+
+    /// 农历日，优先使用预先计算的农历年历表
+    pub fn get_lunar_day_with_ephemeris(&self, ephemeris: Option<&LunarYearEphemeris>) -> LunarDay {
+        ephemeris
+            .and_then(|e| e.find_lunar_day(*self))
+            .unwrap_or_else(|| self.get_lunar_day())
+    }
+
     /// 星座
     pub fn get_constellation(&self) -> Constellation {
         let mut index: isize = 8;
@@ -817,6 +927,18 @@ impl SolarDay {
         Constellation::from_index(index)
     }
 
+    /// 土王用事天
+    #[cfg(feature = "earthking")]
+    pub fn get_earth_king_day(&self) -> Option<EarthKingDay> {
+        for earth_king in SolarYear::from_year(self.get_year()).get_earth_kings() {
+            let start: SolarDay = earth_king.get_solar_day();
+            if !self.is_before(start) && self.is_before(earth_king.get_end_solar_day()) {
+                return Some(EarthKingDay::new(earth_king, self.subtract(start) as usize));
+            }
+        }
+        None
+    }
+
     /// 三伏天
     #[cfg(feature = "dog")]
     pub fn get_dog_day(&self) -> Option<DogDay> {
@@ -1027,6 +1149,31 @@ impl PartialEq for SolarDay {
 
 impl Eq for SolarDay {}
 
+impl PartialOrd for SolarDay {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SolarDay {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.get_year(), self.get_month(), self.get_day()).cmp(&(
+            other.get_year(),
+            other.get_month(),
+            other.get_day(),
+        ))
+    }
+}
+
+/// 相差的天数
+impl Sub for SolarDay {
+    type Output = isize;
+
+    fn sub(self, rhs: Self) -> isize {
+        self.subtract(rhs)
+    }
+}
+
 /// 公历时刻
 #[derive(Debug, Copy, Clone)]
 pub struct SolarTime {
@@ -1101,7 +1248,7 @@ impl SolarTime {
             Err(format!("illegal second: {}", second))
         } else {
             Ok(Self {
-                day: SolarDay::from_ymd(year, month, day),
+                day: SolarDay::new(year, month, day)?,
                 hour,
                 minute,
                 second,
@@ -1120,6 +1267,76 @@ impl SolarTime {
         Self::new(year, month, day, hour, minute, second).unwrap()
     }
 
+    /// 儒略日对应的公历时刻
+    pub fn from_julian_day(julian_day: JulianDay) -> Self {
+        julian_day.get_solar_time()
+    }
+
+    /// 解析公历时刻，日期格式同SolarDay::parse，与时间（时:分或时:分:秒）以空格或T分隔；宽松模式下24:00:00计为次日0时
+    pub fn parse(s: &str, mode: ParseMode) -> Result<Self, String> {
+        let text: &str = match mode {
+            ParseMode::STRICT => s,
+            ParseMode::LENIENT => s.trim(),
+        };
+        let (date, time) = text
+            .split_once([' ', 'T'])
+            .ok_or_else(|| format!("illegal solar time: {}", s))?;
+        let day: SolarDay = SolarDay::parse(date, mode)?;
+        let fields: Vec<&str> = time.split(':').collect();
+        if fields.len() < 2 || fields.len() > 3 {
+            return Err(format!("illegal solar time: {}", s));
+        }
+        let hour: usize = parse_number(fields[0], mode)?;
+        let minute: usize = parse_number(fields[1], mode)?;
+        let second: usize = match fields.get(2) {
+            Some(f) => parse_number(f, mode)?,
+            None => 0,
+        };
+        if mode == ParseMode::LENIENT && hour == 24 && minute == 0 && second == 0 {
+            if day.get_year() == 9999 && day.get_month() == 12 && day.get_day() == 31 {
+                return Err(format!("solar time out of range: {}", s));
+            }
+            let next: SolarDay = day.next(1);
+            return Self::new(next.get_year(), next.get_month(), next.get_day(), 0, 0, 0);
+        }
+        Self::new(
+            day.get_year(),
+            day.get_month(),
+            day.get_day(),
+            hour,
+            minute,
+            second,
+        )
+    }
+
+    /// 从公历日及当日时长（小时，可带小数）创建，四舍五入到秒，满24时计入次日
+    pub fn new_decimal_hours(
+        year: isize,
+        month: usize,
+        day: usize,
+        hours: f64,
+    ) -> Result<Self, String> {
+        if !(0.0..24.0).contains(&hours) {
+            return Err(format!("illegal hours: {}", hours));
+        }
+        let seconds: usize = round(hours * 3600.0) as usize;
+        let d: SolarDay = SolarDay::new(year, month, day)?;
+        if seconds >= 86400 && year == 9999 && month == 12 && day == 31 {
+            return Err(format!("illegal hours: {} in {}", hours, d));
+        }
+        Ok(Self {
+            day: d,
+            hour: 0,
+            minute: 0,
+            second: 0,
+        }
+        .next(seconds as isize))
+    }
+
+    pub fn from_ymd_decimal_hours(year: isize, month: usize, day: usize, hours: f64) -> Self {
+        Self::new_decimal_hours(year, month, day, hours).unwrap()
+    }
+
     /// 公历日
     pub fn get_solar_day(&self) -> SolarDay {
         self.day
@@ -1144,6 +1361,11 @@ impl SolarTime {
         self.hour
     }
 
+    /// 当日时长（小时，带小数）
+    pub fn get_decimal_hours(&self) -> f64 {
+        (self.hour * 3600 + self.minute * 60 + self.second) as f64 / 3600.0
+    }
+
     pub fn get_minute(&self) -> usize {
         self.minute
     }
@@ -1211,17 +1433,13 @@ impl SolarTime {
         )
     }
 
-    pub fn subtract(&self, target: SolarTime) -> isize {
-        let mut days: isize = self.day.subtract(target.get_solar_day());
-        let cs: usize = self.hour * 3600 + self.minute * 60 + self.second;
-        let ts: usize = target.get_hour() * 3600 + target.get_minute() * 60 + target.get_second();
-        let mut seconds: isize = cs as isize - ts as isize;
-        if seconds < 0 {
-            seconds += 86400;
-            days -= 1;
-        }
-        seconds += days * 86400;
-        seconds
+    /// 公历时刻相减，返回秒数（按i64计算，32位平台跨度超过68年时isize会溢出）
+    pub fn subtract(&self, target: SolarTime) -> i64 {
+        let days: i64 = self.day.subtract(target.get_solar_day()) as i64;
+        let cs: i64 = (self.hour * 3600 + self.minute * 60 + self.second) as i64;
+        let ts: i64 =
+            (target.get_hour() * 3600 + target.get_minute() * 60 + target.get_second()) as i64;
+        days * 86400 + cs - ts
     }
 
     pub fn get_lunar_hour(&self) -> LunarHour {
@@ -1253,6 +1471,33 @@ impl SolarTime {
         }
         p
     }
+
+    /// 月将
+    pub fn get_month_general(&self) -> MonthGeneral {
+        MonthGeneral::from_solar_time(*self)
+    }
+
+    /// 太阳所在的宿及入宿度
+    #[cfg(feature = "star-twenty-eight")]
+    pub fn get_sun_twenty_eight_star_position(&self) -> TwentyEightStarPosition {
+        let jd: f64 = self.get_julian_day().get_day() - J2000 - ONE_THIRD;
+        let jd: Days = Days(jd + Sxtwl::dtt(jd));
+        TwentyEightStarPosition::from_ecliptic_longitude(
+            Sxtwl::sun_apparent_longitude(jd).0.to_degrees(),
+            jd.0 / 36525.0,
+        )
+    }
+
+    /// 月亮所在的宿及入宿度
+    #[cfg(feature = "star-twenty-eight")]
+    pub fn get_moon_twenty_eight_star_position(&self) -> TwentyEightStarPosition {
+        let jd: f64 = self.get_julian_day().get_day() - J2000 - ONE_THIRD;
+        let jd: Days = Days(jd + Sxtwl::dtt(jd));
+        TwentyEightStarPosition::from_ecliptic_longitude(
+            Sxtwl::moon_apparent_longitude(jd).0.to_degrees(),
+            jd.0 / 36525.0,
+        )
+    }
 }
 
 impl Display for SolarTime {
@@ -1272,12 +1517,129 @@ impl PartialEq for SolarTime {
 
 impl Eq for SolarTime {}
 
+impl PartialOrd for SolarTime {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SolarTime {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.get_solar_day().cmp(&other.get_solar_day()).then(
+            (self.get_hour(), self.get_minute(), self.get_second()).cmp(&(
+                other.get_hour(),
+                other.get_minute(),
+                other.get_second(),
+            )),
+        )
+    }
+}
+
+/// 相差的秒数
+impl Sub for SolarTime {
+    type Output = i64;
+
+    fn sub(self, rhs: Self) -> i64 {
+        self.subtract(rhs)
+    }
+}
+
+/// 解析日期时间中的一项数字，严格模式下不允许空白
+fn parse_number(s: &str, mode: ParseMode) -> Result<usize, String> {
+    let t: &str = match mode {
+        ParseMode::STRICT => s,
+        ParseMode::LENIENT => s.trim(),
+    };
+    if t.is_empty() || !t.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(format!("illegal number: {}", s));
+    }
+    usize::from_str(t).map_err(|_| format!("illegal number: {}", s))
+}
+
+impl FromStr for SolarDay {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, ParseMode::STRICT)
+    }
+}
+
+impl FromStr for SolarTime {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, ParseMode::STRICT)
+    }
+}
+
 /// 节气名称
 pub static SOLAR_TERM_NAMES: [&str; 24] = [
     "冬至", "小寒", "大寒", "立春", "雨水", "惊蛰", "春分", "清明", "谷雨", "立夏", "小满", "芒种",
     "夏至", "小暑", "大暑", "立秋", "处暑", "白露", "秋分", "寒露", "霜降", "立冬", "小雪", "大雪",
 ];
 
+/// 节气的太阳视黄经（度），与SOLAR_TERM_NAMES索引一致：自冬至270°起，每节气加15°，立春（索引3）为315°，春分（索引6）为0°
+pub const TERM_LONGITUDES: [f64; 24] = [
+    270.0, 285.0, 300.0, 315.0, 330.0, 345.0, 0.0, 15.0, 30.0, 45.0, 60.0, 75.0, 90.0, 105.0,
+    120.0, 135.0, 150.0, 165.0, 180.0, 195.0, 210.0, 225.0, 240.0, 255.0,
+];
+
+/// 节气的太阳视黄经（百分之一度），与TERM_LONGITUDES一致，便于整数比较
+pub const TERM_LONGITUDES_CENTI: [u16; 24] = [
+    27000, 28500, 30000, 31500, 33000, 34500, 0, 1500, 3000, 4500, 6000, 7500, 9000, 10500, 12000,
+    13500, 15000, 16500, 18000, 19500, 21000, 22500, 24000, 25500,
+];
+
+/// 节气英文名称
+pub static SOLAR_TERM_NAMES_EN: [&str; 24] = [
+    "Winter Solstice",
+    "Minor Cold",
+    "Major Cold",
+    "Start of Spring",
+    "Rain Water",
+    "Awakening of Insects",
+    "Spring Equinox",
+    "Pure Brightness",
+    "Grain Rain",
+    "Start of Summer",
+    "Grain Buds",
+    "Grain in Ear",
+    "Summer Solstice",
+    "Minor Heat",
+    "Major Heat",
+    "Start of Autumn",
+    "End of Heat",
+    "White Dew",
+    "Autumn Equinox",
+    "Cold Dew",
+    "Frost's Descent",
+    "Start of Winter",
+    "Minor Snow",
+    "Major Snow",
+];
+
+/// 立春在节气名称（SOLAR_TERM_NAMES，以冬至为首）中的索引
+pub static SPRING_BEGIN_TERM_INDEX: usize = 3;
+
+/// 节气歌，每句对应一季6个节气（自立春起）
+pub static SOLAR_TERM_SONG: [&str; 4] = [
+    "春雨惊春清谷天",
+    "夏满芒夏暑相连",
+    "秋处露秋寒霜降",
+    "冬雪雪冬小大寒",
+];
+
+/// 节气在节气歌中的简称，以立春为首（小暑、大暑合称“暑相连”）
+pub static SOLAR_TERM_SONG_CHARS: [&str; 24] = [
+    "春", "雨", "惊", "春", "清", "谷", "夏", "满", "芒", "夏", "暑", "暑", "秋", "处", "露", "秋",
+    "寒", "霜", "冬", "雪", "雪", "冬", "小", "大",
+];
+
+/// 节气通常所在的公历月，以冬至为首
+pub static SOLAR_TERM_SOLAR_MONTHS: [usize; 24] = [
+    12, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12,
+];
+
 /// 节气
 #[derive(Debug, Clone)]
 pub struct SolarTerm {
@@ -1317,12 +1679,7 @@ impl SolarTerm {
                 .collect(),
             index,
         );
-        let jd: f64 = floor((y as f64 - 2000.0) * 365.2422 + 180.0);
-        // 355是2000.12冬至，得到较靠近jd的冬至估计值
-        let mut w: f64 = floor((jd - 355.0 + 183.0) / 365.2422) * 365.2422 + 355.0;
-        if Sxtwl::calc_qi(w) > jd {
-            w -= 365.2422;
-        }
+        let w: f64 = Self::get_winter_solstice_estimate(y);
         let index: usize = parent.get_index();
         Self {
             parent,
@@ -1331,6 +1688,38 @@ impl SolarTerm {
         }
     }
 
+    /// 上年冬至的估计值（J2000起算的儒略日数）
+    fn get_winter_solstice_estimate(year: isize) -> f64 {
+        let jd: f64 = floor((year as f64 - 2000.0) * 365.2422 + 180.0);
+        // 355是2000.12冬至，得到较靠近jd的冬至估计值
+        let mut w: f64 = floor((jd - 355.0 + 183.0) / 365.2422) * 365.2422 + 355.0;
+        if Sxtwl::calc_qi(w) > jd {
+            w -= 365.2422;
+        }
+        w
+    }
+
+    /// 自上年冬至起24个节气交节时刻的四柱（年、月、日、时柱），交节时刻算作新节气
+    pub fn get_jiao_jie_pillars(year: isize) -> Vec<(SolarTerm, SolarTime, SixtyCycleHour)> {
+        let mut l: Vec<(SolarTerm, SolarTime, SixtyCycleHour)> = Vec::new();
+        for i in 0..24 {
+            let term: SolarTerm = SolarTerm::from_index(year, i);
+            let time: SolarTime = term.get_julian_day().get_solar_time();
+            l.push((term, time, time.get_sixty_cycle_hour()));
+        }
+        l
+    }
+
+    /// 自上年冬至起24个节气的儒略日（精确到秒），写入给定数组，不分配内存
+    pub fn get_julian_days_into(year: isize, out: &mut [JulianDay; 24]) {
+        let w: f64 = Self::get_winter_solstice_estimate(year);
+        for (i, jd) in out.iter_mut().enumerate() {
+            *jd = JulianDay::from_julian_day(
+                Sxtwl::qi_accurate2(Sxtwl::calc_qi(w + 15.2184 * i as f64)) + J2000,
+            );
+        }
+    }
+
     pub fn new(year: isize, name: &str) -> Result<Self, String> {
         let parent: LoopTyme = LoopTyme::from_name(
             SOLAR_TERM_NAMES
@@ -1340,12 +1729,7 @@ impl SolarTerm {
                 .collect(),
             name,
         );
-        let jd: f64 = floor(((year - 2000) as f64) * 365.2422 + 180.0);
-        // 355是2000.12冬至，得到较靠近jd的冬至估计值
-        let mut w: f64 = floor((jd - 355.0 + 183.0) / 365.2422) * 365.2422 + 355.0;
-        if Sxtwl::calc_qi(w) > jd {
-            w -= 365.2422;
-        }
+        let w: f64 = Self::get_winter_solstice_estimate(year);
         let index: usize = parent.get_index();
         Ok(Self {
             parent,
@@ -1358,6 +1742,51 @@ impl SolarTerm {
         Self::new(year, name).unwrap()
     }
 
+    /// 儒略日所在的节气（交节时刻算作新节气）
+    pub fn from_julian_day(julian_day: JulianDay) -> Self {
+        julian_day.get_solar_time().get_term()
+    }
+
+    /// 按半球的名称取节气，南半球的节气即太阳黄经相差180°的节气（如南半球的立春为北半球的立秋），一年的南半球节气自6月的冬至起依次排列
+    pub fn new_with_hemisphere(
+        year: isize,
+        name: &str,
+        hemisphere: Hemisphere,
+    ) -> Result<Self, String> {
+        let term: Self = Self::new(year, name)?;
+        Ok(match hemisphere {
+            Hemisphere::NORTH => term,
+            Hemisphere::SOUTH => {
+                Self::from_index(year, Self::to_hemisphere_index(term.get_index()))
+            }
+        })
+    }
+
+    /// 南北半球名称互换的索引（平移12个节气，超出当年的由from_index顺延至下一年）
+    fn to_hemisphere_index(index: usize) -> isize {
+        index as isize + 12
+    }
+
+    /// 按半球取名称，南半球季节与北半球相反，名称平移12个节气，交节时刻不变
+    pub fn get_name_with_hemisphere(&self, hemisphere: Hemisphere) -> String {
+        match hemisphere {
+            Hemisphere::NORTH => self.get_name(),
+            Hemisphere::SOUTH => SOLAR_TERM_NAMES
+                [Self::to_hemisphere_index(self.get_index()) as usize % 24]
+                .to_string(),
+        }
+    }
+
+    /// 英文名称
+    pub fn name_en(&self) -> String {
+        SOLAR_TERM_NAMES_EN[self.get_index()].to_string()
+    }
+
+    /// 太阳视黄经（度）
+    pub fn get_longitude(&self) -> f64 {
+        TERM_LONGITUDES[self.get_index()]
+    }
+
     pub fn get_index(&self) -> usize {
         self.parent.get_index()
     }
@@ -1366,6 +1795,63 @@ impl SolarTerm {
         self.parent.get_size()
     }
 
+    /// 以立春为首的索引（立春为0，大寒为23）；get_index()以冬至为首
+    pub fn get_index_from_spring_begin(&self) -> usize {
+        (self.get_index() + 24 - SPRING_BEGIN_TERM_INDEX) % 24
+    }
+
+    /// 按以立春为首的索引（节气歌顺序）取节气，立春为0，大寒为23
+    pub fn from_spring_begin_index(year: isize, index: isize) -> Self {
+        Self::from_index(year, index + SPRING_BEGIN_TERM_INDEX as isize)
+    }
+
+    /// 所在的节气歌句子
+    pub fn get_song_line(&self) -> &'static str {
+        SOLAR_TERM_SONG[self.get_index_from_spring_begin() / 6]
+    }
+
+    /// 在节气歌中的简称
+    pub fn get_song_char(&self) -> &'static str {
+        SOLAR_TERM_SONG_CHARS[self.get_index_from_spring_begin()]
+    }
+
+    /// 通常所在的公历月
+    pub fn get_solar_month_number(&self) -> usize {
+        SOLAR_TERM_SOLAR_MONTHS[self.get_index()]
+    }
+
+    /// 所属节令月的月序（立春至惊蛰前为正月，即1），由月支推得
+    pub fn get_term_month_number(&self) -> usize {
+        (self.get_month_branch().get_index() + 10) % 12 + 1
+    }
+
+    /// 所属季节（立春、立夏、立秋、立冬起季）
+    pub fn get_season(&self) -> Season {
+        Season::from_index((self.get_index_from_spring_begin() / 6) as isize)
+    }
+
+    /// 所属节令月的月支（立春至惊蛰前为寅月），即月柱地支；气令取其前一个节令
+    pub fn get_month_branch(&self) -> EarthBranch {
+        EarthBranch::from_index(self.get_index().div_ceil(2) as isize)
+    }
+
+    /// 三候（初候、二候、三候）
+    #[cfg(feature = "phenology")]
+    pub fn get_phenologies(&self) -> Vec<Phenology> {
+        (0..3)
+            .map(|i| Phenology::from_index(self.year, self.get_index() as isize * 3 + i))
+            .collect()
+    }
+
+    /// 三候名称（初候、二候、三候）
+    #[cfg(feature = "phenology")]
+    pub fn get_phenology_names(&self) -> Vec<String> {
+        self.get_phenologies()
+            .iter()
+            .map(|p| p.get_name())
+            .collect()
+    }
+
     /// 是否节令
     pub fn is_jie(&self) -> bool {
         self.get_index() % 2 == 1
@@ -1381,11 +1867,44 @@ impl SolarTerm {
         JulianDay::from_julian_day(Sxtwl::qi_accurate2(self.cursory_julian_day) + J2000)
     }
 
+    /// 按指定精度计算的儒略日
+    pub fn get_julian_day_with_precision(&self, precision: Precision) -> JulianDay {
+        JulianDay::from_julian_day(
+            Sxtwl::qi_with_precision(Sxtwl::qi_lon(self.cursory_julian_day), precision) + J2000,
+        )
+    }
+
+    /// 按上下文的精度计算交节时刻，并换算为上下文的当地时间
+    pub fn get_solar_time_with_context(&self, context: &CalendarContext) -> SolarTime {
+        context.to_local(
+            self.get_julian_day_with_precision(context.get_precision())
+                .get_solar_time(),
+        )
+    }
+
+    /// 按上下文的语言取名称
+    pub fn get_name_with_context(&self, context: &CalendarContext) -> String {
+        match context.get_locale() {
+            Locale::ZH => self.get_name(),
+            Locale::EN => self.name_en(),
+        }
+    }
+
     /// 公历日（用于日历）
     pub fn get_solar_day(&self) -> SolarDay {
         JulianDay::from_julian_day(self.cursory_julian_day + J2000).get_solar_day()
     }
 
+    /// 交节日及交节时刻是否临近子夜（前后一天存疑）
+    pub fn get_day_resolution(&self) -> TermDayResolution {
+        TermDayResolution::from_solar_term(self.clone())
+    }
+
+    /// 交节前一日（按交节精确时刻）
+    pub fn get_eve_solar_day(&self) -> SolarDay {
+        self.get_julian_day().get_solar_day().next(-1)
+    }
+
     /// 年
     pub fn get_year(&self) -> isize {
         self.year
@@ -1395,6 +1914,12 @@ impl SolarTerm {
     pub fn get_cursory_julian_day(&self) -> f64 {
         self.cursory_julian_day
     }
+
+    /// 农事
+    #[cfg(feature = "farming")]
+    pub fn get_farming_advisory(&self) -> FarmingAdvisory {
+        FarmingRegistry::get(self)
+    }
 }
 
 impl Display for SolarTerm {
@@ -1411,6 +1936,25 @@ impl PartialEq for SolarTerm {
 
 impl Eq for SolarTerm {}
 
+/// 稳定编码：索引0（冬至）至23（大雪），各版本保持不变，可用于持久化；所属年份需另行保存
+impl From<SolarTerm> for u8 {
+    fn from(term: SolarTerm) -> Self {
+        term.get_index() as u8
+    }
+}
+
+/// 由（年，编码）还原节气
+impl TryFrom<(isize, u8)> for SolarTerm {
+    type Error = String;
+
+    fn try_from((year, code): (isize, u8)) -> Result<Self, Self::Error> {
+        if code >= 24 {
+            return Err(format!("illegal solar term code: {}", code));
+        }
+        Ok(Self::from_index(year, code as isize))
+    }
+}
+
 impl Into<LoopTyme> for SolarTerm {
     fn into(self) -> LoopTyme {
         self.parent
@@ -1478,16 +2022,80 @@ impl Into<AbstractCultureDay> for SolarTermDay {
     }
 }
 
+/// 交节时刻距子夜在此秒数以内时，交节日可能因计算误差、时区或闰秒相差一天
+pub static TERM_AMBIGUOUS_SECONDS: usize = 1800;
+
+/// 节气交节日判定
+#[derive(Debug, Clone)]
+pub struct TermDayResolution {
+    /// 节气
+    solar_term: SolarTerm,
+    /// 交节时刻
+    time: SolarTime,
+}
+
+impl TermDayResolution {
+    pub fn from_solar_term(solar_term: SolarTerm) -> Self {
+        let time: SolarTime = solar_term.get_julian_day().get_solar_time();
+        Self { solar_term, time }
+    }
+
+    /// 节气
+    pub fn get_solar_term(&self) -> SolarTerm {
+        self.solar_term.clone()
+    }
+
+    /// 交节时刻
+    pub fn get_solar_time(&self) -> SolarTime {
+        self.time
+    }
+
+    /// 交节日（按交节时刻）
+    pub fn get_solar_day(&self) -> SolarDay {
+        self.time.get_solar_day()
+    }
+
+    /// 交节时刻距最近子夜的秒数
+    pub fn get_seconds_to_midnight(&self) -> usize {
+        let seconds: usize =
+            self.time.get_hour() * 3600 + self.time.get_minute() * 60 + self.time.get_second();
+        seconds.min(86400 - seconds)
+    }
+
+    /// 交节时刻是否在子夜前后30分钟内（交节日或前后一天）
+    pub fn is_ambiguous(&self) -> bool {
+        self.get_seconds_to_midnight() < TERM_AMBIGUOUS_SECONDS
+    }
+}
+
+impl Display for TermDayResolution {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} {}", self.solar_term, self.time)?;
+        if self.is_ambiguous() {
+            write!(f, "（或前后一天）")?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use alloc::string::ToString;
+    use alloc::format;
+    use alloc::string::{String, ToString};
+    use alloc::vec;
+    use alloc::vec::Vec;
 
+    use crate::enums::{Hemisphere, ParseMode, Precision};
+    use crate::jd::{J2000, JulianDay};
     use crate::lunar::LunarWeek;
     use crate::sixtycycle::HideHeavenStemDay;
     use crate::solar::{
-        SolarDay, SolarHalfYear, SolarMonth, SolarSeason, SolarTerm, SolarTime, SolarWeek,
-        SolarYear,
+        SOLAR_TERM_NAMES, SolarDay, SolarHalfYear, SolarMonth, SolarSeason, SolarTerm, SolarTime,
+        SolarWeek, SolarYear, TERM_LONGITUDES, TERM_LONGITUDES_CENTI, TermDayResolution,
     };
+    use crate::sxtwl::ephemeris::{assert_time, time_tolerance};
+    use crate::sxtwl::units::Days;
+    use crate::sxtwl::{ONE_THIRD, Sxtwl};
     use crate::types::{Culture, Tyme};
 
     #[test]
@@ -2122,12 +2730,456 @@ mod tests {
                 .get_solar_day()
                 .to_string()
         );
-        assert_eq!(
-            "1034年10月3日 06:02:28",
+        assert_time(
+            SolarTime::from_ymd_hms(1034, 10, 3, 6, 2, 28),
             SolarTerm::from_name(1034, "寒露")
                 .get_julian_day()
+                .get_solar_time(),
+        );
+    }
+
+    #[test]
+    fn test75() {
+        let days: Vec<String> = SolarYear::from_year(2024)
+            .get_si_li_si_jue()
+            .iter()
+            .map(|d| d.to_string())
+            .collect();
+        assert_eq!(
+            vec![
+                "2024年2月3日",
+                "2024年3月19日",
+                "2024年5月4日",
+                "2024年6月20日",
+                "2024年8月6日",
+                "2024年9月21日",
+                "2024年11月6日",
+                "2024年12月20日"
+            ],
+            days
+        );
+        assert_eq!(
+            "1034年10月2日",
+            SolarTerm::from_name(1034, "寒露")
+                .get_eve_solar_day()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test76() {
+        let (term, n) = SolarDay::from_ymd(2024, 8, 1).days_until_next_term();
+        assert_eq!("立秋", term.get_name());
+        assert_eq!(6, n);
+        let (term, n) = SolarDay::from_ymd(2024, 8, 1).days_since_previous_term();
+        assert_eq!("大暑", term.get_name());
+        assert_eq!(10, n);
+        let (term, n) = SolarDay::from_ymd(2024, 8, 7).days_since_previous_term();
+        assert_eq!("立秋", term.get_name());
+        assert_eq!(0, n);
+        let (term, n) = SolarDay::from_ymd(2024, 12, 25).days_until_next_term();
+        assert_eq!("小寒", term.get_name());
+        assert_eq!(2025, term.get_year());
+        assert_eq!(11, n);
+    }
+
+    #[test]
+    fn test77() {
+        assert!(SolarDay::new(2024, 13, 1).is_err());
+        assert!(SolarDay::new(0, 1, 1).is_err());
+        assert!(SolarDay::new(10000, 1, 1).is_err());
+        assert!(SolarMonth::new(10000, 1).is_err());
+        assert!(SolarTime::new(2024, 0, 1, 0, 0, 0).is_err());
+    }
+
+    #[test]
+    fn test78() {
+        // 跨度超过i32范围的秒数
+        let first: SolarTime = SolarTime::from_ymd_hms(1, 1, 1, 0, 0, 0);
+        let last: SolarTime = SolarTime::from_ymd_hms(9999, 12, 31, 23, 59, 59);
+        assert_eq!(
+            3652060,
+            SolarDay::from_ymd(9999, 12, 31).subtract(SolarDay::from_ymd(1, 1, 1))
+        );
+        assert_eq!(315538070399, last.subtract(first));
+        assert_eq!(-315538070399, first.subtract(last));
+        assert_eq!(-1, first.subtract(first.next(1)));
+    }
+
+    #[test]
+    fn test79() {
+        let (term, time) = SolarDay::from_ymd(2024, 2, 4).get_jie_qi().unwrap();
+        assert_eq!("立春", term.get_name());
+        assert_time(SolarTime::from_ymd_hms(2024, 2, 4, 16, 27, 7), time);
+        assert!(SolarDay::from_ymd(2024, 2, 5).get_jie_qi().is_none());
+        assert_eq!(
+            "冬至",
+            SolarDay::from_ymd(2024, 12, 21)
+                .get_jie_qi()
+                .unwrap()
+                .0
+                .get_name()
+        );
+    }
+
+    #[test]
+    fn test80() {
+        let a: SolarDay = SolarDay::from_ymd(2024, 2, 29);
+        let b: SolarDay = SolarDay::from_ymd(2024, 3, 1);
+        assert_eq!(1, b - a);
+        assert_eq!(-1, a - b);
+        assert!(a < b);
+        assert!(SolarDay::from_ymd(1582, 10, 4) < SolarDay::from_ymd(1582, 10, 15));
+        let mut days: Vec<SolarDay> = vec![b, a, SolarDay::from_ymd(2023, 12, 31)];
+        days.sort();
+        assert_eq!("2023年12月31日", days[0].to_string());
+        assert_eq!(b, days.iter().max().copied().unwrap());
+
+        let t1: SolarTime = SolarTime::from_ymd_hms(2024, 2, 29, 23, 59, 59);
+        let t2: SolarTime = SolarTime::from_ymd_hms(2024, 3, 1, 0, 0, 0);
+        assert_eq!(1, t2 - t1);
+        assert!(t1 < t2);
+        assert!(SolarTime::from_ymd_hms(2024, 3, 1, 0, 0, 1) > t2);
+    }
+
+    #[test]
+    fn test81() {
+        assert_eq!(
+            "2024年2月4日 16:27:07",
+            SolarTime::from_ymd_decimal_hours(2024, 2, 4, 16.452).to_string()
+        );
+        assert_eq!(
+            "2024年2月4日 00:00:00",
+            SolarTime::from_ymd_decimal_hours(2024, 2, 4, 0.0).to_string()
+        );
+        // 不足半秒舍去，满半秒进位，23:59:59.5及以后计入次日
+        assert_eq!(
+            "2024年2月4日 23:59:59",
+            SolarTime::from_ymd_decimal_hours(2024, 2, 4, 23.9998).to_string()
+        );
+        assert_eq!(
+            "2024年3月1日 00:00:00",
+            SolarTime::from_ymd_decimal_hours(2024, 2, 29, 23.99999).to_string()
+        );
+        assert!(SolarTime::new_decimal_hours(2024, 2, 4, 24.0).is_err());
+        assert!(SolarTime::new_decimal_hours(2024, 2, 4, -0.1).is_err());
+        assert!(SolarTime::new_decimal_hours(2024, 2, 4, f64::NAN).is_err());
+        assert!(SolarTime::new_decimal_hours(9999, 12, 31, 23.99999).is_err());
+        assert_eq!(
+            16.5,
+            SolarTime::from_ymd_hms(2024, 2, 4, 16, 30, 0).get_decimal_hours()
+        );
+    }
+
+    #[test]
+    fn test82() {
+        let mut days: [JulianDay; 24] = [JulianDay::from_julian_day(0.0); 24];
+        SolarTerm::get_julian_days_into(2024, &mut days);
+        for (i, jd) in days.iter().enumerate() {
+            assert_eq!(
+                SolarTerm::from_index(2024, i as isize)
+                    .get_julian_day()
+                    .get_solar_time(),
+                jd.get_solar_time()
+            );
+        }
+        assert_time(
+            SolarTime::from_ymd_hms(2024, 2, 4, 16, 27, 7),
+            days[3].get_solar_time(),
+        );
+    }
+
+    #[cfg(feature = "heapless")]
+    #[test]
+    fn test83() {
+        let mut days: heapless::Vec<SolarDay, 31> = heapless::Vec::new();
+        SolarMonth::from_ym(2024, 2).get_days_into(&mut days);
+        assert_eq!(29, days.len());
+        assert_eq!("2024年2月29日", days[28].to_string());
+    }
+
+    #[test]
+    fn test84() {
+        let term: SolarTerm = SolarTerm::from_index(2024, 3);
+        let high: SolarTime = term.get_julian_day().get_solar_time();
+        assert_eq!(
+            high,
+            term.get_julian_day_with_precision(Precision::HIGH)
                 .get_solar_time()
+        );
+        let standard: SolarTime = term
+            .get_julian_day_with_precision(Precision::STANDARD)
+            .get_solar_time();
+        let fast: SolarTime = term
+            .get_julian_day_with_precision(Precision::FAST)
+            .get_solar_time();
+        assert!((standard - high).abs() <= 60);
+        assert!((fast - high).abs() <= 1800);
+        assert_eq!(Precision::STANDARD, Precision::from_name("标准").unwrap());
+    }
+
+    #[test]
+    fn test85() {
+        assert_eq!(315.0, SolarTerm::from_name(2024, "立春").get_longitude());
+        assert_eq!(0.0, SolarTerm::from_name(2024, "春分").get_longitude());
+        assert_eq!(270.0, SolarTerm::from_index(2024, 0).get_longitude());
+        for i in 0..24 {
+            assert_eq!(TERM_LONGITUDES[i] * 100.0, TERM_LONGITUDES_CENTI[i] as f64);
+            // 交节时刻的太阳视黄经与表一致
+            let jd: f64 = SolarTerm::from_index(2024, i as isize)
+                .get_julian_day()
+                .get_day()
+                - J2000;
+            let lon: f64 = Sxtwl::sun_apparent_longitude(Days(jd - ONE_THIRD + Sxtwl::dtt(jd)))
+                .0
+                .to_degrees();
+            let diff: f64 = (lon - TERM_LONGITUDES[i] + 540.0).rem_euclid(360.0) - 180.0;
+            assert!(diff.abs() < 0.001, "{} {}", i, lon);
+        }
+    }
+
+    #[test]
+    fn test86() {
+        let day: SolarDay = SolarDay::from_ymd(2024, 6, 15);
+        assert_eq!(day, "2024-06-15".parse::<SolarDay>().unwrap());
+        assert_eq!(day, "2024/6/15".parse::<SolarDay>().unwrap());
+        assert_eq!(day, "2024年6月15日".parse::<SolarDay>().unwrap());
+        assert!("2024-02-30".parse::<SolarDay>().is_err());
+        assert!("2024-6/15".parse::<SolarDay>().is_err());
+        assert!(" 2024-06-15".parse::<SolarDay>().is_err());
+        assert!("2024-+6-15".parse::<SolarDay>().is_err());
+        assert_eq!(
+            day,
+            SolarDay::parse(" 2024 - 06 - 15 ", ParseMode::LENIENT).unwrap()
+        );
+        assert!(SolarDay::parse("2024-02-30", ParseMode::LENIENT).is_err());
+    }
+
+    #[test]
+    fn test87() {
+        assert_eq!(
+            "2024年6月15日 12:30:00",
+            "2024-06-15 12:30".parse::<SolarTime>().unwrap().to_string()
+        );
+        assert_eq!(
+            "2024年6月15日 12:30:05",
+            "2024年6月15日T12:30:05"
+                .parse::<SolarTime>()
+                .unwrap()
+                .to_string()
+        );
+        assert!("2024-06-15 24:00:00".parse::<SolarTime>().is_err());
+        assert_eq!(
+            "2024年3月1日 00:00:00",
+            SolarTime::parse("2024-02-29 24:00", ParseMode::LENIENT)
+                .unwrap()
                 .to_string()
         );
+        assert!(SolarTime::parse("2024-02-29 24:00:01", ParseMode::LENIENT).is_err());
+        assert!(SolarTime::parse("9999-12-31 24:00", ParseMode::LENIENT).is_err());
+    }
+
+    #[test]
+    fn test88() {
+        let pillars = SolarTerm::get_jiao_jie_pillars(2024);
+        assert_eq!(24, pillars.len());
+        let (term, time, hour) = &pillars[3];
+        assert_eq!("立春", term.get_name());
+        assert_time(SolarTime::from_ymd_hms(2024, 2, 4, 16, 27, 7), *time);
+        assert_eq!(
+            "甲辰 丙寅 戊戌 庚申",
+            format!(
+                "{} {} {} {}",
+                hour.get_year(),
+                hour.get_month(),
+                hour.get_day(),
+                hour.get_sixty_cycle()
+            )
+        );
+        // 交节前一秒仍属上一节气
+        let before = time.next(-1).get_sixty_cycle_hour();
+        assert_eq!("癸卯", before.get_year().get_name());
+        assert_eq!("乙丑", before.get_month().get_name());
+    }
+
+    #[cfg(feature = "star-twenty-eight")]
+    #[test]
+    fn test89() {
+        use crate::culture::Phase;
+
+        // 春分太阳约在室宿
+        let time: SolarTime = SolarTerm::from_name(2024, "春分")
+            .get_julian_day()
+            .get_solar_time();
+        assert_eq!(
+            "入室宿9度",
+            time.get_sun_twenty_eight_star_position().to_string()
+        );
+
+        // 朔时日月同度
+        let new_moon: SolarTime = Phase::from_index(2024, 5, 0).get_solar_time();
+        let sun = new_moon.get_sun_twenty_eight_star_position();
+        let moon = new_moon.get_moon_twenty_eight_star_position();
+        assert_eq!(sun.get_star(), moon.get_star());
+        assert!((sun.get_degree() - moon.get_degree()).abs() < 0.01);
+    }
+
+    #[test]
+    fn test90() {
+        // 2009年清明交节于子夜前，交节日存疑
+        let r: TermDayResolution = SolarTerm::from_name(2009, "清明").get_day_resolution();
+        assert_eq!("2009年4月4日", r.get_solar_day().to_string());
+        assert!((r.get_seconds_to_midnight() as i64 - 1573).abs() <= time_tolerance());
+        assert!(r.is_ambiguous());
+        if time_tolerance() == 0 {
+            assert_eq!("清明 2009年4月4日 23:33:47（或前后一天）", r.to_string());
+        }
+
+        let r: TermDayResolution = SolarTerm::from_name(1943, "清明").get_day_resolution();
+        assert_time(
+            SolarTime::from_ymd_hms(1943, 4, 6, 0, 11, 10),
+            r.get_solar_time(),
+        );
+        assert!(r.is_ambiguous());
+
+        let r: TermDayResolution = SolarTerm::from_name(2024, "清明").get_day_resolution();
+        assert!(!r.is_ambiguous());
+        assert_eq!(
+            SolarTerm::from_name(2024, "清明").get_solar_day(),
+            r.get_solar_day()
+        );
+    }
+
+    #[test]
+    fn test91() {
+        // 北半球立秋即南半球立春
+        let term: SolarTerm = SolarTerm::from_name(2024, "立秋");
+        assert_eq!("立春", term.get_name_with_hemisphere(Hemisphere::SOUTH));
+        assert_eq!("立秋", term.get_name_with_hemisphere(Hemisphere::NORTH));
+        let south: SolarTerm =
+            SolarTerm::new_with_hemisphere(2024, "立春", Hemisphere::SOUTH).unwrap();
+        assert_eq!(term.get_julian_day(), south.get_julian_day());
+        assert_eq!("立秋", south.get_name());
+        // 冬至（上年12月）对应南半球的冬至为当年6月的夏至
+        let winter: SolarTerm =
+            SolarTerm::new_with_hemisphere(2024, "冬至", Hemisphere::SOUTH).unwrap();
+        assert_eq!("2024年6月21日", winter.get_solar_day().to_string());
+        // 南半球的夏至为当年12月的冬至
+        let summer: SolarTerm =
+            SolarTerm::new_with_hemisphere(2024, "夏至", Hemisphere::SOUTH).unwrap();
+        assert_eq!("2024年12月21日", summer.get_solar_day().to_string());
+        // 同一年南半球的24节气按时间先后排列
+        let days: Vec<f64> = SOLAR_TERM_NAMES
+            .iter()
+            .map(|name| {
+                SolarTerm::new_with_hemisphere(2024, name, Hemisphere::SOUTH)
+                    .unwrap()
+                    .get_julian_day()
+                    .get_day()
+            })
+            .collect();
+        assert!(days.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test92() {
+        let names: Vec<String> = (0..24)
+            .map(|i| {
+                let term: SolarTerm = SolarTerm::from_index(2024, i);
+                format!(
+                    "{}{}{}{}",
+                    term.get_name(),
+                    term.get_index_from_spring_begin(),
+                    term.get_season(),
+                    term.get_month_branch()
+                )
+            })
+            .collect();
+        assert_eq!("冬至21冬子", names[0]);
+        assert_eq!("小寒22冬丑", names[1]);
+        assert_eq!("立春0春寅", names[3]);
+        assert_eq!("雨水1春寅", names[4]);
+        assert_eq!("立夏6夏巳", names[9]);
+        assert_eq!("大雪20冬子", names[23]);
+    }
+
+    #[cfg(feature = "phenology")]
+    #[test]
+    fn test93() {
+        let term: SolarTerm = SolarTerm::from_name(2024, "立春");
+        let l: Vec<String> = term
+            .get_phenologies()
+            .iter()
+            .map(|p| p.get_name())
+            .collect();
+        assert_eq!(vec!["东风解冻", "蛰虫始振", "鱼陟负冰"], l);
+        assert_eq!(
+            SolarDay::from_ymd(2024, 2, 4).get_phenology(),
+            term.get_phenologies()[0]
+        );
+    }
+
+    #[test]
+    fn test94() {
+        let term: SolarTerm = SolarTerm::from_spring_begin_index(2024, 0);
+        assert_eq!("立春", term.get_name());
+        assert_eq!(2024, term.get_year());
+        assert_eq!("春雨惊春清谷天", term.get_song_line());
+        assert_eq!(2, term.get_solar_month_number());
+        assert_eq!(1, term.get_term_month_number());
+
+        let term: SolarTerm = SolarTerm::from_spring_begin_index(2024, 23);
+        assert_eq!("大寒", term.get_name());
+        assert_eq!(2025, term.get_year());
+        assert_eq!("大", term.get_song_char());
+        assert_eq!("冬雪雪冬小大寒", term.get_song_line());
+        assert_eq!(1, term.get_solar_month_number());
+        assert_eq!(12, term.get_term_month_number());
+
+        let term: SolarTerm = SolarTerm::from_name(2024, "冬至");
+        assert_eq!(12, term.get_solar_month_number());
+        assert_eq!(11, term.get_term_month_number());
+        let song: String = (0..24)
+            .map(|i| SolarTerm::from_spring_begin_index(2024, i).get_song_char())
+            .collect();
+        assert_eq!("春雨惊春清谷夏满芒夏暑暑秋处露秋寒霜冬雪雪冬小大", song);
+    }
+
+    #[cfg(feature = "phenology")]
+    #[test]
+    fn test95() {
+        assert_eq!(
+            vec!["东风解冻", "蛰虫始振", "鱼陟负冰"],
+            SolarTerm::from_name(2024, "立春").get_phenology_names()
+        );
+    }
+
+    #[test]
+    fn test96() {
+        let term: SolarTerm = SolarTerm::from_name(2024, "立春");
+        let code: u8 = term.clone().into();
+        assert_eq!(3, code);
+        let restored: SolarTerm = SolarTerm::try_from((2024, code)).unwrap();
+        assert_eq!(term.get_julian_day(), restored.get_julian_day());
+        assert_eq!(0, u8::from(SolarTerm::from_name(2024, "冬至")));
+        assert!(SolarTerm::try_from((2024, 24)).is_err());
+    }
+
+    #[test]
+    fn test97() {
+        let jd: JulianDay = SolarTime::from_ymd_hms(2024, 2, 4, 16, 28, 0).get_julian_day();
+        assert_eq!(
+            SolarDay::from_ymd(2024, 2, 4),
+            SolarDay::from_julian_day(jd)
+        );
+        assert_eq!(
+            "2024年2月4日 16:28:00",
+            SolarTime::from_julian_day(jd).to_string()
+        );
+        assert_eq!("立春", SolarTerm::from_julian_day(jd).get_name());
+        let before: JulianDay = SolarTime::from_ymd_hms(2024, 2, 4, 16, 0, 0).get_julian_day();
+        assert_eq!("大寒", SolarTerm::from_julian_day(before).get_name());
+        let day: SolarDay = SolarDay::from_ymd(2024, 6, 15);
+        assert_eq!(day, SolarDay::from_julian_day(day.get_julian_day()));
     }
 }
@@ -0,0 +1,588 @@
+//! 历法统计
+
+use core::f64::consts::PI;
+use core::fmt::{Display, Formatter};
+use core::ops::RangeInclusive;
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use libm::floor;
+
+use crate::context::{BEIJING_UTC_OFFSET, CalendarContext};
+use crate::jd::J2000;
+use crate::lunar::{LUNAR_DAY_NAMES, LUNAR_MONTH_NAMES, LunarDay, LunarMonth, LunarYear};
+use crate::solar::{SolarDay, SolarTerm};
+use crate::sxtwl::{PI_2, Sxtwl};
+use crate::types::Tyme;
+
+/// 农历年份范围内各年春节（正月初一）的公历日，按农历年升序
+pub fn spring_festival_dates(range: RangeInclusive<isize>) -> Result<Vec<SolarDay>, String> {
+    let mut l: Vec<SolarDay> = Vec::new();
+    for year in range {
+        l.push(LunarDay::new(year, 1, 1)?.get_solar_day());
+    }
+    Ok(l)
+}
+
+/// 农历年份范围内公历日期（月日）最早的春节，同月日时取较早的年份
+pub fn earliest_spring_festival(range: RangeInclusive<isize>) -> Result<SolarDay, String> {
+    let (start, end) = (*range.start(), *range.end());
+    spring_festival_dates(range)?
+        .into_iter()
+        .min_by_key(|d| (d.get_month(), d.get_day()))
+        .ok_or_else(|| format!("empty lunar year range: {}..={}", start, end))
+}
+
+/// 农历年份范围内公历日期（月日）最晚的春节，同月日时取较早的年份
+pub fn latest_spring_festival(range: RangeInclusive<isize>) -> Result<SolarDay, String> {
+    let (start, end) = (*range.start(), *range.end());
+    spring_festival_dates(range)?
+        .into_iter()
+        .rev()
+        .max_by_key(|d| (d.get_month(), d.get_day()))
+        .ok_or_else(|| format!("empty lunar year range: {}..={}", start, end))
+}
+
+/// 公历日区间统计
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct IntervalStatistics {
+    /// 总天数
+    days: usize,
+    /// 周末（周六、周日）天数
+    weekends: usize,
+    /// 工作日天数（启用holiday时计法定假日及调休）
+    workdays: usize,
+    /// 交节日天数
+    term_days: usize,
+    /// 节日天数（启用festival时计公历及农历节日，同日多节只计一次）
+    festival_days: usize,
+}
+
+impl IntervalStatistics {
+    /// 统计起止公历日（含）之间的天数
+    pub fn new(start: SolarDay, end: SolarDay) -> Result<Self, String> {
+        if end.is_before(start) {
+            return Err(format!("illegal solar day range: {}..={}", start, end));
+        }
+        let mut stat: Self = Self::default();
+        let mut day: SolarDay = start;
+        loop {
+            stat.add(day);
+            if day == end {
+                break;
+            }
+            day = day.next(1);
+        }
+        Ok(stat)
+    }
+
+    fn add(&mut self, day: SolarDay) {
+        self.days += 1;
+        let week: usize = day.get_week().get_index();
+        let weekend: bool = week == 0 || week == 6;
+        if weekend {
+            self.weekends += 1;
+        }
+        if Self::is_workday(day, weekend) {
+            self.workdays += 1;
+        }
+        if day.get_term_day().get_day_index() == 0 {
+            self.term_days += 1;
+        }
+        if Self::is_festival_day(day) {
+            self.festival_days += 1;
+        }
+    }
+
+    #[cfg(feature = "holiday")]
+    fn is_workday(day: SolarDay, weekend: bool) -> bool {
+        match day.get_legal_holiday() {
+            Some(holiday) => holiday.is_work(),
+            None => !weekend,
+        }
+    }
+
+    #[cfg(not(feature = "holiday"))]
+    fn is_workday(_day: SolarDay, weekend: bool) -> bool {
+        !weekend
+    }
+
+    #[cfg(feature = "festival")]
+    fn is_festival_day(day: SolarDay) -> bool {
+        day.get_festival().is_some() || day.get_lunar_day().get_festival().is_some()
+    }
+
+    #[cfg(not(feature = "festival"))]
+    fn is_festival_day(_day: SolarDay) -> bool {
+        false
+    }
+
+    /// 总天数
+    pub fn get_days(&self) -> usize {
+        self.days
+    }
+
+    /// 周末天数
+    pub fn get_weekends(&self) -> usize {
+        self.weekends
+    }
+
+    /// 工作日天数
+    pub fn get_workdays(&self) -> usize {
+        self.workdays
+    }
+
+    /// 交节日天数
+    pub fn get_term_days(&self) -> usize {
+        self.term_days
+    }
+
+    /// 节日天数
+    pub fn get_festival_days(&self) -> usize {
+        self.festival_days
+    }
+}
+
+/// 两种配置上下文（UTC偏移）下农历月名不同的公历日
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LunarMonthDivergence {
+    /// 公历日
+    day: SolarDay,
+    /// 第一种上下文下的农历月名及日
+    first: (String, usize),
+    /// 第二种上下文下的农历月名及日
+    second: (String, usize),
+}
+
+impl LunarMonthDivergence {
+    /// 公历日
+    pub fn get_solar_day(&self) -> SolarDay {
+        self.day
+    }
+
+    /// 第一种上下文下的农历月名，如：闰二月
+    pub fn get_first_month_name(&self) -> String {
+        self.first.0.clone()
+    }
+
+    /// 第一种上下文下的农历日
+    pub fn get_first_day(&self) -> usize {
+        self.first.1
+    }
+
+    /// 第二种上下文下的农历月名
+    pub fn get_second_month_name(&self) -> String {
+        self.second.0.clone()
+    }
+
+    /// 第二种上下文下的农历日
+    pub fn get_second_day(&self) -> usize {
+        self.second.1
+    }
+}
+
+impl Display for LunarMonthDivergence {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{}：{}{} / {}{}",
+            self.day,
+            self.first.0,
+            LUNAR_DAY_NAMES[self.first.1 - 1],
+            self.second.0,
+            LUNAR_DAY_NAMES[self.second.1 - 1]
+        )
+    }
+}
+
+/// 起止公历日（含）之间，按两种配置上下文的UTC偏移定朔、定气时农历月名不同的公历日（如UTC+8的中国农历与UTC+7的越南阴历）。
+/// 两侧均按定朔定气（Sxtwl::shuo_high、Sxtwl::qi_high）推算：冬至所在月为十一月，两冬至间有13个月时首个无中气的月为闰月
+pub fn lunar_month_divergence(
+    start: SolarDay,
+    end: SolarDay,
+    first: &CalendarContext,
+    second: &CalendarContext,
+) -> Result<Vec<LunarMonthDivergence>, String> {
+    if end.is_before(start) {
+        return Err(format!("illegal solar day range: {}..={}", start, end));
+    }
+    let from: i64 = day_number(start);
+    let to: i64 = day_number(end);
+    let a: Vec<LocalLunarMonth> = local_lunar_months(start, end, first.get_utc_offset());
+    let b: Vec<LocalLunarMonth> = local_lunar_months(start, end, second.get_utc_offset());
+    let mut l: Vec<LunarMonthDivergence> = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    let mut day: SolarDay = start;
+    for d in from..=to {
+        while a[i + 1].start <= d {
+            i += 1;
+        }
+        while b[j + 1].start <= d {
+            j += 1;
+        }
+        if a[i].month != b[j].month {
+            l.push(LunarMonthDivergence {
+                day,
+                first: (a[i].get_name(), (d - a[i].start) as usize + 1),
+                second: (b[j].get_name(), (d - b[j].start) as usize + 1),
+            });
+        }
+        day = day.next(1);
+    }
+    Ok(l)
+}
+
+/// 按UTC偏移推算的农历月
+#[derive(Debug, Copy, Clone)]
+struct LocalLunarMonth {
+    /// 初一（J2000起算的日序号）
+    start: i64,
+    /// 月（闰月为负）
+    month: isize,
+}
+
+impl LocalLunarMonth {
+    fn get_name(&self) -> String {
+        format!(
+            "{}{}",
+            if self.month < 0 { "闰" } else { "" },
+            LUNAR_MONTH_NAMES[self.month.unsigned_abs() - 1]
+        )
+    }
+}
+
+/// 公历日的日序号（J2000起算，当日12时为整数）
+fn day_number(day: SolarDay) -> i64 {
+    (day.get_julian_day().get_day() - J2000 + 0.5) as i64
+}
+
+/// 北京时间的时刻（J2000起算的儒略日数）在指定UTC偏移下所在日的日序号
+fn local_day_number(beijing: f64, utc_offset: isize) -> i64 {
+    floor(beijing + (utc_offset - BEIJING_UTC_OFFSET) as f64 / 86400.0 + 0.5) as i64
+}
+
+/// 覆盖起止公历日的农历月（按初一升序，末项仅作区间终点）
+fn local_lunar_months(start: SolarDay, end: SolarDay, utc_offset: isize) -> Vec<LocalLunarMonth> {
+    let new_moon = |k: i64| local_day_number(Sxtwl::shuo_high(k as f64 * PI_2), utc_offset);
+    let zhong_qi = |w: f64| local_day_number(Sxtwl::qi_high(w), utc_offset);
+    let mut l: Vec<LocalLunarMonth> = Vec::new();
+    for year in start.get_year() - 1..=end.get_year() + 1 {
+        // 冬至：视黄经270°，自J2000起累计
+        let w: f64 = 1.5 * PI + (year - 1999) as f64 * PI_2;
+        let winter_solstice: i64 = zhong_qi(w);
+        let next_winter_solstice: i64 = zhong_qi(w + PI_2);
+        let first: i64 = month_index(winter_solstice, &new_moon);
+        let last: i64 = month_index(next_winter_solstice, &new_moon);
+        let qi: Vec<i64> = (0..=12)
+            .map(|i| zhong_qi(w + i as f64 * PI / 6.0))
+            .collect();
+        let mut leap: bool = last - first == 13;
+        let mut month: isize = 0;
+        for k in first..last {
+            let (s, e) = (new_moon(k), new_moon(k + 1));
+            month = if k == first {
+                11
+            } else if leap && !qi.iter().any(|q| s <= *q && *q < e) {
+                leap = false;
+                -month
+            } else {
+                month.abs() % 12 + 1
+            };
+            if l.last().is_none_or(|p| p.start < s) {
+                l.push(LocalLunarMonth { start: s, month });
+            }
+        }
+    }
+    l
+}
+
+/// 包含日序号d的农历月的朔序号
+fn month_index(d: i64, new_moon: &impl Fn(i64) -> i64) -> i64 {
+    let mut k: i64 = floor((d as f64 - 5.0) / 29.5306) as i64;
+    while new_moon(k + 1) <= d {
+        k += 1;
+    }
+    while new_moon(k) > d {
+        k -= 1;
+    }
+    k
+}
+
+/// 农历年概要
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct YearSummary {
+    /// 农历年
+    year: isize,
+    /// 闰月，无闰月为0
+    leap_month: usize,
+    /// 正月初一的公历日
+    new_year_day: SolarDay,
+    /// 全年天数
+    day_count: usize,
+    /// 自上年冬至起24个节气的公历日
+    term_days: [SolarDay; 24],
+}
+
+impl YearSummary {
+    /// 农历年
+    pub fn get_year(&self) -> isize {
+        self.year
+    }
+
+    /// 闰月，无闰月为0
+    pub fn get_leap_month(&self) -> usize {
+        self.leap_month
+    }
+
+    /// 正月初一的公历日
+    pub fn get_new_year_day(&self) -> SolarDay {
+        self.new_year_day
+    }
+
+    /// 全年天数
+    pub fn get_day_count(&self) -> usize {
+        self.day_count
+    }
+
+    /// 自上年冬至起24个节气的公历日（下标同SolarTerm::from_index）
+    pub fn get_term_days(&self) -> [SolarDay; 24] {
+        self.term_days
+    }
+}
+
+/// 逐年概要迭代器：每次只计算一年，不保存已产出的年份，相邻年份共用正月初一
+#[derive(Debug, Clone)]
+pub struct YearSummaries {
+    /// 下一个产出的农历年
+    year: isize,
+    /// 末年（含）
+    end: isize,
+    /// 下一年正月初一的公历日（已由上一年算出时）
+    new_year_day: Option<SolarDay>,
+}
+
+impl Iterator for YearSummaries {
+    type Item = YearSummary;
+
+    fn next(&mut self) -> Option<YearSummary> {
+        if self.year > self.end {
+            return None;
+        }
+        let year: isize = self.year;
+        let new_year_day: SolarDay = self
+            .new_year_day
+            .take()
+            .unwrap_or_else(|| lunar_new_year_day(year));
+        let next: SolarDay = lunar_new_year_day(year + 1);
+        self.new_year_day = Some(next);
+        let mut term_days: [SolarDay; 24] = [new_year_day; 24];
+        for (i, d) in term_days.iter_mut().enumerate() {
+            *d = SolarTerm::from_index(year, i as isize)
+                .get_julian_day()
+                .get_solar_day();
+        }
+        self.year += 1;
+        Some(YearSummary {
+            year,
+            leap_month: LunarYear::from_year(year).get_leap_month(),
+            new_year_day,
+            day_count: next.subtract(new_year_day) as usize,
+            term_days,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n: usize = (self.end - self.year + 1).max(0) as usize;
+        (n, Some(n))
+    }
+}
+
+impl ExactSizeIterator for YearSummaries {}
+
+/// 可逐年概要的农历年（含首尾）：节气日自上年冬至起，上年须为合法公历年；全年天数需要下一年的正月初一
+pub const SUMMARY_YEARS: RangeInclusive<isize> = 2..=9998;
+
+/// 农历年份范围内逐年的概要（闰月、正月初一、全年天数、节气日），流式计算，内存占用与年数无关
+pub fn iter_years(range: RangeInclusive<isize>) -> Result<YearSummaries, String> {
+    let (start, end) = (*range.start(), *range.end());
+    if start <= end && !(SUMMARY_YEARS.contains(&start) && SUMMARY_YEARS.contains(&end)) {
+        return Err(format!("illegal lunar year range: {}..={}", start, end));
+    }
+    Ok(YearSummaries {
+        year: start,
+        end,
+        new_year_day: None,
+    })
+}
+
+/// 农历年正月初一的公历日
+fn lunar_new_year_day(year: isize) -> SolarDay {
+    LunarMonth::from_ym(year, 1)
+        .get_first_julian_day()
+        .get_solar_day()
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+
+    use crate::context::{BEIJING_UTC_OFFSET, CalendarContext};
+    use crate::solar::SolarDay;
+    use crate::statistics::{
+        IntervalStatistics, LunarMonthDivergence, YearSummary, day_number,
+        earliest_spring_festival, iter_years, latest_spring_festival, local_lunar_months,
+        lunar_month_divergence, spring_festival_dates,
+    };
+    use crate::types::Tyme;
+
+    #[test]
+    fn test1() {
+        let dates = spring_festival_dates(2020..=2025).unwrap();
+        assert_eq!(6, dates.len());
+        assert_eq!("2020年1月25日", dates[0].to_string());
+        assert_eq!("2025年1月29日", dates[5].to_string());
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(
+            "1966年1月21日",
+            earliest_spring_festival(1900..=2100).unwrap().to_string()
+        );
+        assert_eq!(
+            "1920年2月20日",
+            latest_spring_festival(1900..=2100).unwrap().to_string()
+        );
+        assert!(earliest_spring_festival(2000..=1999).is_err());
+        assert!(spring_festival_dates(9999..=10000).is_err());
+    }
+
+    #[test]
+    fn test3() {
+        let stat: IntervalStatistics = IntervalStatistics::new(
+            SolarDay::from_ymd(2024, 1, 1),
+            SolarDay::from_ymd(2024, 12, 31),
+        )
+        .unwrap();
+        assert_eq!(366, stat.get_days());
+        assert_eq!(104, stat.get_weekends());
+        assert_eq!(24, stat.get_term_days());
+        assert!(
+            IntervalStatistics::new(
+                SolarDay::from_ymd(2024, 1, 2),
+                SolarDay::from_ymd(2024, 1, 1)
+            )
+            .is_err()
+        );
+    }
+
+    #[cfg(all(feature = "holiday", feature = "festival"))]
+    #[test]
+    fn test4() {
+        // 2024年10月：国庆7天假，10月12日调休上班；国庆节、重阳节
+        let stat: IntervalStatistics = IntervalStatistics::new(
+            SolarDay::from_ymd(2024, 10, 1),
+            SolarDay::from_ymd(2024, 10, 31),
+        )
+        .unwrap();
+        assert_eq!(31, stat.get_days());
+        assert_eq!(8, stat.get_weekends());
+        assert_eq!(19, stat.get_workdays());
+        assert_eq!(2, stat.get_festival_days());
+    }
+
+    #[test]
+    fn test5() {
+        // 越南（UTC+7）2007年春节早于中国一天
+        let vietnam: CalendarContext = CalendarContext::default().with_utc_offset(25200).unwrap();
+        let l: Vec<LunarMonthDivergence> = lunar_month_divergence(
+            SolarDay::from_ymd(2007, 1, 1),
+            SolarDay::from_ymd(2007, 12, 31),
+            &CalendarContext::default(),
+            &vietnam,
+        )
+        .unwrap();
+        assert_eq!("2007年2月17日：腊月三十 / 正月初一", l[0].to_string());
+
+        // 1985年：中国闰十月在1984年，越南春节早一个月
+        let l: Vec<LunarMonthDivergence> = lunar_month_divergence(
+            SolarDay::from_ymd(1985, 1, 21),
+            SolarDay::from_ymd(1985, 1, 21),
+            &CalendarContext::default(),
+            &vietnam,
+        )
+        .unwrap();
+        assert_eq!("腊月", l[0].get_first_month_name());
+        assert_eq!("正月", l[0].get_second_month_name());
+        assert_eq!(1, l[0].get_second_day());
+
+        assert!(
+            lunar_month_divergence(
+                SolarDay::from_ymd(2024, 1, 1),
+                SolarDay::from_ymd(2024, 12, 31),
+                &CalendarContext::default(),
+                &CalendarContext::default()
+            )
+            .unwrap()
+            .is_empty()
+        );
+    }
+
+    #[test]
+    fn test6() {
+        let l: Vec<YearSummary> = iter_years(2020..=2025).unwrap().collect();
+        assert_eq!(6, l.len());
+        assert_eq!(4, l[0].get_leap_month());
+        assert_eq!("2020年1月25日", l[0].get_new_year_day().to_string());
+        assert_eq!(384, l[0].get_day_count());
+        assert_eq!(2, l[3].get_leap_month());
+        assert_eq!(0, l[4].get_leap_month());
+        assert_eq!(354, l[4].get_day_count());
+        assert_eq!("2023年12月22日", l[4].get_term_days()[0].to_string());
+        assert_eq!("2024年2月4日", l[4].get_term_days()[3].to_string());
+
+        // 与逐年单独计算一致
+        let summaries = iter_years(1900..=2100).unwrap();
+        assert_eq!(201, summaries.len());
+        for (s, d) in summaries.zip(spring_festival_dates(1900..=2100).unwrap()) {
+            assert_eq!(d, s.get_new_year_day());
+            assert_eq!(
+                crate::lunar::LunarYear::from_year(s.get_year()).get_day_count(),
+                s.get_day_count()
+            );
+        }
+        assert_eq!(1, iter_years(9998..=9998).unwrap().count());
+        assert_eq!(0, iter_years(2001..=2000).unwrap().len());
+        assert!(iter_years(1..=2000).is_err());
+        assert!(iter_years(2000..=9999).is_err());
+    }
+
+    #[test]
+    fn test7() {
+        // 北京时间下的逐日农历月日与LunarDay一致
+        let start: SolarDay = SolarDay::from_ymd(1960, 1, 1);
+        let end: SolarDay = SolarDay::from_ymd(2050, 12, 31);
+        let months = local_lunar_months(start, end, BEIJING_UTC_OFFSET);
+        let mut i: usize = 0;
+        let mut day: SolarDay = start;
+        while day <= end {
+            let d: i64 = day_number(day);
+            while months[i + 1].start <= d {
+                i += 1;
+            }
+            let lunar_day = day.get_lunar_day();
+            assert_eq!(
+                (
+                    lunar_day.get_lunar_month().get_month_with_leap(),
+                    lunar_day.get_day()
+                ),
+                (months[i].month, (d - months[i].start + 1) as usize),
+                "{}",
+                day
+            );
+            day = day.next(1);
+        }
+    }
+}
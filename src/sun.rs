@@ -0,0 +1,376 @@
+use alloc::format;
+use alloc::string::String;
+use libm::{acos, atan, cos, fabs, round, sin, sqrt, tan};
+
+use crate::jd::{J2000, JulianDay};
+use crate::solar::{SolarDay, SolarTime};
+use crate::sxtwl::units::{Days, Degrees, Radians};
+use crate::sxtwl::{EARTH_FLATTENING, ONE_THIRD, Sxtwl};
+use core::f64::consts::PI;
+
+/// 北京时间所用的标准经度（东经120度）
+pub static BEIJING_LONGITUDE: f64 = 120.0;
+
+/// 观测者位置
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Observer {
+    /// 地理经度（度，东经为正）
+    longitude: f64,
+    /// 地理纬度（度，北纬为正）
+    latitude: f64,
+}
+
+impl Observer {
+    pub fn new(longitude: f64, latitude: f64) -> Result<Self, String> {
+        if !(-180.0..=180.0).contains(&longitude) {
+            return Err(format!("illegal longitude: {}", longitude));
+        }
+        if !(-90.0..=90.0).contains(&latitude) {
+            return Err(format!("illegal latitude: {}", latitude));
+        }
+        Ok(Self {
+            longitude,
+            latitude,
+        })
+    }
+
+    pub fn from_degrees(longitude: f64, latitude: f64) -> Self {
+        Self::new(longitude, latitude).unwrap()
+    }
+
+    /// 地理经度（度）
+    pub fn get_longitude(&self) -> f64 {
+        self.longitude
+    }
+
+    /// 地理纬度（度）
+    pub fn get_latitude(&self) -> f64 {
+        self.latitude
+    }
+
+    /// 天体赤纬为dec、升没高度角为h0时，升没时角的余弦；不小于1为终日不升，不大于-1为终日不没
+    pub(crate) fn hour_angle_cos(&self, dec: Radians, h0: Degrees) -> f64 {
+        let phi: Radians = Degrees(self.latitude).into();
+        let h0: Radians = h0.into();
+        (sin(h0.0) - sin(phi.0) * sin(dec.0)) / (cos(phi.0) * cos(dec.0))
+    }
+
+    /// 观测者在海平面时的地心坐标(ρcosφ′, ρsinφ′)，ρ以地球赤道半径为单位，φ′为地心纬度
+    pub(crate) fn geocentric_position(&self) -> (f64, f64) {
+        let phi: Radians = Degrees(self.latitude).into();
+        let u: f64 = atan((1.0 - EARTH_FLATTENING) * tan(phi.0));
+        (cos(u), (1.0 - EARTH_FLATTENING) * sin(u))
+    }
+}
+
+/// 升没计算参数（地平大气折射、天体视半径、观测者海拔），默认按折射34′、日面半径16′、海平面计
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct RiseSetOptions {
+    /// 地平大气折射（角分）
+    refraction: f64,
+    /// 视半径（角分），以天体中心计时为0
+    semi_diameter: f64,
+    /// 观测者海拔（米）
+    elevation: f64,
+}
+
+impl Default for RiseSetOptions {
+    fn default() -> Self {
+        Self {
+            refraction: 34.0,
+            semi_diameter: 16.0,
+            elevation: 0.0,
+        }
+    }
+}
+
+impl RiseSetOptions {
+    pub fn new(refraction: f64, semi_diameter: f64, elevation: f64) -> Result<Self, String> {
+        if !(0.0..=120.0).contains(&refraction) {
+            return Err(format!("illegal refraction: {}", refraction));
+        }
+        if !(0.0..=120.0).contains(&semi_diameter) {
+            return Err(format!("illegal semi diameter: {}", semi_diameter));
+        }
+        if !(0.0..=10000.0).contains(&elevation) {
+            return Err(format!("illegal elevation: {}", elevation));
+        }
+        Ok(Self {
+            refraction,
+            semi_diameter,
+            elevation,
+        })
+    }
+
+    /// 月亮升没：折射34′、月面半径15.5′、海平面
+    pub fn moon() -> Self {
+        Self {
+            refraction: 34.0,
+            semi_diameter: 15.5,
+            elevation: 0.0,
+        }
+    }
+
+    /// 几何升没（不计折射，以天体中心过地平计）
+    pub fn geometric() -> Self {
+        Self {
+            refraction: 0.0,
+            semi_diameter: 0.0,
+            elevation: 0.0,
+        }
+    }
+
+    /// 地平大气折射（角分）
+    pub fn get_refraction(&self) -> f64 {
+        self.refraction
+    }
+
+    /// 视半径（角分）
+    pub fn get_semi_diameter(&self) -> f64 {
+        self.semi_diameter
+    }
+
+    /// 观测者海拔（米）
+    pub fn get_elevation(&self) -> f64 {
+        self.elevation
+    }
+
+    /// 升没时天体中心的地平高度，含海拔引起的地平俯角（1.76′×√海拔）
+    pub fn get_altitude(&self) -> Degrees {
+        Degrees(-(self.refraction + self.semi_diameter + 1.76 * sqrt(self.elevation)) / 60.0)
+    }
+}
+
+/// 某地某日的太阳视运动（日中天、昼长）
+#[derive(Debug, Copy, Clone)]
+pub struct SunTimes {
+    /// 公历日
+    day: SolarDay,
+    /// 地理经度（度，东经为正）
+    longitude: f64,
+    /// 地理纬度（度，北纬为正）
+    latitude: f64,
+    /// 升没计算参数
+    options: RiseSetOptions,
+}
+
+impl SunTimes {
+    pub fn new(day: SolarDay, longitude: f64, latitude: f64) -> Result<Self, String> {
+        Ok(Self::from_observer(
+            day,
+            Observer::new(longitude, latitude)?,
+        ))
+    }
+
+    pub fn from_solar_day(day: SolarDay, longitude: f64, latitude: f64) -> Self {
+        Self::new(day, longitude, latitude).unwrap()
+    }
+
+    pub fn from_observer(day: SolarDay, observer: Observer) -> Self {
+        Self {
+            day,
+            longitude: observer.get_longitude(),
+            latitude: observer.get_latitude(),
+            options: RiseSetOptions::default(),
+        }
+    }
+
+    /// 公历日
+    pub fn get_solar_day(&self) -> SolarDay {
+        self.day
+    }
+
+    /// 地理经度（度）
+    pub fn get_longitude(&self) -> f64 {
+        self.longitude
+    }
+
+    /// 地理纬度（度）
+    pub fn get_latitude(&self) -> f64 {
+        self.latitude
+    }
+
+    /// 升没计算参数
+    pub fn get_options(&self) -> RiseSetOptions {
+        self.options
+    }
+
+    /// 使用指定升没计算参数
+    pub fn with_options(&self, options: RiseSetOptions) -> Self {
+        Self { options, ..*self }
+    }
+
+    /// 日中天（真太阳过上中天）时刻，J2000起算的世界时儒略日数
+    fn transit(&self) -> f64 {
+        let d: f64 = self.day.get_julian_day().get_day() - J2000 + 0.5 - self.longitude / 360.0;
+        let mut t: f64 = d;
+        for _ in 0..2 {
+            t = d - Sxtwl::equation_of_time(Days(t + Sxtwl::dtt(t))).0;
+        }
+        t
+    }
+
+    /// 日中天（真太阳过上中天），北京时间，已按经度及时差修正
+    pub fn solar_noon(&self) -> SolarTime {
+        JulianDay::from_julian_day(self.transit() + ONE_THIRD + J2000).get_solar_time()
+    }
+
+    /// 日中天时的太阳高度角（不含大气折射），即90°减去纬度与太阳赤纬之差的绝对值；极夜为负
+    pub fn noon_altitude(&self) -> Degrees {
+        let t: f64 = self.transit();
+        let (_, dec) = Sxtwl::sun_equatorial(Days(t + Sxtwl::dtt(t)));
+        let dec: Degrees = dec.into();
+        Degrees(90.0 - fabs(self.latitude - dec.0))
+    }
+
+    /// 日中天时高为height的直立表杆（圭表）的影长，与height同单位；太阳不出地平时为None
+    pub fn noon_shadow_length(&self, height: f64) -> Option<f64> {
+        let h: Radians = self.noon_altitude().into();
+        if h.0 <= 0.0 {
+            return None;
+        }
+        Some(height / tan(h.0))
+    }
+
+    /// 观测者位置
+    pub fn get_observer(&self) -> Observer {
+        Observer {
+            longitude: self.longitude,
+            latitude: self.latitude,
+        }
+    }
+
+    /// 日出，北京时间；极昼、极夜为None
+    pub fn sunrise(&self) -> Option<SolarTime> {
+        self.rise_set(-1.0)
+    }
+
+    /// 日没，北京时间；极昼、极夜为None
+    pub fn sunset(&self) -> Option<SolarTime> {
+        self.rise_set(1.0)
+    }
+
+    /// 日出（sign为-1）或日没（sign为1）：自日中天起按当时的太阳赤纬迭代时角
+    fn rise_set(&self, sign: f64) -> Option<SolarTime> {
+        let transit: f64 = self.transit();
+        let mut t: f64 = transit;
+        for _ in 0..3 {
+            let (_, dec) = Sxtwl::sun_equatorial(Days(t + Sxtwl::dtt(t)));
+            let c: f64 = self
+                .get_observer()
+                .hour_angle_cos(dec, self.options.get_altitude());
+            if !(-1.0 < c && c < 1.0) {
+                return None;
+            }
+            t = transit + sign * acos(c) / (2.0 * PI);
+        }
+        Some(JulianDay::from_julian_day(t + ONE_THIRD + J2000).get_solar_time())
+    }
+
+    /// 昼长（秒），按升没计算参数确定日出日没，极昼为86400，极夜为0
+    pub fn day_length(&self) -> usize {
+        let t: f64 = self.transit();
+        let (_, dec) = Sxtwl::sun_equatorial(Days(t + Sxtwl::dtt(t)));
+        let c: f64 = self
+            .get_observer()
+            .hour_angle_cos(dec, self.options.get_altitude());
+        if c <= -1.0 {
+            return 86400;
+        }
+        if c >= 1.0 {
+            return 0;
+        }
+        round(acos(c) / PI * 86400.0) as usize
+    }
+}
+
+/// 某日某纬度日中天时的太阳高度角（度），按北京时间所在经度计
+pub fn solar_noon_altitude(day: SolarDay, latitude: f64) -> Result<f64, String> {
+    Ok(SunTimes::new(day, BEIJING_LONGITUDE, latitude)?
+        .noon_altitude()
+        .0)
+}
+
+/// 某日某纬度日中天时高为height的表杆影长，与height同单位；太阳不出地平时为None
+pub fn noon_shadow_length(
+    day: SolarDay,
+    latitude: f64,
+    height: f64,
+) -> Result<Option<f64>, String> {
+    Ok(SunTimes::new(day, BEIJING_LONGITUDE, latitude)?.noon_shadow_length(height))
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::solar::SolarDay;
+    use crate::sun::{Observer, RiseSetOptions, SunTimes, noon_shadow_length, solar_noon_altitude};
+
+    #[test]
+    fn test1() {
+        let t: SunTimes = SunTimes::from_solar_day(SolarDay::from_ymd(2024, 6, 21), 116.4, 39.9);
+        assert_eq!("2024年6月21日 12:16:15", t.solar_noon().to_string());
+        assert_eq!(54014, t.day_length());
+    }
+
+    #[test]
+    fn test2() {
+        let t: SunTimes = SunTimes::from_solar_day(SolarDay::from_ymd(2024, 11, 3), 120.0, 30.0);
+        assert_eq!("2024年11月3日 11:43:33", t.solar_noon().to_string());
+    }
+
+    #[test]
+    fn test3() {
+        let day: SolarDay = SolarDay::from_ymd(2024, 6, 21);
+        assert_eq!(
+            86400,
+            SunTimes::from_solar_day(day, 25.0, 70.0).day_length()
+        );
+        assert_eq!(0, SunTimes::from_solar_day(day, 25.0, -70.0).day_length());
+        assert!(SunTimes::new(day, 181.0, 0.0).is_err());
+        assert!(SunTimes::new(day, 0.0, 91.0).is_err());
+    }
+
+    #[test]
+    fn test4() {
+        let t: SunTimes = SunTimes::from_solar_day(SolarDay::from_ymd(2024, 6, 21), 116.4, 39.9);
+        assert_eq!(RiseSetOptions::default(), t.get_options());
+        let geometric: usize = t.with_options(RiseSetOptions::geometric()).day_length();
+        assert!(geometric < t.day_length());
+        let high: SunTimes = t.with_options(RiseSetOptions::new(34.0, 16.0, 1000.0).unwrap());
+        assert!(high.day_length() > t.day_length());
+        assert!((-50.0 / 60.0 - RiseSetOptions::default().get_altitude().0).abs() < 1e-12);
+        assert!(RiseSetOptions::new(-1.0, 16.0, 0.0).is_err());
+    }
+
+    #[test]
+    fn test5() {
+        let t: SunTimes = SunTimes::from_observer(
+            SolarDay::from_ymd(2024, 6, 21),
+            Observer::from_degrees(116.4, 39.9),
+        );
+        assert_eq!("2024年6月21日 04:46:08", t.sunrise().unwrap().to_string());
+        assert_eq!("2024年6月21日 19:46:22", t.sunset().unwrap().to_string());
+        let polar: SunTimes = SunTimes::from_solar_day(SolarDay::from_ymd(2024, 6, 21), 25.0, 70.0);
+        assert!(polar.sunrise().is_none());
+        assert!(polar.sunset().is_none());
+        assert!(Observer::new(0.0, -91.0).is_err());
+    }
+
+    #[test]
+    fn test6() {
+        let summer: SolarDay = SolarDay::from_ymd(2024, 6, 21);
+        let winter: SolarDay = SolarDay::from_ymd(2024, 12, 21);
+        // 夏至太阳直射北回归线
+        assert!((90.0 - solar_noon_altitude(summer, 23.44).unwrap()).abs() < 0.01);
+        assert!((73.54 - solar_noon_altitude(summer, 39.9).unwrap()).abs() < 0.01);
+        // 登封观星台（北纬34.4°）八尺表：夏至影长约一尺五寸，冬至约一丈二尺七寸
+        let s: f64 = noon_shadow_length(summer, 34.4, 8.0).unwrap().unwrap();
+        assert!((1.55 - s).abs() < 0.01);
+        let w: f64 = noon_shadow_length(winter, 34.4, 8.0).unwrap().unwrap();
+        assert!((12.72 - w).abs() < 0.01);
+        assert_eq!(None, noon_shadow_length(winter, 80.0, 8.0).unwrap());
+        assert!(solar_noon_altitude(summer, 91.0).is_err());
+    }
+}
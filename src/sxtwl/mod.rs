@@ -1,20 +1,71 @@
 mod coefficients;
-mod generated_compressed_qishuo_correction_data;
+pub mod ephemeris;
+pub(crate) mod generated_compressed_qishuo_correction_data {
+    include!(concat!(
+        env!("OUT_DIR"),
+        "/generated_compressed_qishuo_correction_data.rs"
+    ));
+}
+pub mod sum;
+pub mod trig;
+pub mod units;
+
+#[cfg(feature = "external-ephemeris")]
+pub use crate::sxtwl::ephemeris::init_with_tables;
 
 use crate::sxtwl::coefficients::DT_AT;
-use crate::sxtwl::coefficients::XL1;
-use crate::sxtwl::coefficients::{NUT_B, QI_KB, SHUO_KB, XL0_0, XL1_0};
+use crate::sxtwl::coefficients::{QI_KB, SHUO_KB};
+use crate::sxtwl::ephemeris::Coefficients;
 
-use crate::sxtwl::generated_compressed_qishuo_correction_data::{get_qi_value, get_shuo_value};
+use crate::enums::Precision;
+use crate::lunar::RECOMMENDED_LUNAR_RANGE;
+use crate::sxtwl::generated_compressed_qishuo_correction_data::{
+    QI_LEN, QISHUO_SOURCE_HASH, SHUO_LEN, get_qi_value, get_shuo_value,
+};
+use crate::sxtwl::sum::{SeriesSum, Summation};
+use crate::sxtwl::units::{Days, Degrees, Radians, Seconds};
 
-use libm::{cos, floor, sin};
+use libm::{asin, atan2, cos, floor, sin, tan};
 
 use core::f64::consts::PI;
+use core::ops::Range;
 
 pub const PI_2: f64 = PI * 2.0;
 pub const ONE_THIRD: f64 = 1.0 / 3.0;
 const SECOND_PER_DAY: f64 = 86400.0;
 const SECOND_PER_RAD: f64 = 180.0 * 3600.0 / PI;
+/// 黄白交角（5.145°）
+const MOON_ORBIT_INCLINATION: f64 = 5.145 * PI / 180.0;
+/// 日食限（月亮距交点的角距）
+const SOLAR_ECLIPSE_LIMIT: f64 = 18.5 * PI / 180.0;
+/// 月食限（含半影月食，月亮距交点的角距）
+const LUNAR_ECLIPSE_LIMIT: f64 = 17.0 * PI / 180.0;
+/// 判定朔望时允许的日月黄经差偏离（约半天的月亮运动）
+const ECLIPSE_SYZYGY_TOLERANCE: f64 = 6.5 * PI / 180.0;
+/// 地球赤道半径（千米）
+pub const EARTH_EQUATORIAL_RADIUS: f64 = 6378.1366;
+/// 地球扁率
+pub const EARTH_FLATTENING: f64 = 1.0 / 298.257;
+/// 自此儒略日起定朔、定气改用高精度算法
+const HIGH_PRECISION_JD: f64 = 2436935.0;
+
+/// 朔、气修正表覆盖的儒略日范围：此前用拟合参数表，此后用高精度算法（气的修正自气拟合参数表末起）。更新修正表或拟合参数时须保证覆盖，否则编译失败
+pub const CORRECTION_TABLE_JD_RANGE: Range<f64> =
+    SHUO_KB[SHUO_KB.len() - 1] - 14.0..HIGH_PRECISION_JD;
+
+/// 朔、气修正表原始压缩字符串的哈希（FNV-1a 64位，十六进制），用于确认数据来源
+pub const CORRECTION_TABLE_SOURCE_HASH: &str = QISHUO_SOURCE_HASH;
+
+const _: () = {
+    let start: f64 = CORRECTION_TABLE_JD_RANGE.start;
+    let end: f64 = CORRECTION_TABLE_JD_RANGE.end;
+    let qi_start: f64 = QI_KB[QI_KB.len() - 1] - 7.0;
+    assert!(start < qi_start && qi_start < end);
+    assert!(SHUO_LEN as f64 * 29.5306 >= end - start);
+    assert!(QI_LEN as f64 * 365.2422 / 24.0 >= end - qi_start);
+    // 推荐年份不超出ΔT表末年外推100年
+    assert!(DT_AT[DT_AT.len() - 2] + 100.0 >= *RECOMMENDED_LUNAR_RANGE.end() as f64);
+};
 
 /// 寿星天文历工具
 pub struct Sxtwl {}
@@ -25,9 +76,11 @@ impl Sxtwl {
         let t2: f64 = t * t;
         let mut dl: f64 = 0.0;
         let mut i: usize = 0;
-        let size: usize = NUT_B.len();
+        let nut_b: Coefficients = ephemeris::nut_b();
+        let size: usize = nut_b.len();
         while i < size {
-            dl += (NUT_B[i + 3] + a) * sin(NUT_B[i] + NUT_B[i + 1] * t + NUT_B[i + 2] * t2);
+            dl += (nut_b.get(i + 3) + a)
+                * sin(nut_b.get(i) + nut_b.get(i + 1) * t + nut_b.get(i + 2) * t2);
             a = 0.0;
             i += 5;
         }
@@ -35,15 +88,33 @@ impl Sxtwl {
     }
 
     pub fn elon(pt: f64, n: isize) -> f64 {
+        Self::elon_with::<SeriesSum>(pt, n)
+    }
+
+    /// 地球日心黄经，级数以S累加
+    pub(crate) fn elon_with<S: Summation>(pt: f64, n: isize) -> f64 {
         let t: f64 = pt / 10.0;
+        let mut v: f64 = Self::e_coord::<S>(t, 1, n);
+        let t2: f64 = t * t;
+        v += (-0.0728 - 2.7702 * t - 1.1019 * t2 - 0.0996 * t2 * t) / SECOND_PER_RAD;
+        v
+    }
+
+    /// 地球日心黄纬（参数为J2000起算的儒略世纪数）
+    pub fn elat(pt: f64, n: isize) -> f64 {
+        Self::e_coord::<SeriesSum>(pt / 10.0, 7, n)
+    }
+
+    /// XL0中地球的某个坐标级数（pn为位置索引表中该坐标的起始下标，t为儒略千年数）
+    fn e_coord<S: Summation>(t: f64, pn: usize, n: isize) -> f64 {
         let mut v: f64 = 0.0;
         let mut tn: f64 = 1.0;
         let mut m: usize;
-        let pn: usize = 1;
-        let m0: f64 = XL0_0[pn + 1] - XL0_0[pn];
+        let xl0: Coefficients = ephemeris::xl0();
+        let m0: f64 = xl0.get(pn + 1) - xl0.get(pn);
         for i in 0..6 {
-            let n1: usize = XL0_0[pn + i] as usize;
-            let n2: usize = XL0_0[pn + 1 + i] as usize;
+            let n1: usize = xl0.get(pn + i) as usize;
+            let n2: usize = xl0.get(pn + 1 + i) as usize;
             let n0: f64 = (n2 - n1) as f64;
             if n0 == 0.0 {
                 continue;
@@ -59,23 +130,26 @@ impl Sxtwl {
                     m = n2;
                 }
             }
-            let mut c: f64 = 0.0;
+            let mut c: S = S::default();
             let mut j: usize = n1;
             while j < m {
-                c += XL0_0[j] * cos(XL0_0[j + 1] + t * XL0_0[j + 2]);
+                c.add(xl0.get(j) * cos(xl0.get(j + 1) + t * xl0.get(j + 2)));
                 j += 3;
             }
-            v += c * tn;
+            v += c.get() * tn;
             tn *= t;
         }
-        v /= XL0_0[0];
-        let t2: f64 = t * t;
-        v += (-0.0728 - 2.7702 * t - 1.1019 * t2 - 0.0996 * t2 * t) / SECOND_PER_RAD;
-        v
+        v / xl0.get(0)
     }
 
     pub fn mlon(t: f64, pn: isize) -> f64 {
-        let obl: isize = XL1[0].len() as isize;
+        Self::mlon_with::<SeriesSum>(t, pn)
+    }
+
+    /// 月亮地心黄经，级数以S累加
+    pub(crate) fn mlon_with<S: Summation>(t: f64, pn: isize) -> f64 {
+        let xl1: [Coefficients; 4] = ephemeris::xl1();
+        let obl: isize = xl1.len() as isize;
         let mut tn: f64 = 1.0;
         let mut v: f64 = 0.0;
         let mut t2: f64 = t * t;
@@ -99,8 +173,7 @@ impl Sxtwl {
         if n < 0 {
             n = obl;
         }
-        for i in 0..XL1_0.len() {
-            let f = XL1_0[i];
+        for (i, f) in xl1.iter().enumerate() {
             let l: usize = f.len();
             let mut m: usize = (((n * (l as isize) / obl) as f64) + 0.5) as usize;
             if i > 0 {
@@ -109,20 +182,86 @@ impl Sxtwl {
             if m >= l {
                 m = l;
             }
-            let mut c: f64 = 0.0;
+            let mut c: S = S::default();
             let mut j: usize = 0;
             while j < m {
-                c += f[j]
-                    * cos(f[j + 1] + t * f[j + 2] + t2 * f[j + 3] + t3 * f[j + 4] + t4 * f[j + 5]);
+                c.add(
+                    f.get(j)
+                        * cos(f.get(j + 1)
+                            + t * f.get(j + 2)
+                            + t2 * f.get(j + 3)
+                            + t3 * f.get(j + 4)
+                            + t4 * f.get(j + 5)),
+                );
                 j += 6;
             }
-            v += c * tn;
+            v += c.get() * tn;
             tn *= t;
         }
         v /= SECOND_PER_RAD;
         v
     }
 
+    /// 月亮地心黄纬（弧度，t为J2000起算的儒略世纪数，pn为截断项数，负数取全部项）
+    pub fn mlat(t: f64, pn: isize) -> f64 {
+        Self::mlat_with::<SeriesSum>(t, pn)
+    }
+
+    /// 月亮地心黄纬，级数以S累加
+    pub(crate) fn mlat_with<S: Summation>(t: f64, pn: isize) -> f64 {
+        Self::m_series::<S>(&ephemeris::xl1_lat(), t, pn) / SECOND_PER_RAD
+    }
+
+    /// 月地距离（千米，地心到月心，t为J2000起算的儒略世纪数，pn为截断项数，负数取全部项）
+    pub fn mdist(t: f64, pn: isize) -> f64 {
+        Self::mdist_with::<SeriesSum>(t, pn)
+    }
+
+    /// 月地距离，级数以S累加
+    pub(crate) fn mdist_with<S: Summation>(t: f64, pn: isize) -> f64 {
+        Self::m_series::<S>(&ephemeris::xl1_dist(), t, pn)
+    }
+
+    /// XL1黄纬、距离级数之和（各分表依次乘t的幂），截断项数按首个分表的比例分配
+    fn m_series<S: Summation>(xl1: &[Coefficients], t: f64, pn: isize) -> f64 {
+        let obl: isize = xl1[0].len() as isize;
+        let t2: f64 = t * t / 1e4;
+        let t3: f64 = t * t * t / 1e8;
+        let t4: f64 = t * t * t * t / 1e8;
+        let mut n: isize = pn * 6;
+        if n < 0 {
+            n = obl;
+        }
+        let mut tn: f64 = 1.0;
+        let mut v: f64 = 0.0;
+        for (i, f) in xl1.iter().enumerate() {
+            let l: usize = f.len();
+            let mut m: usize = (((n * (l as isize) / obl) as f64) + 0.5) as usize;
+            if i > 0 {
+                m += 6;
+            }
+            if m >= l {
+                m = l;
+            }
+            let mut c: S = S::default();
+            let mut j: usize = 0;
+            while j < m {
+                c.add(
+                    f.get(j)
+                        * cos(f.get(j + 1)
+                            + t * f.get(j + 2)
+                            + t2 * f.get(j + 3)
+                            + t3 * f.get(j + 4)
+                            + t4 * f.get(j + 5)),
+                );
+                j += 6;
+            }
+            v += c.get() * tn;
+            tn *= t;
+        }
+        v
+    }
+
     pub fn gxc_sun_lon(t: f64) -> f64 {
         let t2: f64 = t * t;
         let v: f64 = -0.043126 + 628.301955 * t - 0.000002732 * t2;
@@ -279,18 +418,18 @@ impl Sxtwl {
         let v: f64 = 628.3319653318;
         let mut t: f64 = (w - 4.895062166) / v;
         t -= (53.0 * t * t
-            + 334116.0 * cos(4.67 + 628.307585 * t)
-            + 2061.0 * cos(2.678 + 628.3076 * t) * t)
+            + 334116.0 * trig::cos(4.67 + 628.307585 * t)
+            + 2061.0 * trig::cos(2.678 + 628.3076 * t) * t)
             / v
             / 10000000.0;
         let n: f64 = 48950621.66
             + 6283319653.318 * t
             + 53.0 * t * t
-            + 334166.0 * cos(4.669257 + 628.307585 * t)
-            + 3489.0 * cos(4.6261 + 1256.61517 * t)
-            + 2060.6 * cos(2.67823 + 628.307585 * t) * t
+            + 334166.0 * trig::cos(4.669257 + 628.307585 * t)
+            + 3489.0 * trig::cos(4.6261 + 1256.61517 * t)
+            + 2060.6 * trig::cos(2.67823 + 628.307585 * t) * t
             - 994.0
-            - 834.0 * sin(2.1824 - 33.75705 * t);
+            - 834.0 * trig::sin(2.1824 - 33.75705 * t);
         t -= (n / 10000000.0 - w) / 628.332
             + (32.0 * (t + 1.8) * (t + 1.8) - 20.0) / SECOND_PER_DAY / 36525.0;
         t * 36525.0 + ONE_THIRD
@@ -300,9 +439,9 @@ impl Sxtwl {
         let v: f64 = 7771.37714500204;
         let mut t: f64 = (w + 1.08472) / v;
         t -= (-0.0000331 * t * t
-            + 0.10976 * cos(0.785 + 8328.6914 * t)
-            + 0.02224 * cos(0.187 + 7214.0629 * t)
-            - 0.03342 * cos(4.669 + 628.3076 * t))
+            + 0.10976 * trig::cos(0.785 + 8328.6914 * t)
+            + 0.02224 * trig::cos(0.187 + 7214.0629 * t)
+            - 0.03342 * trig::cos(4.669 + 628.3076 * t))
             / v
             + (32.0 * (t + 1.8) * (t + 1.8) - 20.0) / SECOND_PER_DAY / 36525.0;
         t * 36525.0 + ONE_THIRD
@@ -316,7 +455,7 @@ impl Sxtwl {
         let jd: f64 = pjd + 2451545.0;
         let f1: f64 = SHUO_KB[0] - pc;
         let f2: f64 = SHUO_KB[size - 1] - pc;
-        let f3: f64 = 2436935.0;
+        let f3: f64 = HIGH_PRECISION_JD;
         if jd < f1 || jd >= f3 {
             d = floor(Self::shuo_high(floor((jd + pc - 2451551.0) / 29.5306) * PI_2) + 0.5);
         } else if jd >= f1 && jd < f2 {
@@ -354,7 +493,7 @@ impl Sxtwl {
         let jd: f64 = pjd + 2451545.0;
         let f1: f64 = QI_KB[0] - pc;
         let f2: f64 = QI_KB[size - 1] - pc;
-        let f3: f64 = 2436935.0;
+        let f3: f64 = HIGH_PRECISION_JD;
         if jd < f1 || jd >= f3 {
             d = floor(
                 Self::qi_high(floor((jd + pc - 2451259.0) / 365.2422 * 24.0) * PI / 12.0) + 0.5,
@@ -392,16 +531,163 @@ impl Sxtwl {
         t - Self::dtt(t) + ONE_THIRD
     }
 
+    /// 按精度计算太阳视黄经（自J2000起累计）到达w的时刻，返回J2000起算的北京时间儒略日数
+    pub fn qi_with_precision(w: f64, precision: Precision) -> f64 {
+        match precision {
+            Precision::FAST => Self::qi_low(w),
+            Precision::STANDARD => Self::qi_high(w),
+            Precision::HIGH => Self::qi_accurate(w),
+        }
+    }
+
+    /// 按精度计算月日视黄经差（自J2000起累计）到达w的时刻，返回J2000起算的北京时间儒略日数
+    pub fn shuo_with_precision(w: f64, precision: Precision) -> f64 {
+        match precision {
+            Precision::FAST => Self::shuo_low(w),
+            Precision::STANDARD => Self::shuo_high(w),
+            Precision::HIGH => {
+                let t: f64 = Self::m_sa_lon_t(w) * 36525.0;
+                t - Self::dtt(t) + ONE_THIRD
+            }
+        }
+    }
+
+    /// 太阳视黄经（参数为J2000起算的力学时儒略日数）
+    pub fn sun_apparent_longitude(jd: Days) -> Radians {
+        Radians(Self::sa_lon(jd.0 / 36525.0, -1))
+    }
+
+    /// 太阳地心黄纬，即地球日心黄纬取反（参数为J2000起算的力学时儒略日数）
+    pub fn sun_latitude(jd: Days) -> Radians {
+        Radians(-Self::elat(jd.0 / 36525.0, -1))
+    }
+
+    /// 月亮视黄经（参数为J2000起算的力学时儒略日数）
+    pub fn moon_apparent_longitude(jd: Days) -> Radians {
+        let t: f64 = jd.0 / 36525.0;
+        Radians(Self::mlon(t, -1) + (-3.4E-6) + Self::nutation_lon2(t))
+    }
+
+    /// 月亮地心黄纬（参数为J2000起算的力学时儒略日数）
+    pub fn moon_latitude(jd: Days) -> Radians {
+        Radians(Self::mlat(jd.0 / 36525.0, -1))
+    }
+
+    /// 朔或望时刻（参数为J2000起算的力学时儒略日数）是否可能发生日食或月食：
+    /// 由月亮黄纬推算其距黄白交点的角距，朔时在日食限（18.5°）内、望时在月食限（含半影，17°）内即可能发生
+    pub fn is_eclipse_possible(jd: Days) -> bool {
+        let elongation: f64 =
+            Self::moon_apparent_longitude(jd).0 - Self::sun_apparent_longitude(jd).0;
+        let elongation: f64 = elongation - floor(elongation / PI_2 + 0.5) * PI_2;
+        let limit: f64 = if elongation.abs() < ECLIPSE_SYZYGY_TOLERANCE {
+            SOLAR_ECLIPSE_LIMIT
+        } else if PI - elongation.abs() < ECLIPSE_SYZYGY_TOLERANCE {
+            LUNAR_ECLIPSE_LIMIT
+        } else {
+            return false;
+        };
+        let node_distance: f64 = asin(
+            (sin(Self::moon_latitude(jd).0) / sin(MOON_ORBIT_INCLINATION))
+                .abs()
+                .min(1.0),
+        );
+        node_distance < limit
+    }
+
+    /// 太阳视黄经（自J2000起累计）到达w的时刻，返回J2000起算的北京时间儒略日数
+    pub fn sun_longitude_time(w: Radians) -> Days {
+        Days(Self::qi_accurate(w.0))
+    }
+
+    /// ΔT（参数为J2000起算的儒略日数）
+    pub fn delta_t(jd: Days) -> Seconds {
+        Seconds(Self::dt_calc(jd.0 / 365.2425 + 2000.0))
+    }
+
+    /// 真黄赤交角（弧度，含交角章动，参数为J2000起算的儒略世纪数）
+    pub fn obliquity(t: f64) -> f64 {
+        let t2: f64 = t * t;
+        let t3: f64 = t2 * t;
+        (84381.406 - 46.836769 * t - 0.0001831 * t2 + 0.0020034 * t3
+            - 0.000000576 * t2 * t2
+            - 0.0000000434 * t3 * t2
+            + 9.2 * cos(2.1824 - 33.75705 * t))
+            / SECOND_PER_RAD
+    }
+
+    /// 太阳视赤经、赤纬（弧度，参数为J2000起算的力学时儒略日数）
+    pub fn sun_equatorial(jd: Days) -> (Radians, Radians) {
+        let t: f64 = jd.0 / 36525.0;
+        let e: f64 = Self::obliquity(t);
+        let l: f64 = Self::sa_lon(t, 50);
+        let ra: f64 = atan2(sin(l) * cos(e), cos(l));
+        (Radians(ra), Radians(asin(sin(e) * sin(l))))
+    }
+
+    /// 月亮视赤经、赤纬（弧度，参数为J2000起算的力学时儒略日数）
+    pub fn moon_equatorial(jd: Days) -> (Radians, Radians) {
+        let e: f64 = Self::obliquity(jd.0 / 36525.0);
+        let l: f64 = Self::moon_apparent_longitude(jd).0;
+        let b: f64 = Self::moon_latitude(jd).0;
+        let ra: f64 = atan2(sin(l) * cos(e) - tan(b) * sin(e), cos(l));
+        let dec: f64 = asin(sin(b) * cos(e) + cos(b) * sin(e) * sin(l));
+        (Radians(ra), Radians(dec))
+    }
+
+    /// 月地距离（千米，地心到月心，参数为J2000起算的力学时儒略日数）
+    pub fn moon_distance(jd: Days) -> f64 {
+        Self::mdist(jd.0 / 36525.0, -1)
+    }
+
+    /// 月亮地平视差（弧度，参数为J2000起算的力学时儒略日数）
+    pub fn moon_horizontal_parallax(jd: Days) -> Radians {
+        Radians(asin(EARTH_EQUATORIAL_RADIUS / Self::moon_distance(jd)))
+    }
+
+    /// 格林尼治平恒星时（弧度，参数为J2000起算的世界时儒略日数）
+    pub fn mean_sidereal_time(jd: Days) -> Radians {
+        let d: f64 = 280.46061837 + 360.98564736629 * jd.0;
+        Degrees(d - floor(d / 360.0) * 360.0).into()
+    }
+
+    /// 时差，即真太阳时减平太阳时（参数为J2000起算的力学时儒略日数）
+    pub fn equation_of_time(jd: Days) -> Days {
+        let t: f64 = jd.0 / 36525.0;
+        let t2: f64 = t * t;
+        let t3: f64 = t2 * t;
+        let dl: f64 = -17.2 * sin(2.1824 - 33.75705 * t) / SECOND_PER_RAD;
+        let l: f64 = (1753470142.0 + 628331965331.8 * t + 5296.74 * t2 + 0.432 * t3
+            - 0.1124 * t2 * t2
+            - 0.00009 * t3 * t2)
+            / 1000000000.0
+            + PI
+            - 20.5 / SECOND_PER_RAD;
+        let (ra, _) = Self::sun_equatorial(jd);
+        let mut v: f64 = (l - ra.0 + dl * cos(Self::obliquity(t))) % (2.0 * PI);
+        if v < 0.0 {
+            v += 2.0 * PI;
+        }
+        if v > PI {
+            v -= 2.0 * PI;
+        }
+        Days(v / (2.0 * PI))
+    }
+
     pub fn qi_accurate2(jd: f64) -> f64 {
+        Self::qi_accurate(Self::qi_lon(jd))
+    }
+
+    /// 离jd最近的节气对应的太阳视黄经（弧度，自J2000起累计）
+    pub fn qi_lon(jd: f64) -> f64 {
         let d: f64 = PI / 12.0;
         let w: f64 = floor((jd + 293.0) / 365.2422 * 24.0) * d;
         let a: f64 = Self::qi_accurate(w);
         if a - jd > 5.0 {
-            return Self::qi_accurate(w - d);
+            return w - d;
         }
         if a - jd < -5.0 {
-            return Self::qi_accurate(w + d);
+            return w + d;
         }
-        a
+        w
     }
 }
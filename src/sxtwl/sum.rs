@@ -0,0 +1,156 @@
+//! 级数求和
+
+/// 级数累加器：启用compensated-sum时为Neumaier补偿求和，减少数千项累加的舍入误差，否则为普通加法
+#[derive(Debug, Copy, Clone, Default)]
+pub struct SeriesSum {
+    /// 和
+    sum: f64,
+    /// 补偿项（未启用compensated-sum时恒为0）
+    compensation: f64,
+}
+
+impl SeriesSum {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 累加一项
+    #[cfg(feature = "compensated-sum")]
+    #[inline]
+    pub fn add(&mut self, x: f64) {
+        let t: f64 = self.sum + x;
+        if self.sum.abs() >= x.abs() {
+            self.compensation += (self.sum - t) + x;
+        } else {
+            self.compensation += (x - t) + self.sum;
+        }
+        self.sum = t;
+    }
+
+    /// 累加一项
+    #[cfg(not(feature = "compensated-sum"))]
+    #[inline]
+    pub fn add(&mut self, x: f64) {
+        self.sum += x;
+    }
+
+    /// 和
+    pub fn get(&self) -> f64 {
+        self.sum + self.compensation
+    }
+}
+
+/// 级数累加方式，天文级数按此累加（默认为SeriesSum），便于以普通加法对照补偿求和
+pub trait Summation: Default {
+    /// 累加一项
+    fn add(&mut self, x: f64);
+
+    /// 和
+    fn get(&self) -> f64;
+}
+
+impl Summation for SeriesSum {
+    #[inline]
+    fn add(&mut self, x: f64) {
+        SeriesSum::add(self, x);
+    }
+
+    fn get(&self) -> f64 {
+        SeriesSum::get(self)
+    }
+}
+
+/// 普通加法累加器（不论是否启用compensated-sum）
+#[derive(Debug, Copy, Clone, Default)]
+pub struct NaiveSum {
+    /// 和
+    sum: f64,
+}
+
+impl Summation for NaiveSum {
+    #[inline]
+    fn add(&mut self, x: f64) {
+        self.sum += x;
+    }
+
+    fn get(&self) -> f64 {
+        self.sum
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::jd::J2000;
+    use crate::solar::SolarDay;
+    use crate::sxtwl::Sxtwl;
+    use crate::sxtwl::sum::{NaiveSum, SeriesSum};
+    use crate::types::Tyme;
+
+    #[test]
+    fn test1() {
+        let mut s: SeriesSum = SeriesSum::new();
+        for x in [0.1; 10] {
+            s.add(x);
+        }
+        assert!((s.get() - 1.0).abs() < 1e-15);
+    }
+
+    #[cfg(feature = "compensated-sum")]
+    #[test]
+    fn test2() {
+        let mut s: SeriesSum = SeriesSum::new();
+        for x in [1.0, 1e100, 1.0, -1e100] {
+            s.add(x);
+        }
+        assert_eq!(2.0, s.get());
+    }
+
+    #[cfg(feature = "compensated-sum")]
+    #[test]
+    fn test3() {
+        // 0.1的double值为0.1000000000000000055511151231257827…，累加10⁶次的精确和舍入后为100000.0，普通加法为100000.00000133288
+        let mut s: SeriesSum = SeriesSum::new();
+        let mut naive: f64 = 0.0;
+        for _ in 0..1_000_000 {
+            s.add(0.1);
+            naive += 0.1;
+        }
+        assert_eq!(100000.0, s.get());
+        assert_ne!(100000.0, naive);
+
+        // 大数相消：每组1e20、1、-1e20，精确和为组数
+        let mut s: SeriesSum = SeriesSum::new();
+        for _ in 0..1000 {
+            for x in [1e20, 1.0, -1e20] {
+                s.add(x);
+            }
+        }
+        assert_eq!(1000.0, s.get());
+    }
+
+    #[test]
+    fn test4() {
+        // 公元1000年至3000年每101日：补偿求和与普通加法之差在双精度舍入量级（黄经数千弧度、月地距离数十万千米的相对误差约1e-15），
+        // 远小于星历本身的精度；未启用compensated-sum时两者逐位相同
+        let (elon, mlon, mlat, mdist): (f64, f64, f64, f64) = if cfg!(feature = "compensated-sum") {
+            (1e-10, 1e-10, 1e-14, 1e-9)
+        } else {
+            (0.0, 0.0, 0.0, 0.0)
+        };
+        let mut day: SolarDay = SolarDay::from_ymd(1000, 1, 1);
+        let end: SolarDay = SolarDay::from_ymd(3000, 1, 1);
+        while day.is_before(end) {
+            let t: f64 = (day.get_julian_day().get_day() - J2000) / 36525.0;
+            let d: f64 = Sxtwl::elon_with::<SeriesSum>(t, -1) - Sxtwl::elon_with::<NaiveSum>(t, -1);
+            assert!(d.abs() <= elon, "elon {} {}", day, d);
+            let d: f64 = Sxtwl::mlon_with::<SeriesSum>(t, -1) - Sxtwl::mlon_with::<NaiveSum>(t, -1);
+            assert!(d.abs() <= mlon, "mlon {} {}", day, d);
+            let d: f64 = Sxtwl::mlat_with::<SeriesSum>(t, -1) - Sxtwl::mlat_with::<NaiveSum>(t, -1);
+            assert!(d.abs() <= mlat, "mlat {} {}", day, d);
+            let d: f64 =
+                Sxtwl::mdist_with::<SeriesSum>(t, -1) - Sxtwl::mdist_with::<NaiveSum>(t, -1);
+            assert!(d.abs() <= mdist, "mdist {} {}", day, d);
+            day = day.next(101);
+        }
+    }
+}
@@ -0,0 +1,65 @@
+//! 低精度气朔（qi_low、shuo_low）所用的正弦、余弦
+//!
+//! 启用trig-lut时以四分之一周期的定点查找表线性插值，不调用libm；查表误差不超过3e-7，
+//! 公元1600年至2400年间低精度气朔时刻的偏差不超过0.1秒（仅当时刻距日界0.1秒以内时取整后的日期才可能不同）。未启用时即libm。
+
+#[cfg(not(feature = "trig-lut"))]
+pub use libm::{cos, sin};
+
+#[cfg(feature = "trig-lut")]
+mod generated_sin_table {
+    include!(concat!(env!("OUT_DIR"), "/generated_sin_table.rs"));
+}
+
+#[cfg(feature = "trig-lut")]
+use generated_sin_table::{FRACTION_BITS, QUARTER_STEPS, SIN_TABLE};
+
+/// 正弦（查表插值）
+#[cfg(feature = "trig-lut")]
+pub fn sin(x: f64) -> f64 {
+    let turn: usize = QUARTER_STEPS * 4;
+    let mut u: f64 = x * (turn as f64 / crate::sxtwl::PI_2);
+    let mut k: i64 = u as i64;
+    if k as f64 > u {
+        k -= 1;
+    }
+    u -= k as f64;
+    let i: usize = k.rem_euclid(turn as i64) as usize;
+    let a: f64 = lookup(i);
+    a + (lookup(i + 1) - a) * u
+}
+
+/// 余弦（查表插值）
+#[cfg(feature = "trig-lut")]
+pub fn cos(x: f64) -> f64 {
+    sin(x + core::f64::consts::FRAC_PI_2)
+}
+
+/// 第i个分点（一周共4·QUARTER_STEPS个）的正弦
+#[cfg(feature = "trig-lut")]
+fn lookup(i: usize) -> f64 {
+    let q: usize = QUARTER_STEPS;
+    let i: usize = i % (q * 4);
+    let v: i32 = match i / q {
+        0 => SIN_TABLE[i],
+        1 => SIN_TABLE[2 * q - i],
+        2 => -SIN_TABLE[i - 2 * q],
+        _ => -SIN_TABLE[4 * q - i],
+    };
+    v as f64 / (1u64 << FRACTION_BITS) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sxtwl::trig::{cos, sin};
+
+    #[test]
+    fn test1() {
+        let mut x: f64 = -20000.0;
+        while x < 20000.0 {
+            assert!((sin(x) - libm::sin(x)).abs() < 3e-7, "{}", x);
+            assert!((cos(x) - libm::cos(x)).abs() < 3e-7, "{}", x);
+            x += 0.37;
+        }
+    }
+}
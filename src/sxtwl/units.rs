@@ -0,0 +1,80 @@
+use core::f64::consts::PI;
+
+/// 弧度
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct Radians(pub f64);
+
+/// 角度
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct Degrees(pub f64);
+
+/// 天
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct Days(pub f64);
+
+/// 秒
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct Seconds(pub f64);
+
+impl From<Degrees> for Radians {
+    fn from(d: Degrees) -> Self {
+        Radians(d.0 * PI / 180.0)
+    }
+}
+
+impl From<Radians> for Degrees {
+    fn from(r: Radians) -> Self {
+        Degrees(r.0 * 180.0 / PI)
+    }
+}
+
+impl From<Days> for Seconds {
+    fn from(d: Days) -> Self {
+        Seconds(d.0 * 86400.0)
+    }
+}
+
+impl From<Seconds> for Days {
+    fn from(s: Seconds) -> Self {
+        Days(s.0 / 86400.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f64::consts::PI;
+
+    use crate::jd::{J2000, JulianDay};
+    use crate::solar::SolarTime;
+    use crate::sxtwl::ephemeris::assert_time;
+    use crate::sxtwl::units::{Days, Degrees, Radians, Seconds};
+    use crate::sxtwl::{ONE_THIRD, Sxtwl};
+
+    #[test]
+    fn test1() {
+        assert_eq!(Radians(PI), Degrees(180.0).into());
+        assert_eq!(Degrees(90.0), Radians(PI / 2.0).into());
+        assert_eq!(Seconds(43200.0), Days(0.5).into());
+        assert_eq!(Days(2.0), Seconds(172800.0).into());
+    }
+
+    #[test]
+    fn test2() {
+        // 2000年ΔT约64秒
+        let dt: Seconds = Sxtwl::delta_t(Days(0.0));
+        assert!(dt.0 > 63.0 && dt.0 < 65.0);
+    }
+
+    #[test]
+    fn test3() {
+        // 2024年春分，太阳视黄经累计25圈
+        let jd: Days = Sxtwl::sun_longitude_time(Degrees(25.0 * 360.0).into());
+        assert_time(
+            SolarTime::from_ymd_hms(2024, 3, 20, 11, 6, 25),
+            JulianDay::from_julian_day(jd.0 + J2000).get_solar_time(),
+        );
+        let tt: Days = Days(jd.0 - ONE_THIRD + Sxtwl::dtt(jd.0));
+        let lon: Degrees = Sxtwl::sun_apparent_longitude(tt).into();
+        assert!((lon.0 - 25.0 * 360.0).abs() < 1e-4);
+    }
+}
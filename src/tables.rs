@@ -0,0 +1,158 @@
+//! 内置数据表的体积统计，便于评估嵌入式环境的Flash占用
+
+use core::mem::size_of_val;
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+#[cfg(feature = "god")]
+use crate::culture::generated_day_god_data::DAY_GODS_TABLE;
+#[cfg(feature = "era")]
+use crate::generated_era_data::ERA_TABLE;
+#[cfg(feature = "farming")]
+use crate::generated_farming_data::FARMING_TABLE;
+#[cfg(feature = "holiday")]
+use crate::generated_holidays_data::LEGAL_HOLIDAY_TABLE;
+use crate::generated_leap_year_data::LEAP_MONTH_YEAR_DATA;
+#[cfg(feature = "rabbyung")]
+use crate::generated_rab_byung::RAB_BYUNG_DATA;
+use crate::sxtwl::ephemeris;
+use crate::sxtwl::generated_compressed_qishuo_correction_data::{QI_BYTES, SHUO_BYTES};
+
+/// 数据表体积
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TableSize {
+    /// 数据表名称
+    name: &'static str,
+    /// 字节数（按内存布局计，含切片及字符串引用的数据）
+    bytes: usize,
+}
+
+impl TableSize {
+    /// 数据表名称
+    pub fn get_name(&self) -> &'static str {
+        self.name
+    }
+
+    /// 字节数
+    pub fn get_bytes(&self) -> usize {
+        self.bytes
+    }
+}
+
+/// 已启用的各内置数据表体积；星历系数表可通过external-ephemeris改为运行时加载（仅编入加载前所用的精简表），节假日、藏历、神煞、年号、农事数据表可通过关闭对应特性排除
+pub fn get_table_sizes() -> Vec<TableSize> {
+    // 其余数据表随特性追加，全部关闭时无追加
+    #[allow(unused_mut)]
+    let mut l: Vec<TableSize> = vec![
+        TableSize {
+            name: "ephemeris",
+            bytes: ephemeris::embedded_size(),
+        },
+        TableSize {
+            name: "qishuo",
+            bytes: size_of_val(SHUO_BYTES) + size_of_val(QI_BYTES),
+        },
+        TableSize {
+            name: "leap_month",
+            bytes: size_of_val(LEAP_MONTH_YEAR_DATA)
+                + LEAP_MONTH_YEAR_DATA
+                    .iter()
+                    .map(|d| size_of_val(*d))
+                    .sum::<usize>(),
+        },
+    ];
+    #[cfg(feature = "holiday")]
+    l.push(TableSize {
+        name: "holiday",
+        bytes: size_of_val(&LEGAL_HOLIDAY_TABLE),
+    });
+    #[cfg(feature = "rabbyung")]
+    l.push(TableSize {
+        name: "rabbyung",
+        bytes: size_of_val(RAB_BYUNG_DATA)
+            + RAB_BYUNG_DATA
+                .iter()
+                .map(|d| size_of_val(d.days))
+                .sum::<usize>(),
+    });
+    #[cfg(feature = "god")]
+    l.push(TableSize {
+        name: "day_god",
+        bytes: size_of_val(&DAY_GODS_TABLE)
+            + DAY_GODS_TABLE
+                .iter()
+                .flatten()
+                .flatten()
+                .map(|d| size_of_val(*d))
+                .sum::<usize>(),
+    });
+    #[cfg(feature = "era")]
+    l.push(TableSize {
+        name: "era",
+        bytes: size_of_val(&ERA_TABLE)
+            + ERA_TABLE
+                .iter()
+                .map(|e| e.dynasty.len() + e.name.len())
+                .sum::<usize>(),
+    });
+    #[cfg(feature = "farming")]
+    l.push(TableSize {
+        name: "farming",
+        bytes: size_of_val(&FARMING_TABLE)
+            + FARMING_TABLE
+                .iter()
+                .map(|e| {
+                    e.advice.len()
+                        + size_of_val(e.proverbs)
+                        + e.proverbs.iter().map(|p| p.len()).sum::<usize>()
+                })
+                .sum::<usize>(),
+    });
+    l
+}
+
+/// 已启用的内置数据表总字节数
+pub fn get_total_table_bytes() -> usize {
+    get_table_sizes().iter().map(|t| t.bytes).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tables::{TableSize, get_table_sizes, get_total_table_bytes};
+
+    /// 各数据表的体积上限（64位目标），超出时说明数据或编码方式发生了变化，需确认后调整
+    static BUDGETS: [(&str, usize); 8] = [
+        ("ephemeris", 79000),
+        ("qishuo", 6500),
+        ("leap_month", 32000),
+        ("holiday", 9500),
+        ("rabbyung", 52000),
+        ("day_god", 21000),
+        ("era", 21500),
+        ("farming", 4000),
+    ];
+
+    #[test]
+    fn test1() {
+        let sizes = get_table_sizes();
+        for size in sizes.iter() {
+            let budget: usize = BUDGETS
+                .iter()
+                .find(|(name, _)| *name == size.get_name())
+                .unwrap()
+                .1;
+            assert!(
+                size.get_bytes() <= budget,
+                "{} table is {} bytes, budget {}",
+                size.get_name(),
+                size.get_bytes(),
+                budget
+            );
+        }
+        assert_eq!(
+            get_total_table_bytes(),
+            sizes.iter().map(TableSize::get_bytes).sum::<usize>()
+        );
+    }
+}
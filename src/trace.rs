@@ -0,0 +1,318 @@
+//! 月序判定过程：列出农历年各月的朔日、所含中气及闰月判定，便于排查“闰月不对”一类问题；
+//! 并报告换算回退为估算结果（lunar::Resolution::Approximate）的情形
+
+use core::fmt::{Display, Formatter};
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::cache::Lock;
+use crate::lunar::{LunarMonth, LunarYear};
+use crate::solar::{SolarDay, SolarTerm};
+use crate::types::{Culture, Tyme};
+
+/// 换算回退为估算结果时的回调，参数为回退原因
+static FALLBACK_HOOK: Lock<Option<fn(&str)>> = Lock::new(None);
+
+/// 设置换算回退为估算结果时的回调（如写入日志），None为取消
+pub fn set_fallback_hook(hook: Option<fn(&str)>) {
+    FALLBACK_HOOK.with(|h| *h = hook);
+}
+
+/// 报告回退
+pub(crate) fn report_fallback(reason: &str) {
+    // 回调在锁外执行；调用方（如自定义事件的规则匹配）也不持锁，single-thread下不会在临界区内回调
+    if let Some(hook) = FALLBACK_HOOK.with(|h| *h) {
+        hook(reason);
+    }
+}
+
+/// 单月的判定过程
+#[derive(Debug, Clone)]
+pub struct MonthTrace {
+    /// 农历月
+    month: LunarMonth,
+    /// 朔日（初一）
+    first_day: SolarDay,
+    /// 月内的中气（名称, 公历日）
+    major_terms: Vec<(String, SolarDay)>,
+}
+
+impl MonthTrace {
+    /// 农历月
+    pub fn get_lunar_month(&self) -> LunarMonth {
+        self.month
+    }
+
+    /// 朔日（初一）
+    pub fn get_first_day(&self) -> SolarDay {
+        self.first_day
+    }
+
+    /// 月内的中气（名称, 公历日），无中气时为空
+    pub fn get_major_terms(&self) -> Vec<(String, SolarDay)> {
+        self.major_terms.clone()
+    }
+
+    /// 是否无中气
+    pub fn is_without_major_term(&self) -> bool {
+        self.major_terms.is_empty()
+    }
+}
+
+impl Display for MonthTrace {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let terms: Vec<String> = self
+            .major_terms
+            .iter()
+            .map(|(name, day)| format!("{}{}", name, day))
+            .collect();
+        write!(
+            f,
+            "{} 朔{} {}天 {}",
+            self.month.get_name(),
+            self.first_day,
+            self.month.get_day_count(),
+            if terms.is_empty() {
+                String::from("无中气")
+            } else {
+                terms.join("、")
+            }
+        )
+    }
+}
+
+/// 农历年的月序判定过程
+#[derive(Debug, Clone)]
+pub struct MonthNamingTrace {
+    /// 农历年
+    year: LunarYear,
+    /// 各月（含闰月）
+    months: Vec<MonthTrace>,
+    /// 各月按无中气置闰是否应为闰月，与months一一对应
+    expected_leaps: Vec<bool>,
+}
+
+impl MonthNamingTrace {
+    pub fn new(year: isize) -> Result<Self, String> {
+        let year: LunarYear = LunarYear::new(year)?;
+        // 连同上年、下年各月，使本年各月都落在完整的冬至区间内
+        let prev: Vec<LunarMonth> = LunarYear::new(year.get_year() - 1)
+            .map(|y| y.get_months())
+            .unwrap_or_default();
+        let next: Vec<LunarMonth> = LunarYear::new(year.get_year() + 1)
+            .map(|y| y.get_months())
+            .unwrap_or_default();
+        let offset: usize = prev.len();
+        let count: usize = year.get_months().len();
+        let months: Vec<LunarMonth> = prev
+            .into_iter()
+            .chain(year.get_months())
+            .chain(next)
+            .collect();
+        let mut terms: Vec<(String, SolarDay)> = Vec::new();
+        // 自首月之前的冬至起逐个取中气，直到超出末月
+        let end: SolarDay = months
+            .last()
+            .unwrap()
+            .get_first_julian_day()
+            .get_solar_day();
+        let mut term: SolarTerm = SolarTerm::from_index(months[0].get_year(), 0);
+        loop {
+            // 取交节时刻所在的日，不用估算的日，以免中气临近子夜时归错月
+            let day: SolarDay = term.get_julian_day().get_solar_day();
+            if day.is_after(end.next(30)) {
+                break;
+            }
+            terms.push((term.get_name(), day));
+            term = term.next(2);
+        }
+        let months: Vec<MonthTrace> = months
+            .into_iter()
+            .map(|m| {
+                let first_day: SolarDay = m.get_first_julian_day().get_solar_day();
+                let next: SolarDay = first_day.next(m.get_day_count() as isize);
+                MonthTrace {
+                    month: m,
+                    first_day,
+                    major_terms: terms
+                        .iter()
+                        .filter(|(_, d)| !d.is_before(first_day) && d.is_before(next))
+                        .cloned()
+                        .collect(),
+                }
+            })
+            .collect();
+        let expected_leaps: Vec<bool> = Self::get_expected_leaps(&months);
+        Ok(Self {
+            year,
+            months: months[offset..offset + count].to_vec(),
+            expected_leaps: expected_leaps[offset..offset + count].to_vec(),
+        })
+    }
+
+    /// 按无中气置闰判定各月是否为闰月：相邻两个含冬至的月之间（不含前者）有13个月时，其中第一个无中气的月为闰月；
+    /// 不在完整冬至区间内的月（支持范围两端）退回为无中气即闰月
+    fn get_expected_leaps(months: &[MonthTrace]) -> Vec<bool> {
+        let mut leaps: Vec<bool> = months.iter().map(|m| m.is_without_major_term()).collect();
+        let winters: Vec<usize> = months
+            .iter()
+            .enumerate()
+            .filter(|(_, m)| m.major_terms.iter().any(|(name, _)| name == "冬至"))
+            .map(|(i, _)| i)
+            .collect();
+        for pair in winters.windows(2) {
+            let leap: Option<usize> = if pair[1] - pair[0] == 13 {
+                (pair[0] + 1..pair[1]).find(|i| months[*i].is_without_major_term())
+            } else {
+                None
+            };
+            for (i, expected) in leaps.iter_mut().enumerate().take(pair[1]).skip(pair[0] + 1) {
+                *expected = leap == Some(i);
+            }
+        }
+        leaps
+    }
+
+    /// 农历年
+    pub fn get_lunar_year(&self) -> LunarYear {
+        self.year
+    }
+
+    /// 各月（含闰月）的判定过程
+    pub fn get_months(&self) -> Vec<MonthTrace> {
+        self.months.clone()
+    }
+
+    /// 闰月（取自内置闰月表），无闰月为0
+    pub fn get_leap_month(&self) -> usize {
+        self.year.get_leap_month()
+    }
+
+    /// 本年无中气的月（月名），按无中气置闰，闰月应为其中之一
+    pub fn get_months_without_major_term(&self) -> Vec<String> {
+        self.months
+            .iter()
+            .filter(|m| m.is_without_major_term())
+            .map(|m| m.month.get_name())
+            .collect()
+    }
+
+    /// 内置闰月与无中气置闰是否一致：按冬至区间判定，区间内有13个月时第一个无中气的月为闰月，其余月均非闰月
+    pub fn is_consistent(&self) -> bool {
+        self.months
+            .iter()
+            .zip(self.expected_leaps.iter())
+            .all(|(m, leap)| m.month.is_leap() == *leap)
+    }
+}
+
+impl Display for MonthNamingTrace {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let leap: String = match self.get_leap_month() {
+            0 => String::from("无闰月"),
+            n => format!("闰{}月", n),
+        };
+        write!(f, "{} {}", self.year.get_name(), leap)?;
+        for m in &self.months {
+            write!(f, "\n{}", m)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+
+    use crate::cache::Lock;
+    use crate::lunar::{LunarDay, Resolution};
+    use crate::solar::SolarDay;
+    use crate::trace::set_fallback_hook;
+    use crate::trace::{MonthNamingTrace, MonthTrace};
+    use crate::types::Culture;
+
+    #[test]
+    fn test1() {
+        let trace: MonthNamingTrace = MonthNamingTrace::new(2023).unwrap();
+        assert_eq!(2, trace.get_leap_month());
+        let months: Vec<MonthTrace> = trace.get_months();
+        assert_eq!(13, months.len());
+        assert_eq!("闰二月", months[2].get_lunar_month().get_name());
+        assert!(months[2].is_without_major_term());
+        assert_eq!(
+            "二月 朔2023年2月20日 30天 春分2023年3月21日",
+            months[1].to_string()
+        );
+        assert_eq!("闰二月 朔2023年3月22日 29天 无中气", months[2].to_string());
+        assert_eq!(
+            alloc::vec![String::from("闰二月")],
+            trace.get_months_without_major_term()
+        );
+        assert!(trace.is_consistent());
+        assert!(trace.to_string().starts_with("农历癸卯年 闰2月\n正月"));
+        assert!(MonthNamingTrace::new(10000).is_err());
+    }
+
+    #[test]
+    fn test2() {
+        // 1900—2100年间内置闰月均为本年的无中气月
+        for year in 1900..=2100 {
+            let trace: MonthNamingTrace = MonthNamingTrace::new(year).unwrap();
+            if trace.get_leap_month() > 0 {
+                let leap: Vec<MonthTrace> = trace
+                    .get_months()
+                    .into_iter()
+                    .filter(|m| m.get_lunar_month().is_leap())
+                    .collect();
+                assert!(leap[0].is_without_major_term(), "{}", trace);
+            }
+        }
+    }
+
+    /// 收到的回退原因；回调为全局设置，并行的其他测试也可能报告回退，只按原因查找
+    static FALLBACKS: Lock<Vec<String>> = Lock::new(Vec::new());
+
+    fn record_fallback(reason: &str) {
+        FALLBACKS.with(|f| f.push(reason.to_string()));
+    }
+
+    fn has_fallback(year: isize) -> bool {
+        let prefix: String = format!("lunar year {} outside recommended range", year);
+        FALLBACKS.with(|f| f.iter().any(|r| r.starts_with(&prefix)))
+    }
+
+    #[test]
+    fn test3() {
+        set_fallback_hook(Some(record_fallback));
+        let d: Resolution<SolarDay> = LunarDay::from_ymd(2024, 1, 1).resolve_solar_day();
+        assert_eq!(Resolution::Exact(SolarDay::from_ymd(2024, 2, 10)), d);
+        assert!(!has_fallback(2024));
+
+        let d: Resolution<LunarDay> = SolarDay::from_ymd(2200, 6, 1).resolve_lunar_day();
+        assert!(!d.is_exact());
+        assert!(has_fallback(2200));
+        assert_eq!(
+            SolarDay::from_ymd(2200, 6, 1),
+            d.get_value().get_solar_day()
+        );
+        set_fallback_hook(None);
+    }
+
+    #[test]
+    fn test4() {
+        // 1900—2100年间内置闰月均与按冬至区间的无中气置闰一致（如2033年八月、闰冬月都无中气，闰月为区间内第一个即闰冬月）
+        for year in 1900..=2100 {
+            let trace: MonthNamingTrace = MonthNamingTrace::new(year).unwrap();
+            assert!(trace.is_consistent(), "{}", trace);
+        }
+        let trace: MonthNamingTrace = MonthNamingTrace::new(2033).unwrap();
+        assert_eq!(
+            alloc::vec![String::from("八月"), String::from("闰冬月")],
+            trace.get_months_without_major_term()
+        );
+    }
+}